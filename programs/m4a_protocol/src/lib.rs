@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
+use anchor_lang::solana_program::hash::hashv;
+#[cfg(feature = "debug-compute")]
+use anchor_lang::solana_program::log::sol_log_compute_units;
 use anchor_spl::token::{self, Token, TokenAccount};
 use core::mem::size_of;
 use solana_security_txt::security_txt;
@@ -23,40 +26,79 @@ const INITIAL_CEO_ADDRESS: Pubkey = pubkey!("Fdqu1muWocA5ms8VmTrUxRxxmSattrmpNra
 pub const INITIAL_TREASURER_ADDRESS: Pubkey = pubkey!("9BRgCdmwyP5wGVTvKAUDjSwucpqGncurVa35DjaWqSsC");
 
 const FEE_4CENTS: f64 = 0.04;
+const DEFAULT_APPEAL_FEE_CENTS: u64 = 4;
+const PROTOCOL_ERROR_CODE: u8 = 1;//Max deny error code for a protocol or data error, the only one that gets the fee refunded
 
 //Patients need atleast 57 extra bytes of space to pass with full load
 const PATIENT_EXTRA_SIZE: usize = 64;
 
-//Claims need atleast 288 extra bytes of space to pass with full load
-const CLAIM_EXTRA_SIZE: usize = 300;
+//Claims need atleast 1853 extra bytes of space to pass with full load
+const CLAIM_EXTRA_SIZE: usize = 1864;
 
-//Hospitals need atleast 254 extra bytes of space to pass with full load
-const HOSPITAL_EXTRA_SIZE: usize = 264;
+//Hospitals need atleast 722 extra bytes of space to pass with full load
+const HOSPITAL_EXTRA_SIZE: usize = 728;
 
 //Insurance companies need atleast 138 extra bytes of space to pass with full load
 const INSURANCE_COMPANY_EXTRA_SIZE: usize = 144;
 
-//Patient records need atleast 143 extra bytes of space to pass with full load
-const PATIENT_RECORD_EXTRA_SIZE: usize = 150;
+//Patient records need atleast 392 extra bytes of space to pass with full load
+const PATIENT_RECORD_EXTRA_SIZE: usize = 400;
 
-//Hospital records need atleast 139 extra bytes of space to pass with full load
-const HOSPITAL_RECORD_EXTRA_SIZE: usize = 144;
+//Hospital records need atleast 388 extra bytes of space to pass with full load
+const HOSPITAL_RECORD_EXTRA_SIZE: usize = 392;
 
-//Insurance company records need atleast 141 extra bytes of space to pass with full load
-const INSURANCE_COMPANY_RECORD_EXTRA_SIZE: usize = 144;
+//Insurance company records need atleast 390 extra bytes of space to pass with full load
+const INSURANCE_COMPANY_RECORD_EXTRA_SIZE: usize = 392;
 
-//Processed claims need atleast 284 extra bytes of space to pass with full load
-const PROCESSED_CLAIM_EXTRA_SIZE: usize = 290;
+//Processed claims need atleast 953 extra bytes of space to pass with full load
+const PROCESSED_CLAIM_EXTRA_SIZE: usize = 960;
+
+//Denial reason templates need atleast 118 extra bytes of space to pass with full load
+const DENIAL_REASON_TEMPLATE_EXTRA_SIZE: usize = 128;
+
+//States need atleast 12 extra bytes of space to pass with full load
+const STATE_EXTRA_SIZE: usize = 16;
+
+//Processors need atleast 152 extra bytes of space to pass with a full claims in progress list and max length name
+const PROCESSOR_EXTRA_SIZE: usize = 160;
+
+//Processor claim indexes need atleast 108 extra bytes of space to pass with a full list of assigned submitter addresses
+const PROCESSOR_CLAIM_INDEX_EXTRA_SIZE: usize = 112;
 
 const MAX_NOTE_LENGTH: usize = 144;
+const MAX_CLAIM_COMMENT_COUNT: usize = 5;
+const MAX_CLAIM_COMMENT_LENGTH: usize = 140;
+const MAX_PROCESSOR_NAME_LENGTH: usize = 40;
+const MAX_HOSPITAL_NOTE_HISTORY_COUNT: usize = 3;
+const ADMIN_ACTION_LOG_SIZE: usize = 32;
+const MAX_BATCH_APPROVAL_CLAIM_COUNT: usize = 3;
+const BATCH_APPROVAL_ACCOUNTS_PER_CLAIM: usize = 13;
+const MAX_FEE_TOKEN_ENTRY_BATCH_COUNT: usize = 10;
+const MAX_RESERVED_INSURANCE_COMPANY_INDEX: u16 = 10;//Indexes 0-10 are the default insurance companies
+const MAX_DENIAL_HAMMER_CLAIM_COUNT: usize = 25;
+const APPROVED_AMOUNT_BUCKET_LIMITS_CENTS: [u64; 5] = [10_000, 100_000, 1_000_000, 10_000_000, 100_000_000];//Upper limits of every approved amount bucket but the last
+const MAX_PROCESSED_CLAIM_SUMMARY_COUNT: usize = 20;//25 bytes each keeps the summaries well under the 1024 byte return data limit
+const MAX_CLAIMS_IN_PROGRESS: usize = 4;
 const MAX_PATIENT_FIRST_NAME_LENGTH: usize = 52;
 const MAX_PATIENT_LAST_NAME_LENGTH: usize = 52;
 const MAX_HOSPITAL_NAME_LENGTH: usize = 50;
 const MAX_HOSPITAL_ADDRESS_LENGTH: usize = 100;
 const MAX_HOSPITAL_CITY_LENGTH: usize = 40;
 const MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH: usize = 20;
+const MAX_HOSPITAL_PHONE_NUMBER_DIGITS: u32 = 15;
+const DEFAULT_CURRENCY_CODE: [u8; 3] = *b"USD";
+const DEFAULT_SLA_SECONDS: u64 = 30 * 24 * 60 * 60;//30 days
+const DAILY_PROCESSING_WINDOW_SECONDS: u64 = 24 * 60 * 60;//24 hours
+const STATS_SNAPSHOT_INTERVAL_SECONDS: u64 = 24 * 60 * 60;//24 hours
+const DEFAULT_MIN_QUEUE_SIZE: u32 = 1;
+const DEFAULT_MAX_QUEUE_SIZE: u32 = 100_000;
+const MAX_ABSOLUTE_QUEUE: u32 = 1_000_000;//Hard cap that holds no matter what the queue size limit is set to
 const MAX_AILMENT_LENGTH: usize = 45;
+const MAX_DIAGNOSIS_CODE_COUNT: usize = 5;
+const MAX_DIAGNOSIS_CODE_LENGTH: usize = 45;
 const MAX_INSURANCE_COMPANY_NAME_LENGTH: usize = 35;
+const MAX_STATE_NAME_LENGTH: usize = 32;
+const MAX_REASSIGNMENT_REASON_LENGTH: usize = 100;
 
 enum Status
 {
@@ -64,7 +106,71 @@ enum Status
     Processing = 1,
     Approved = 2,
     Denied = 3,
-    Appealed = 4
+    Appealed = 4,
+    ReturnedForCorrection = 6,
+    Settled = 7,
+    Assigned = 8, //Assigned to a processor that hasn't acknowledged it yet
+    Voided = 9,
+    PendingSecondApproval = 10 //Approved once, waiting on a second distinct processor
+}
+
+enum AdminAction
+{
+    PassOnM4AProtocolCEO = 0,
+    AddFeeTokenEntry = 1,
+    UpdateFeeTokenDecimals = 2,
+    RemoveFeeTokenEntry = 3,
+    InitializeFeeConfig = 4,
+    SetPreferredFeeMint = 5,
+    InitializeProtocolStats = 6,
+    SetClaimQueueFlag = 7,
+    EditClaimQueueSize = 8,
+    SetMaxAppealsPerClaim = 9,
+    CreateProcessorAccount = 10,
+    SetProcessorAccountActiveFlag = 11,
+    SetProcessorAccountPrivilege = 12,
+    ReassignClaimToNewProcessor = 13,
+    UnassignClaimFromProcessor = 14,
+    SetProcessorToNotProcessingClaimState = 15,
+    EditHospital = 16,
+    EditInsuranceCompany = 17,
+    MaxDenyPendingClaim = 18,
+    MaxDenyInProgressClaim = 19,
+    DenyAppealedClaimWithAllRecords = 20,
+    UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords = 21,
+    UndenyClaimWithAllRecords = 22,
+    EditProcessedClaimAndPatientRecord = 23,
+    EditProcessedClaimAndAllRecords = 24,
+    RevokeApproval = 25,
+    DropDenialHammer = 26,
+    ApproveClaimsBatch = 27,
+    ArchiveProcessedClaim = 28,
+    SetMaxUpwardEditBps = 29,
+    SetAccountCreationFlag = 30,
+    GrantFeeExemption = 31,
+    RevokeFeeExemption = 32,
+    SetSlaSeconds = 33,
+    SetStateMetadata = 34,
+    SetMinClaimAmountCents = 35,
+    SetAppealFees = 36,
+    CorrectPatientRecordCount = 37,
+    SetAssignmentCooldownSeconds = 38,
+    SetMaxDailyProcessing = 39,
+    ReconcileHospitalStats = 40,
+    SetQueueSizeBounds = 41,
+    SetLifetimeBenefitCap = 42,
+    DeactivateStateHospitals = 43,
+    AddFeeTokenEntriesBatch = 44,
+    InitializeFieldLimits = 45,
+    SetFieldLimits = 46,
+    VoidProcessedClaim = 47,
+    SetAutoFlagAmountCents = 48,
+    AddDenialReasonTemplate = 49,
+    MigratePatientRecord = 50,
+    SetDualSignoffAmountCents = 51,
+    SetFeeTokenValidUntil = 52,
+    SetMaxReassignments = 53,
+    UpdateDenialReasonTemplate = 54
 }
 
 enum HospitalType
@@ -72,7 +178,34 @@ enum HospitalType
     General = 0,
     Dental = 1,
     Vision = 2,
-    Mental = 3
+    Mental = 3,
+    Other = 4 //Specialty facilities like urgent care and labs
+}
+
+//Role bits returned by get_roles
+enum Role
+{
+    Ceo = 1,
+    Treasurer = 2,
+    Processor = 4,
+    SuperAdmin = 8
+}
+
+//Reason codes returned by can_submit, checked in this order so the first gate that blocks a submission is the one reported
+enum CanSubmitReason
+{
+    CanSubmit = 0,
+    ClaimQueueDisabled = 1,
+    ClaimQueueFull = 2,
+    AbsoluteQueueLimitReached = 3,
+    AccountCreationDisabled = 4, //No submitter account and new ones can't be created
+    SubmitterAccountMissing = 5, //No submitter account yet, but one can be created
+    ClaimAlreadyOpen = 6,
+    PatientAccountMissing = 7,
+    NotPatientOwner = 8, //Patient account doesn't belong to the submitter
+    PatientConsentMissing = 9,
+    FeeTokenNotSupported = 10, //No fee token entry for the fee mint, it was never added or has been removed
+    FeeTokenExpired = 11
 }
 
 //Error Codes
@@ -85,6 +218,8 @@ pub enum AuthorizationError
     NotTreasurer,
     #[msg("Only a Super Admin or the CEO can call this function")]
     NotSuperAdminOrCEO,
+    #[msg("Only a Super Admin can call this function")]
+    NotSuperAdmin,
     #[msg("Only an active processor can call this function")]
     NotActiveProcessor,
     #[msg("Only the person who is processing the claim can call this function")]
@@ -94,7 +229,23 @@ pub enum AuthorizationError
     #[msg("A claim can only have one processor")]
     ClaimAlreadyHasProcessor,
     #[msg("A processor can only assign themselves to one claim at a time")]
-    ProcessorAlreadyWorkingOnClaim
+    ProcessorAlreadyWorkingOnClaim,
+    #[msg("Only an active delegate of the submitter can submit claims for them")]
+    NotSubmitterDelegate,
+    #[msg("Only the submitter or the Treasurer can call this function")]
+    NotSubmitterOrTreasurer,
+    #[msg("Processor has resolved the max number of claims allowed in a day")]
+    DailyLimitReached,
+    #[msg("Only an active Processor or the CEO can call this function")]
+    NotProcessorOrCEO,
+    #[msg("Only the submitter or the processor assigned to the claim can call this function")]
+    NotSubmitterOrProcessor,
+    #[msg("Only the CEO or the processor themselves can call this function")]
+    NotCEOOrProcessorOwner,
+    #[msg("Only the CEO or the Treasurer can call this function")]
+    NotCEOOrTreasurer,
+    #[msg("The second approval has to come from a different processor than the first")]
+    SameProcessorSecondApproval
 }  
 
 #[error_code]
@@ -127,7 +278,101 @@ pub enum InvalidOperationError
     #[msg("Claim must be in a denied or appealed state to undeny it")]
     ClaimNotDeniedOrAppealed,
     #[msg("Claim must be in a approved state to revoke approval")]
-    ClaimNotApproved
+    ClaimNotApproved,
+    #[msg("Copay and deductible amounts can't add up to more than the claim amount")]
+    CopayAndDeductibleExceedClaimAmount,
+    #[msg("Fee token isn't supported, it was never added or has been removed")]
+    FeeTokenNotSupported,
+    #[msg("Claim has already been appealed the max number of times")]
+    TooManyAppeals,
+    #[msg("Processor count index doesn't match the processed claim")]
+    ProcessedClaimIndexMismatch,
+    #[msg("Batch must have a full set of accounts for each claim and no more than 3 claims")]
+    InvalidBatchSize,
+    #[msg("Batch account doesn't match the claim it was passed for")]
+    InvalidBatchAccount,
+    #[msg("Hospital has been deactivated")]
+    HospitalInactive,
+    #[msg("Insurance company has been deactivated")]
+    InsuranceCompanyInactive,
+    #[msg("Processed claim has already been archived")]
+    ProcessedClaimAlreadyArchived,
+    #[msg("Claim amount can't be edited up that much without a super admin co-signing")]
+    AmountEditTooLarge,
+    #[msg("Account creation is currently disabled")]
+    AccountCreationDisabled,
+    #[msg("A processed claim already exists at this processor count index")]
+    ProcessedClaimAlreadyExists,
+    #[msg("Claim amount is below the minimum claim amount")]
+    ClaimAmountTooSmall,
+    #[msg("Active patient count can't go below 0 or above the patient count")]
+    ActivePatientCountInvalid,
+    #[msg("Claim has been returned to the submitter for correction")]
+    ClaimReturnedForCorrection,
+    #[msg("Claim must be returned for correction to resubmit it")]
+    ClaimNotReturnedForCorrection,
+    #[msg("Record slots have already been reserved for this claim")]
+    RecordSlotsAlreadyReserved,
+    #[msg("Record slots must be reserved before populating them")]
+    RecordSlotsNotReserved,
+    #[msg("Record count would overflow")]
+    RecordCountOverflow,
+    #[msg("Processor has to wait out the assignment cooldown before picking up another claim")]
+    AssignmentCooldownActive,
+    #[msg("Claim is still being processed by this processor, unassign it instead")]
+    ClaimStillBeingProcessed,
+    #[msg("Claim queue size must be within the min and max queue size")]
+    QueueSizeOutOfRange,
+    #[msg("Approving this claim would put the patient over their lifetime benefit cap")]
+    LifetimeCapExceeded,
+    #[msg("Refunding a protocol error needs the Treasurer co-signer, both fee token accounts and the fee token entry")]
+    RefundAccountsMissing,
+    #[msg("Refund token accounts must belong to the Treasurer and the submitter and share the fee token mint")]
+    InvalidRefundAccount,
+    #[msg("Claim has already been approved, there's nothing to appeal")]
+    ClaimAlreadyResolved,
+    #[msg("Fee token entry has already been added for this mint")]
+    FeeTokenEntryAlreadyExists,
+    #[msg("Claim must be assigned and not yet acknowledged")]
+    ClaimNotAwaitingAcknowledgement,
+    #[msg("Insurance company indexes 0-10 are reserved for the default insurance companies, only the CEO can create them")]
+    ReservedInsuranceIndex,
+    #[msg("A stats snapshot was taken too recently, only the CEO can take another one before the interval passes")]
+    StatsSnapshotTooSoon,
+    #[msg("Claim queue count is at the absolute max, no matter the queue size limit")]
+    AbsoluteQueueLimitReached,
+    #[msg("Claims can't have more than 5 comments")]
+    TooManyClaimComments,
+    #[msg("The submitter and treasurer fee token accounts must be for the same mint")]
+    FeeMintMismatch,
+    #[msg("Field limits can't be 0 or higher than the hard cap the accounts are sized for")]
+    FieldLimitOutOfRange,
+    #[msg("The patient has to give consent before a claim can be submitted for them")]
+    ConsentRequired,
+    #[msg("Only approved or denied claims with all of their records can be voided")]
+    ClaimCannotBeVoided,
+    #[msg("Claim queue count would underflow")]
+    ClaimQueueCountUnderflow,
+    #[msg("The submitter and patient of a claim can't be changed")]
+    ClaimIdentityChanged,
+    #[msg("Submitter accounts with patients can't be closed")]
+    SubmitterHasPatients,
+    #[msg("The claim's submitter account has been closed")]
+    SubmitterAccountMissing,
+    #[msg("The denial reason template for the reason code has to be passed in")]
+    DenialReasonTemplateMissing,
+    #[msg("Patient records can only be migrated to a different submitter")]
+    PatientRecordMigrationToSameSubmitter,
+    #[msg("Claim isn't waiting on a second approval")]
+    ClaimNotPendingSecondApproval,
+    #[msg("Claims over the dual signoff amount have to be approved by two processors through first_approve_claim and second_approve_claim")]
+    DualSignoffRequired,
+    #[msg("Fee token was only accepted until its valid until time, which has passed")]
+    FeeTokenExpired,
+    #[msg("Claims at or under the dual signoff amount are approved in one step through approve_claim")]
+    DualSignoffNotRequired,
+    #[msg("Claim is assigned to or being processed by a processor, unassign it before hammering it")]
+    ClaimHeldByProcessor
 }   
 
 #[error_code]
@@ -143,7 +388,7 @@ pub enum InvalidLengthError
     HospitalAddressTooLong,
     #[msg("Hospital City can't be longer than 40 characters")]
     HospitalCityTooLong,
-    #[msg("Hospital Phone Number can't be longer than 20 characters")]
+    #[msg("Hospital Phone Number can't be longer than 15 digits")]
     HospitalPhoneNumberTooLong,
     #[msg("Hospital Bill Invoice Number can't be longer than 20 characters")]
     HospitalBillInvoiceNumberTooLong,
@@ -152,14 +397,254 @@ pub enum InvalidLengthError
     #[msg("Note can't be longer than 140 characters")]
     NoteTooLong,
     #[msg("Insurance company name can't be longer than 35 characters")]
-    InsuranceCompanyNameTooLong
+    InsuranceCompanyNameTooLong,
+    #[msg("State name can't be longer than 32 characters")]
+    StateNameTooLong,
+    #[msg("Claims can't have more than 5 diagnosis codes")]
+    TooManyDiagnosisCodes,
+    #[msg("Diagnosis codes can't be longer than 45 characters")]
+    DiagnosisCodeTooLong,
+    #[msg("Claim comments can't be longer than 140 characters")]
+    ClaimCommentTooLong,
+    #[msg("Processor name can't be longer than 40 characters")]
+    ProcessorNameTooLong,
+    #[msg("Reassignment reason can't be longer than 100 characters")]
+    ReassignmentReasonTooLong
 }  
 
 #[error_code]
 pub enum InvalidType 
 {
     #[msg("Hospital type must be General, Dental, Vision, or Mental (0,1,2,3)")]
-    HospitalTypeInvalid
+    HospitalTypeInvalid,
+    #[msg("Hospital latitude must be between -90 and 90 and longitude must be between -180 and 180")]
+    InvalidCoordinates,
+    #[msg("Currency code must be 3 uppercase letters (ISO 4217)")]
+    InvalidCurrencyCode,
+    #[msg("State abbreviation must be 2 uppercase letters")]
+    InvalidStateAbbreviation
+}
+
+//Events
+#[event]
+pub struct QueueFull
+{
+    pub current: u32,
+    pub limit: u32
+}
+
+#[event]
+pub struct LastSuperAdminDemoted
+{
+    pub processor_address: Pubkey,
+    pub timestamp: u64
+}
+
+#[event]
+pub struct FeeWaived
+{
+    pub submitter_address: Pubkey,
+    pub amount: u64
+}
+
+#[event]
+pub struct DenialHammerDropped
+{
+    pub closed_claim_ids: Vec<u64>,
+    pub actor: Pubkey
+}
+
+#[event]
+pub struct ClaimReassigned
+{
+    pub claim_id: u64,
+    pub submitter_address: Pubkey,
+    pub old_processor_address: Pubkey,
+    pub new_processor_address: Pubkey,
+    pub reassignment_count: u16,
+    pub reason: String
+}
+
+#[event]
+pub struct ClaimApproved
+{
+    pub claim_id: u64,
+    pub submitter_address: Pubkey,
+    pub processor_address: Pubkey,
+    pub processed_claim_id: u64,
+    pub processor_count_index: u64
+}
+
+#[event]
+pub struct ClaimDenied
+{
+    pub claim_id: u64,
+    pub submitter_address: Pubkey,
+    pub processor_address: Pubkey,
+    pub processed_claim_id: u64,
+    pub processor_count_index: u64
+}
+
+// Helper function to load the fee token entry, a removed entry is closed so there's nothing left to load
+fn load_fee_token_entry(fee_token_entry: &AccountInfo) -> Result<FeeTokenEntry>
+{
+    //Fee token entry was never added or has been removed
+    require!(fee_token_entry.data_is_empty() == false, InvalidOperationError::FeeTokenNotSupported);
+
+    let fee_token_entry_data = fee_token_entry.try_borrow_data()?;
+
+    FeeTokenEntry::try_deserialize(&mut &fee_token_entry_data[..])
+}
+
+// Helper function to check a fee token entry is still inside its promo window before a fee is charged with it
+fn check_fee_token_not_expired(fee_token_entry: &FeeTokenEntry) -> Result<()>
+{
+    //A valid until of 0 means the fee token never expires
+    require!(fee_token_entry.valid_until == 0 || Clock::get()?.unix_timestamp <= fee_token_entry.valid_until, InvalidOperationError::FeeTokenExpired);
+
+    Ok(())
+}
+
+// Helper function to create a processed claim PDA the same way Anchor's init constraint does, topping up any lamports already sent to it instead of failing
+fn create_processed_claim_account<'info>(
+    processed_claim: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    processed_claim_seeds: &[&[u8]]) -> Result<()>
+{
+    //Only a processed claim already written at this processor count index is owned by the program, lamports alone leave it owned by the system program
+    require_keys_eq!(*processed_claim.owner, anchor_lang::system_program::ID, InvalidOperationError::ProcessedClaimAlreadyExists);
+
+    let processed_claim_space = size_of::<ProcessedClaim>() + PROCESSED_CLAIM_EXTRA_SIZE + 8;
+    let processed_claim_rent = Rent::get()?.minimum_balance(processed_claim_space);
+
+    if processed_claim.lamports() == 0
+    {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount
+                {
+                    from: payer.clone(),
+                    to: processed_claim.clone()
+                },
+                &[processed_claim_seeds]),
+            processed_claim_rent,
+            processed_claim_space as u64,
+            &ID)?;
+
+        return Ok(());
+    }
+
+    //create_account fails on an address that already holds lamports, so only the missing rent is sent before allocating and assigning it
+    let rent_top_up = processed_claim_rent.saturating_sub(processed_claim.lamports());
+    if rent_top_up > 0
+    {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer
+                {
+                    from: payer.clone(),
+                    to: processed_claim.clone()
+                }),
+            rent_top_up)?;
+    }
+
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate
+            {
+                account_to_allocate: processed_claim.clone()
+            },
+            &[processed_claim_seeds]),
+        processed_claim_space as u64)?;
+
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign
+            {
+                account_to_assign: processed_claim.clone()
+            },
+            &[processed_claim_seeds]),
+        &ID)?;
+
+    Ok(())
+}
+
+// Helper function to create a processed claim at the processor's count index, one that already exists is rejected instead of being reused
+fn create_processed_claim<'info>(
+    processed_claim: &AccountInfo<'info>,
+    signer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    processor_count_index: u64,
+    processed_claim_bump: u8) -> Result<ProcessedClaim>
+{
+    let processor_count_index_bytes = processor_count_index.to_le_bytes();
+
+    create_processed_claim_account(processed_claim,
+        signer,
+        system_program,
+        &[b"processedClaim".as_ref(), signer.key.as_ref(), processor_count_index_bytes.as_ref(), &[processed_claim_bump]])?;
+
+    //A newly created processed claim is all zeros, its discriminator gets written when it's stored
+    let processed_claim_data = processed_claim.try_borrow_data()?;
+
+    ProcessedClaim::try_deserialize_unchecked(&mut &processed_claim_data[..])
+}
+
+// Helper function to write back a processed claim made with create_processed_claim
+fn store_processed_claim(processed_claim_account: &AccountInfo, processed_claim: &ProcessedClaim) -> Result<()>
+{
+    let mut processed_claim_data = processed_claim_account.try_borrow_mut_data()?;
+
+    processed_claim.try_serialize(&mut &mut processed_claim_data[..])
+}
+
+// Helper function to load the submitter account of a claim, a closed submitter account has nothing left to load
+fn load_submitter_account(submitter: &AccountInfo) -> Result<SubmitterAccount>
+{
+    //Submitter account was closed out from under the claim
+    require!(submitter.data_is_empty() == false, InvalidOperationError::SubmitterAccountMissing);
+
+    let submitter_data = submitter.try_borrow_data()?;
+
+    SubmitterAccount::try_deserialize(&mut &submitter_data[..])
+}
+
+// Helper function to write back a submitter account loaded with load_submitter_account
+fn store_submitter_account(submitter_account: &AccountInfo, submitter: &SubmitterAccount) -> Result<()>
+{
+    let mut submitter_data = submitter_account.try_borrow_mut_data()?;
+
+    submitter.try_serialize(&mut &mut submitter_data[..])
+}
+
+// Helper function to recompute the processor's denial rate in basis points (denied*10000/processed)
+fn update_processor_denial_rate(processor: &mut ProcessorAccount)
+{
+    if processor.processed_claim_count > 0
+    {
+        processor.denial_rate_bps = (processor.denied_claim_count as u128 * 10000 / processor.processed_claim_count as u128) as u16;
+    }
+}
+
+// Helper function to write an admin action to the ring buffer, the oldest entry gets overwritten once it's full
+fn log_admin_action(admin_action_log: &mut AdminActionLog, action: AdminAction, actor: Pubkey) -> Result<()>
+{
+    let entry_index = (admin_action_log.total_action_count % ADMIN_ACTION_LOG_SIZE as u64) as usize;
+
+    admin_action_log.entries[entry_index] = AdminActionEntry
+    {
+        action_code: action as u8,
+        actor,
+        timestamp: Clock::get()?.unix_timestamp as u64
+    };
+    admin_action_log.total_action_count += 1;
+
+    Ok(())
 }
 
 // Helper function to handle the USDC fee transfer
@@ -170,8 +655,43 @@ fn apply_fee<'info>(
     token_program: AccountInfo<'info>,
     treasurer: Account<M4AProtocolTreasurer>,
     amount: f64,
-    decimal_amount: u8
-) -> Result<()> {
+    decimal_amount: u8,
+    fee_exemption: Option<&Account<FeeExemption>>,
+    fees_enabled: bool,
+    submitter_address: Pubkey
+) -> Result<u64> {
+    let base_int :u64 = 10;
+    let conversion_number = base_int.pow(decimal_amount as u32) as f64;
+    let fixed_pointed_notation_amount = (amount * conversion_number) as u64;
+
+    //The treasurer turned fee collection off, the waived fee is still reported
+    if fees_enabled == false
+    {
+        emit!(FeeWaived
+        {
+            submitter_address,
+            amount: fixed_pointed_notation_amount
+        });
+
+        msg!("Fee waived, fee collection is disabled");
+
+        return Ok(0);
+    }
+
+    //Fee exempt submitters skip the transfer, the waived fee is still reported
+    if let Some(fee_exemption) = fee_exemption
+    {
+        emit!(FeeWaived
+        {
+            submitter_address: fee_exemption.submitter_address,
+            amount: fixed_pointed_notation_amount
+        });
+
+        msg!("Fee waived for exempt submitter: {}", fee_exemption.submitter_address);
+
+        return Ok(0);
+    }
+
     let cpi_accounts = token::Transfer {
         from: from_account,
         to: to_account.clone(),
@@ -180,2352 +700,6965 @@ fn apply_fee<'info>(
     let cpi_program = token_program;
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-    let base_int :u64 = 10;
-    let conversion_number = base_int.pow(decimal_amount as u32) as f64;
-    let fixed_pointed_notation_amount = (amount * conversion_number) as u64;
-
     //Transfer fee to Treasurer Wallet
     token::transfer(cpi_ctx, fixed_pointed_notation_amount)?;
     
     msg!("Successfully transferred ${:.2} as fee to: {}", amount, treasurer.address);
 
-    Ok(())
+    Ok(fixed_pointed_notation_amount)
 }
 
-//Functions
-#[program]
-pub mod m_4_a_protocol 
+// Helper function to refund the fee the claim paid from the Treasurer when a claim is max denied because of a protocol error
+fn refund_protocol_error_fee<'info>(
+    treasurer: &Account<'info, M4AProtocolTreasurer>,
+    treasurer_signer: &Option<Signer<'info>>,
+    treasurer_fee_ata: &Option<Box<Account<'info, TokenAccount>>>,
+    submitter_fee_ata: &Option<Box<Account<'info, TokenAccount>>>,
+    token_program: &Program<'info, Token>,
+    claim: &Claim) -> Result<()>
 {
-    use super::*;
-
-    pub fn initialize_m4a_protocol_admin_accounts(ctx: Context<InitializeAdminAccounts>) -> Result<()> 
+    //Fee exempt claims and claims submitted while fees were disabled paid nothing, so there's nothing to refund
+    if claim.fee_amount_paid == 0
     {
-        //Only the initial CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), INITIAL_CEO_ADDRESS, AuthorizationError::NotCEO);
-
-        let ceo = &mut ctx.accounts.ceo;
-        ceo.address = INITIAL_CEO_ADDRESS;
-
-        let treasurer = &mut ctx.accounts.treasurer;
-        treasurer.address = INITIAL_TREASURER_ADDRESS;
-
-        msg!("M4A Protocol Admin Accounts Initialized");
-        msg!("New CEO Address: {}", ceo.address.key());
-        msg!("New Treasurer Address: {}", treasurer.address.key());
+        msg!("No fee was paid, nothing to refund");
 
-        Ok(())
+        return Ok(());
     }
 
-    pub fn pass_on_m4a_protocol_ceo(ctx: Context<PassOnM4AProtocolCEO>, new_ceo_address: Pubkey) -> Result<()> 
+    let (Some(treasurer_signer), Some(treasurer_fee_ata), Some(submitter_fee_ata)) = (treasurer_signer, treasurer_fee_ata, submitter_fee_ata) else
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        return err!(InvalidOperationError::RefundAccountsMissing);
+    };
 
-        msg!("The M4A Protocol CEO has passed on the title to a new CEO");
-        msg!("New CEO: {}", new_ceo_address.key());
+    //Only the Treasurer can send a refund out of the Treasurer's token account
+    require_keys_eq!(treasurer_signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
 
-        ceo.address = new_ceo_address.key();
+    //The refund has to come out of the Treasurer's token account and go back to the submitter in the token the fee was paid in
+    require!(treasurer_fee_ata.owner == treasurer.address &&
+    submitter_fee_ata.owner == claim.submitter_address &&
+    treasurer_fee_ata.mint == claim.fee_mint &&
+    submitter_fee_ata.mint == claim.fee_mint, InvalidOperationError::InvalidRefundAccount);
 
-        Ok(())
-    }
+    let cpi_accounts = token::Transfer {
+        from: treasurer_fee_ata.to_account_info(),
+        to: submitter_fee_ata.to_account_info(),
+        authority: treasurer_signer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
 
-    pub fn pass_on_m4a_protocol_treasurer(ctx: Context<PassOnM4AProtocolTreasurer>, new_treasurer_address: Pubkey) -> Result<()> 
-    {
-        let treasurer = &mut ctx.accounts.treasurer;
-        //Only the Treasurer can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
+    //Transfer the fee the claim paid back to the submitter
+    token::transfer(cpi_ctx, claim.fee_amount_paid)?;
 
-        msg!("The M4A Protocol Treasurer has passed on the title to a new Treasurer");
-        msg!("New Treasurer: {}", new_treasurer_address.key());
+    msg!("Successfully refunded {} of fee token {} to: {}", claim.fee_amount_paid, claim.fee_mint, claim.submitter_address);
 
-        treasurer.address = new_treasurer_address.key();
+    Ok(())
+}
 
-        Ok(())
-    }
+// Helper function to count a resolved claim against the processor's rolling 24 hour limit, a max of 0 means no limit
+fn record_daily_processing(processor: &mut ProcessorAccount, max_daily_processing: u32) -> Result<()>
+{
+    let now = Clock::get()?.unix_timestamp as u64;
 
-    pub fn add_fee_token_entry(ctx: Context<AddFeeTokenEntry>, token_mint_address: Pubkey, decimal_amount: u8) -> Result<()> 
+    //Start a new window once the current one has rolled over
+    if now >= processor.day_window_start + DAILY_PROCESSING_WINDOW_SECONDS
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        processor.day_window_start = now;
+        processor.processed_today_count = 0;
+    }
 
-        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
-        fee_token_entry.token_mint_address = token_mint_address;
-        fee_token_entry.decimal_amount = decimal_amount;
+    //Processor can't resolve more claims than the daily max
+    require!(max_daily_processing == 0 || processor.processed_today_count < max_daily_processing, AuthorizationError::DailyLimitReached);
 
-        msg!("Added Fee Token Entry");
-        msg!("Mint Address: {}", token_mint_address.key());
-        msg!("Decimal Amount: {}", decimal_amount);
-            
-        Ok(())
-    }
+    processor.processed_today_count += 1;
 
-    pub fn remove_fee_token_entry(ctx: Context<RemoveFeeTokenEntry>,
-        token_mint_address: Pubkey) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+    Ok(())
+}
 
-        msg!("Removed Fee Token Entry");
-        msg!("Mint Address: {}", token_mint_address.key());
-            
-        Ok(())
-    }
+// Helper function to track a claim the processor picked up by its submitter address, in the processor and its claim index
+fn add_claim_in_progress(processor: &mut ProcessorAccount, processor_claim_index: &mut ProcessorClaimIndex, submitter_address: Pubkey) -> Result<()>
+{
+    //Processor can't hold more claims than there's room for
+    require!(processor.claims_in_progress.len() < MAX_CLAIMS_IN_PROGRESS &&
+    processor_claim_index.submitter_addresses.len() < MAX_CLAIMS_IN_PROGRESS, AuthorizationError::ProcessorAlreadyWorkingOnClaim);
 
-    pub fn initialize_m4a_protocol_and_claim_queue(ctx: Context<InitializeM4AProtocolAndClaimQueue>) -> Result<()> 
-    {
-        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
-        m4a_protocol.m4a_protocol_initiator_address = ctx.accounts.signer.key();
+    processor.claims_in_progress.push(submitter_address);
+    processor_claim_index.submitter_addresses.push(submitter_address);
 
-        let claim_queue = &mut ctx.accounts.claim_queue;
-        claim_queue.enabled = true;
-        claim_queue.queue_size_limit = 100;//Set Claim Queue initial size to 100
+    Ok(())
+}
 
-        msg!("M4A Protocol And Claim Que Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
+// Helper function to stop tracking a claim the processor no longer holds, in the processor and its claim index
+fn remove_claim_in_progress(processor: &mut ProcessorAccount, processor_claim_index: &mut ProcessorClaimIndex, submitter_address: Pubkey)
+{
+    processor.claims_in_progress.retain(|claim_submitter_address| *claim_submitter_address != submitter_address);
+    processor_claim_index.submitter_addresses.retain(|claim_submitter_address| *claim_submitter_address != submitter_address);
+}
 
-        Ok(())
-    }
+// Helper function to fill out a patient record from its claim, shared by the create and populate reserved records paths
+fn fill_patient_record(patient_record: &mut PatientRecord, claim: &Claim, record_id: u32, processor_address: Pubkey)
+{
+    patient_record.record_id = record_id;
+    patient_record.claim_id = claim.id as u32;
+    patient_record.status = Status::Processing as u8;
+    patient_record.patient_record_only = true;
+    patient_record.submitter_address = claim.submitter_address;
+    patient_record.processor_address = processor_address;
+    patient_record.country_index = claim.country_index;
+    patient_record.state_index = claim.state_index;
+    patient_record.hospital_index = claim.hospital_index as u32;
+    patient_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+    patient_record.claim_amount = claim.claim_amount;
+    patient_record.copay_amount = claim.copay_amount;
+    patient_record.deductible_amount = claim.deductible_amount;
+    patient_record.ailment = claim.ailment.clone();
+    patient_record.diagnosis_codes = claim.diagnosis_codes.clone();
+    patient_record.note = claim.note.clone();
+    patient_record.submitted_time = claim.submitted_time;
+    patient_record.insurance_company_index = claim.insurance_company_index as u16;
+}
 
-    pub fn initialize_protocol_stats(ctx: Context<InitializeProtocolStats>) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+// Helper function to fill out a hospital record from its claim, shared by the create and populate reserved records paths
+fn fill_hospital_record(hospital_record: &mut HospitalRecord, claim: &Claim, record_id: u64, processor_address: Pubkey)
+{
+    hospital_record.record_id = record_id;
+    hospital_record.claim_id = claim.id;
+    hospital_record.status = Status::Processing as u8;
+    hospital_record.submitter_address = claim.submitter_address;
+    hospital_record.patient_index = claim.patient_index;
+    hospital_record.processor_address = processor_address;
+    hospital_record.claim_amount = claim.claim_amount;
+    hospital_record.copay_amount = claim.copay_amount;
+    hospital_record.deductible_amount = claim.deductible_amount;
+    hospital_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+    hospital_record.ailment = claim.ailment.clone();
+    hospital_record.diagnosis_codes = claim.diagnosis_codes.clone();
+    hospital_record.note = claim.note.clone();
+    hospital_record.submitted_time = claim.submitted_time;
+    hospital_record.insurance_company_index = claim.insurance_company_index as u16;
+}
 
-        msg!("Protocol Stats Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
+// Helper function to fill out an insurance company record from its claim, shared by the create and populate reserved records paths
+fn fill_insurance_company_record(insurance_company_record: &mut InsuranceCompanyRecord, claim: &Claim, record_id: u64, processor_address: Pubkey)
+{
+    insurance_company_record.record_id = record_id;
+    insurance_company_record.claim_id = claim.id;
+    insurance_company_record.status = Status::Processing as u8;
+    insurance_company_record.submitter_address = claim.submitter_address;
+    insurance_company_record.patient_index = claim.patient_index;
+    insurance_company_record.processor_address = processor_address;
+    insurance_company_record.country_index = claim.country_index;
+    insurance_company_record.state_index = claim.state_index;
+    insurance_company_record.hospital_index = claim.hospital_index as u32;
+    insurance_company_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+    insurance_company_record.claim_amount = claim.claim_amount;
+    insurance_company_record.copay_amount = claim.copay_amount;
+    insurance_company_record.deductible_amount = claim.deductible_amount;
+    insurance_company_record.ailment = claim.ailment.clone();
+    insurance_company_record.diagnosis_codes = claim.diagnosis_codes.clone();
+    insurance_company_record.note = claim.note.clone();
+    insurance_company_record.submitted_time = claim.submitted_time;
+}
 
-        Ok(())
-    }
+// Helper function to validate the submitter provided claim details, shared by the submit and resubmit corrected claim paths
+fn validate_claim_details(
+    hospital_type: u8,
+    hospital_name: &str,
+    hospital_address: &str,
+    hospital_city: &str,
+    hospital_bill_invoice_number: &str,
+    note: &str,
+    claim_amount: u64,
+    copay_amount: u64,
+    deductible_amount: u64,
+    ailment: &str,
+    diagnosis_codes: &[String],
+    insurance_company_name: &str,
+    min_claim_amount_cents: u64,
+    field_limits: &FieldLimits
+) -> Result<()>
+{
+    //Hospital type must be valid
+    require!((hospital_type == HospitalType::General as u8) ||
+    (hospital_type == HospitalType::Dental as u8) ||
+    (hospital_type == HospitalType::Vision as u8) ||
+    (hospital_type == HospitalType::Mental as u8) ||
+    (hospital_type == HospitalType::Other as u8), InvalidType::HospitalTypeInvalid);
 
-    pub fn set_claim_queue_flag(ctx: Context<SetClaimQueueFlag>, is_enabled: bool) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+    //Hospital name string must not be longer than 50 characters
+    require!(hospital_name.len() <= get_field_limit(field_limits.hospital_name_length, MAX_HOSPITAL_NAME_LENGTH), InvalidLengthError::HospitalNameTooLong);
 
-        let claim_queue = &mut ctx.accounts.claim_queue;
-        claim_queue.enabled = is_enabled;
-        
-        msg!("Set Claim Queue Flag");
-        msg!("Set to {}", is_enabled);
-        
-        Ok(())
-    }
+    //Hospital address string must not be longer than 100 characters
+    require!(hospital_address.len() <= get_field_limit(field_limits.hospital_address_length, MAX_HOSPITAL_ADDRESS_LENGTH), InvalidLengthError::HospitalAddressTooLong);
 
-    pub fn edit_claim_queue_size(ctx: Context<EditClaimQueueSize>, new_size_limit: u32) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+    //Hospital city string must not be longer than 40 characters
+    require!(hospital_city.len() <= get_field_limit(field_limits.hospital_city_length, MAX_HOSPITAL_CITY_LENGTH), InvalidLengthError::HospitalCityTooLong);
 
-        let claim_queue = &mut ctx.accounts.claim_queue;
-        claim_queue.queue_size_limit = new_size_limit;
+    //Hospital bill invoice number string must not be longer than 20 characters
+    require!(hospital_bill_invoice_number.len() <= get_field_limit(field_limits.hospital_bill_invoice_number_length, MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH), InvalidLengthError::HospitalBillInvoiceNumberTooLong);
 
-        msg!("Claim Queue Initialized");
-        Ok(())
-    }
+    //Ailment string must not be longer than 45 characters
+    require!(ailment.len() <= get_field_limit(field_limits.ailment_length, MAX_AILMENT_LENGTH), InvalidLengthError::AilmentTooLong);
 
-    pub fn create_submitter_account(ctx: Context<CreateSubmitterAccount>) -> Result<()> 
-    {
-        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
-        m4a_protocol.submitter_account_total += 1;
+    //Claims can have up to 5 diagnosis codes
+    require!(diagnosis_codes.len() <= get_field_limit(field_limits.diagnosis_code_count, MAX_DIAGNOSIS_CODE_COUNT), InvalidLengthError::TooManyDiagnosisCodes);
 
-        let submitter = &mut ctx.accounts.submitter;
-        submitter.id = m4a_protocol.submitter_account_total;
-        submitter.address = ctx.accounts.signer.key();
+    //Each diagnosis code string must not be longer than 45 characters
+    require!(diagnosis_codes.iter().all(|diagnosis_code| diagnosis_code.len() <= get_field_limit(field_limits.diagnosis_code_length, MAX_DIAGNOSIS_CODE_LENGTH)), InvalidLengthError::DiagnosisCodeTooLong);
 
-        msg!("Sumitter Account Initialized");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+    //Note string must not be longer than 140 characters
+    require!(note.len() <= get_field_limit(field_limits.note_length, MAX_NOTE_LENGTH), InvalidLengthError::NoteTooLong);
 
-        Ok(())
-    }
-
-    pub fn create_patient_account(ctx: Context<CreatePatientAccount>, patient_first_name: String, patient_last_name: String) -> Result<()> 
-    {
-        //Patient first name string must not be longer than 52 characters
-        require!(patient_first_name.len() <= MAX_PATIENT_FIRST_NAME_LENGTH, InvalidLengthError::PatientFirstNameTooLong);
+    //Insurance company name string must not be longer than 35 characters
+    require!(insurance_company_name.len() <= get_field_limit(field_limits.insurance_company_name_length, MAX_INSURANCE_COMPANY_NAME_LENGTH), InvalidLengthError::InsuranceCompanyNameTooLong);
 
-        //Patient last name string must not be longer than 52 characters
-        require!(patient_last_name.len() <= MAX_PATIENT_LAST_NAME_LENGTH, InvalidLengthError::PatientLastNameTooLong);
+    //Claim amount must be at least the minimum claim amount
+    require!(claim_amount >= min_claim_amount_cents, InvalidOperationError::ClaimAmountTooSmall);
 
-        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
+    //Copay and deductible are part of the claim amount, so together they can't be more than it
+    require!(copay_amount as u128 + deductible_amount as u128 <= claim_amount as u128, InvalidOperationError::CopayAndDeductibleExceedClaimAmount);
 
-        patient.is_active = true;
-        patient.submitter_address = ctx.accounts.signer.key();
-        patient.patient_first_name = patient_first_name.clone();
-        patient.patient_last_name = patient_last_name.clone();
+    Ok(())
+}
 
-        m4a_protocol.patient_account_total += 1;
-        patient.id = m4a_protocol.patient_account_total;
-        submitter.active_patient_count += 1;
-        
-        msg!("Patient Account Initialized");
-        msg!("Submitter Address: {}", ctx.accounts.signer.key());
-        msg!("Patient Index: {}", submitter.patient_count);
-        msg!("Patient First Name: {}", patient_first_name);
-        msg!("Patient Last Name: {}", patient_last_name);
+// Helper function to get the configured field limit, the constant the accounts are sized for stays the hard cap
+fn get_field_limit(configured_limit: u32, hard_cap: usize) -> usize
+{
+    (configured_limit as usize).min(hard_cap)
+}
 
-        submitter.patient_count += 1;
-        
-        Ok(())
-    }
+// Helper function to hash the claim's salient fields so indexers can tell when a claim was edited, strings are length prefixed so field boundaries can't shift
+fn hash_claim_content(claim_amount: u64, ailment: &str, hospital_index: i32, hospital_name: &str, insurance_company_index: i16, insurance_company_name: &str, note: &str) -> [u8; 32]
+{
+    hashv(&[
+        claim_amount.to_le_bytes().as_ref(),
+        (ailment.len() as u32).to_le_bytes().as_ref(),
+        ailment.as_bytes(),
+        hospital_index.to_le_bytes().as_ref(),
+        (hospital_name.len() as u32).to_le_bytes().as_ref(),
+        hospital_name.as_bytes(),
+        insurance_company_index.to_le_bytes().as_ref(),
+        (insurance_company_name.len() as u32).to_le_bytes().as_ref(),
+        insurance_company_name.as_bytes(),
+        (note.len() as u32).to_le_bytes().as_ref(),
+        note.as_bytes()]).to_bytes()
+}
 
-    pub fn set_patient_flag(ctx: Context<SetPatientFlag>, _patient_index: u8, is_enabled: bool) -> Result<()> 
-    {
-        let patient = &mut ctx.accounts.patient;
-        //Can't set patient to the same state because of the counter
-        require!(patient.is_active != is_enabled, InvalidOperationError::FlagSameState);
+// Helper function to get the content hash of a claim
+fn get_claim_content_hash(claim: &Claim) -> [u8; 32]
+{
+    hash_claim_content(claim.claim_amount, &claim.ailment, claim.hospital_index, &claim.hospital_name, claim.insurance_company_index, &claim.insurance_company_name, &claim.note)
+}
 
-        let submitter = &mut ctx.accounts.submitter;
-        
-        patient.is_active = is_enabled;
+// Helper function to get the content hash of a processed claim
+fn get_processed_claim_content_hash(processed_claim: &ProcessedClaim) -> [u8; 32]
+{
+    hash_claim_content(processed_claim.claim_amount, &processed_claim.ailment, processed_claim.hospital_index, &processed_claim.hospital_name, processed_claim.insurance_company_index, &processed_claim.insurance_company_name, &processed_claim.note)
+}
 
-        if is_enabled
-        {
-            submitter.active_patient_count += 1; 
-        }
-        else
-        {
-            submitter.active_patient_count -= 1; 
-        }
-        
-        msg!("Patient Flag Updated To: {}", is_enabled);
-        msg!("Patient First Name: {}", patient.patient_first_name);
-        msg!("Patient Last Name: {}", patient.patient_last_name);
-        msg!("Submitter Address: {}", ctx.accounts.signer.key());
-        
-        Ok(())
-    }
-    
-    pub fn create_processor_account(ctx: Context<CreateProcessorAccount>, processor_address: Pubkey) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+// Helper function to validate and fill out a newly submitted claim, shared by the submitter and delegate submit paths
+fn submit_claim(
+    claim_queue: &mut ClaimQueue,
+    claim: &mut Claim,
+    submitter: &mut SubmitterAccount,
+    patient: &mut PatientAccount,
+    submitter_address: Pubkey,
+    patient_index: u8,
+    country_index: u16,
+    state_index: u32,
+    hospital_index: i32,
+    hospital_type: u8,
+    hospital_name: String,
+    hospital_address: String,
+    hospital_city: String,
+    hospital_zip_code: u32,
+    hospital_phone_number: u128,
+    hospital_bill_invoice_number: String,
+    note: String,
+    claim_amount: u64,
+    copay_amount: u64,
+    deductible_amount: u64,
+    currency_code: [u8; 3],
+    ailment: String,
+    insurance_company_index: i16,
+    insurance_company_name: String,
+    diagnosis_codes: Vec<String>,
+    sla_seconds: u64,
+    min_claim_amount_cents: u64,
+    auto_flag_amount_cents: u64,
+    field_limits: &FieldLimits
+) -> Result<()>
+{
+    //Claim Queue is currently disabled
+    require!(claim_queue.enabled == true, InvalidOperationError::ClaimQueueDisabled);
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.processor_account_total += 1;
-        processor_stats.processor_active_account_total += 1;
+    //The patient PDA is seeded by the submitter, but the patient itself must still belong to them
+    require_keys_eq!(patient.submitter_address.key(), submitter_address.key(), AuthorizationError::NotSubmitter);
 
-        let processor = &mut ctx.accounts.processor;
-        processor.id = processor_stats.processor_account_total;
-        processor.address = processor_address.key();
-        processor.is_active = true;
+    //Claims can't be submitted for a patient that hasn't given consent
+    require!(patient.consent_given == true, InvalidOperationError::ConsentRequired);
 
-        msg!("Processor Account Initialized");
-        msg!("Processor Address: {}", processor_address.key());
-        msg!("Processor Account Count: {}", processor_stats.processor_account_total);
-        
-        Ok(())
-    }
+    //You can only submit 1 claim at a time
+    //require!(claim.is_active == false, InvalidOperationError::TooManyActiveClaims);
 
-    pub fn set_processor_account_active_flag(ctx: Context<SetProcessorAccountActiveFlag>, processor_address: Pubkey, is_active: bool) -> Result<()> 
+    //Claim Queue is full, let the UI know how full it is before rejecting the claim
+    if claim_queue.current_claim_queue_count + 1 > claim_queue.queue_size_limit
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let processor = &mut ctx.accounts.processor;
-        //The flag can't be set to the same state to keep the counters safe
-        require!(processor.is_active != is_active, InvalidOperationError::FlagSameState);
-
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.edited_processor_count += 1;
-        processor.is_active = is_active;
-
-        if is_active == false
+        emit!(QueueFull
         {
-            processor_stats.processor_active_account_total -= 1;
+            current: claim_queue.current_claim_queue_count,
+            limit: claim_queue.queue_size_limit
+        });
 
-            if processor.is_super_admin == true
-            {
-                processor.is_super_admin = false;
-                processor_stats.processor_super_admin_account_total -= 1;
-            }
-        }
-        else
-        {
-            processor_stats.processor_active_account_total += 1;
-        }
-        
-        msg!("Processor Account Is Active Flag Set To: {}", is_active);
-        msg!("Processor Address: {}", processor_address.key());
+        return err!(InvalidOperationError::TooManyClaimsInQueue);
+    }
 
-        Ok(())
+    //Call the helper function to validate the claim details
+    validate_claim_details(
+        hospital_type,
+        &hospital_name,
+        &hospital_address,
+        &hospital_city,
+        &hospital_bill_invoice_number,
+        &note,
+        claim_amount,
+        copay_amount,
+        deductible_amount,
+        &ailment,
+        &diagnosis_codes,
+        &insurance_company_name,
+        min_claim_amount_cents,
+        field_limits
+    )?;
+
+    //Currency code must be left blank for USD or be 3 uppercase letters
+    require!(currency_code == [0; 3] || currency_code.iter().all(|letter| letter.is_ascii_uppercase()), InvalidType::InvalidCurrencyCode);
+
+    claim_queue.submitted_claim_count += 1;
+    claim_queue.current_claim_queue_count = claim_queue.current_claim_queue_count.checked_add(1).ok_or(InvalidOperationError::TooManyClaimsInQueue)?;
+
+    //The close paths decrement this count assuming it never went past the limit, so hold the invariant after the increment too
+    require!(claim_queue.current_claim_queue_count <= claim_queue.queue_size_limit, InvalidOperationError::TooManyClaimsInQueue);
+
+    //Independent of the configurable limit, in case the count ever desyncs from the real number of claims in the queue
+    require!(claim_queue.current_claim_queue_count <= MAX_ABSOLUTE_QUEUE, InvalidOperationError::AbsoluteQueueLimitReached);
+
+    patient.submitted_claim_count += 1;
+    submitter.submitted_claim_count += 1;
+    
+    claim.id = claim_queue.submitted_claim_count;
+    claim.submitter_address = submitter_address;
+    claim.patient_index = patient_index;
+    claim.country_index = country_index.clone();
+    claim.state_index = state_index.clone();
+    claim.hospital_index = hospital_index;
+    claim.hospital_type = hospital_type;
+    claim.hospital_name = hospital_name;
+    claim.hospital_address = hospital_address;
+    claim.hospital_city = hospital_city;
+    claim.hospital_zip_code = hospital_zip_code;
+    claim.hospital_phone_number = hospital_phone_number;
+    claim.hospital_bill_invoice_number = hospital_bill_invoice_number;
+    claim.note = note;
+    claim.claim_amount = claim_amount.clone();
+    claim.copay_amount = copay_amount;
+    claim.deductible_amount = deductible_amount;
+    claim.currency_code = currency_code;
+    claim.ailment = ailment.clone();
+    claim.insurance_company_index = insurance_company_index;
+    claim.insurance_company_name = insurance_company_name;
+    claim.content_hash = get_claim_content_hash(claim);
+    claim.diagnosis_codes = diagnosis_codes;
+    claim.submitted_time = Clock::get()?.unix_timestamp as u64;
+
+    //Claims must be processed by this time, a 0 SLA leaves the claim without a deadline
+    if sla_seconds != 0
+    {
+        claim.sla_deadline = claim.submitted_time + sla_seconds;
     }
 
-    pub fn set_processor_account_privilege(ctx: Context<SetProcessorAccountPrivilege>, processor_address: Pubkey, is_super_admin: bool) -> Result<()> 
+    //Currency code defaults to USD when left blank
+    if currency_code == [0; 3]
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        claim.currency_code = DEFAULT_CURRENCY_CODE;
+    }
 
-        let processor = &mut ctx.accounts.processor;
-        //The flag can't be set to the same state to keep the counters safe
-        require!(processor.is_super_admin != is_super_admin, InvalidOperationError::FlagSameState);
+    //Call the helper function to flag the claim for review if the amount is over the auto flag amount
+    auto_flag_claim_amount(claim, auto_flag_amount_cents);
+    
+    msg!("New Claim Submited to the Queue");
+    msg!("Submitter Address: {}", submitter_address);
+    msg!("Patient First Name: {}", patient.patient_first_name);
+    msg!("Patient Last Name: {}", patient.patient_last_name);
+    msg!("Country Index: {}", country_index);
+    msg!("State Index: {}", state_index);
+    msg!("Hospital Index: {}", hospital_index);
+    msg!("Hospital Type: {}", hospital_type);
+    msg!("Claim Info: {}", ailment);
+    msg!("For: ${:.2}", claim_amount as f64/100.00);
+    msg!("Note: {}", claim.note);
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.edited_processor_count += 1;
-        processor.is_super_admin = is_super_admin;
+    Ok(())
+}
 
-        if is_super_admin == false
-        {
-            processor_stats.processor_super_admin_account_total -= 1;
-        }
-        else
-        {
-            processor_stats.processor_super_admin_account_total += 1;
+// Helper function to check hospital coordinates are on the map, shared by the hospital create, edit, and approve with edits paths
+fn check_hospital_coordinates(hospital_latitude: f64, hospital_longitude: f64) -> Result<()>
+{
+    //Latitude has to be within -90 to 90 and longitude within -180 to 180
+    require!((-90.0..=90.0).contains(&hospital_latitude) &&
+    (-180.0..=180.0).contains(&hospital_longitude), InvalidType::InvalidCoordinates);
 
-            if processor.is_active == false
-            {
-                processor.is_active = true;
-                processor_stats.processor_active_account_total += 1;
-            }
-        }
+    Ok(())
+}
 
-        msg!("Processor Account Admin Flag Set To: {}", is_super_admin);
-        msg!("Processor Address: {}", processor_address.key());
+// Helper function to flag a claim over the auto flag amount for review so only a super admin can approve it, a 0 amount turns auto flagging off
+fn auto_flag_claim_amount(claim: &mut Claim, auto_flag_amount_cents: u64)
+{
+    if auto_flag_amount_cents != 0 && claim.claim_amount > auto_flag_amount_cents && claim.is_flagged_for_review == false
+    {
+        claim.is_flagged_for_review = true;
+        claim.auto_flagged = true;
+        claim.flag_reason = String::from("Claim amount is over the auto flag amount");
+    }
+}
 
-        Ok(())
+// Helper function to flag a claim that has been reassigned more than the max reassignments for review, a 0 max turns it off
+fn auto_flag_claim_reassignments(claim: &mut Claim, max_reassignments: u16)
+{
+    if max_reassignments != 0 && claim.reassignment_count > max_reassignments && claim.is_flagged_for_review == false
+    {
+        claim.is_flagged_for_review = true;
+        claim.auto_flagged = true;
+        claim.flag_reason = String::from("Claim was reassigned more than the max reassignments");
     }
+}
 
-    pub fn submit_claim_to_queue(ctx: Context<SubmitClaimToQueue>,
-        patient_index: u8,
-        _token_mint_address: Pubkey,
-        country_index: u16,
-        state_index: u32,
-        hospital_index: i32,
-        hospital_type: u8,
-        hospital_name: String,
-        hospital_address: String,
-        hospital_city: String,
-        hospital_zip_code: u32,
-        hospital_phone_number: u128,
-        hospital_bill_invoice_number: String,
-        note: String,
-        claim_amount: u64,
-        ailment: String,
-        insurance_company_index: i16,
-        insurance_company_name: String
-    ) -> Result<()> 
+// Helper function to fill in the denial reason from its template when a reason code is given without any text
+fn get_denial_reason(denial_reason: String, denial_reason_code: Option<u16>, denial_reason_template: &Option<Box<Account<DenialReasonTemplate>>>) -> Result<String>
+{
+    if let Some(denial_reason_code) = denial_reason_code
     {
-        let claim = &mut ctx.accounts.claim;
-        let claim_queue = &mut ctx.accounts.claim_queue;
+        if denial_reason.is_empty() == true
+        {
+            //The template has to be the one for the given reason code
+            let denial_reason_template = denial_reason_template.as_ref().ok_or(InvalidOperationError::DenialReasonTemplateMissing)?;
+            require!(denial_reason_template.code == denial_reason_code, InvalidOperationError::DenialReasonTemplateMissing);
 
-        //Claim Queue is currently disabled
-        require!(claim_queue.enabled == true, InvalidOperationError::ClaimQueueDisabled);
+            return Ok(denial_reason_template.text.clone());
+        }
+    }
 
-        //You can only submit 1 claim at a time
-        //require!(claim.is_active == false, InvalidOperationError::TooManyActiveClaims);
+    Ok(denial_reason)
+}
 
-        //Claim Queue is full
-        require!(claim_queue.current_claim_queue_count + 1 <= claim_queue.queue_size_limit, InvalidOperationError::TooManyClaimsInQueue);
+// Helper function to get the approved amount bucket a claim amount in cents falls in: <$100, <$1k, <$10k, <$100k, <$1M, >=$1M
+fn get_approved_amount_bucket(claim_amount: u64) -> usize
+{
+    APPROVED_AMOUNT_BUCKET_LIMITS_CENTS.iter().position(|bucket_limit| claim_amount < *bucket_limit).unwrap_or(APPROVED_AMOUNT_BUCKET_LIMITS_CENTS.len())
+}
 
-        //Hospital type must be valid
-        require!((hospital_type == HospitalType::General as u8) ||
-        (hospital_type == HospitalType::Dental as u8) ||
-        (hospital_type == HospitalType::Vision as u8) ||
-        (hospital_type == HospitalType::Mental as u8), InvalidType::HospitalTypeInvalid);
+// Helper function to take a resolved claim out of the queue count, every claim resolution path goes through here so the count can't drift
+fn leave_queue(claim_queue: &mut ClaimQueue) -> Result<()>
+{
+    claim_queue.current_claim_queue_count = claim_queue.current_claim_queue_count.checked_sub(1).ok_or(InvalidOperationError::ClaimQueueCountUnderflow)?;
 
-        //Hospital name string must not be longer than 50 characters
-        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+    Ok(())
+}
 
-        //Hospital address string must not be longer than 100 characters
-        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalAddressTooLong);
+// Helper function to flag a processed claim and bump the breach counter when it was processed after its SLA deadline
+fn record_sla_breach(processed_claim: &mut ProcessedClaim, processor_stats: &mut ProcessorStats, sla_deadline: u64)
+{
+    //A deadline of 0 means the claim was submitted while the SLA was turned off
+    if sla_deadline != 0 && processed_claim.processed_time > sla_deadline
+    {
+        processed_claim.sla_breached = true;
+        processor_stats.sla_breach_count += 1;
+    }
+}
 
-        //Hospital city string must not be longer than 40 characters
-        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalCityTooLong);
+// Helper function to work out what the insurer covers, the patient pays the copay and deductible
+fn get_insurance_covered_amount(claim_amount: u64, copay_amount: u64, deductible_amount: u64) -> u64
+{
+    claim_amount.saturating_sub(copay_amount).saturating_sub(deductible_amount)
+}
 
-        //Hospital bill invoice number string must not be longer than 20 characters
-        require!(hospital_bill_invoice_number.len() <= MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH, InvalidLengthError::HospitalBillInvoiceNumberTooLong);
+// Helper function to make sure approving a claim amount keeps the patient within their lifetime benefit cap
+fn check_lifetime_benefit_cap(patient: &PatientAccount, claim_amount: u64) -> Result<()>
+{
+    //A cap of 0 means the patient's lifetime benefits are unlimited
+    require!(patient.lifetime_benefit_cap_cents == 0 ||
+    patient.approved_claim_amount as u128 + claim_amount as u128 <= patient.lifetime_benefit_cap_cents as u128, InvalidOperationError::LifetimeCapExceeded);
 
-        //Ailment string must not be longer than 45 characters
-        require!(ailment.len() <= MAX_AILMENT_LENGTH, InvalidLengthError::AilmentTooLong);
+    Ok(())
+}
 
-        //Note string must not be longer than 140 characters
-        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+// Helper function to check if a claim is over the dual signoff amount and needs a second processor to approve it
+fn requires_dual_signoff(m4a_protocol: &M4AProtocol, claim_amount: u64) -> bool
+{
+    //A dual signoff amount of 0 means no claim needs a second approval
+    m4a_protocol.dual_signoff_amount_cents != 0 && claim_amount > m4a_protocol.dual_signoff_amount_cents
+}
 
-        //Insurance company name string must not be longer than 35 characters
-        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+// Helper function to approve a claim that has all of its records created, shared by the single and batch approve paths
+fn approve_claim_and_records(
+    processor_stats: &mut ProcessorStats,
+    claim_queue: &mut ClaimQueue,
+    submitter: &mut SubmitterAccount,
+    patient: &mut PatientAccount,
+    state: &mut StateAccount,
+    hospital: &mut Hospital,
+    insurance_company: &mut InsuranceCompany,
+    patient_record: &mut PatientRecord,
+    hospital_record: &mut HospitalRecord,
+    insurance_company_record: &mut InsuranceCompanyRecord,
+    processed_claim: &mut ProcessedClaim,
+    processor: &mut ProcessorAccount,
+    claim: &Claim,
+    processor_address: Pubkey) -> Result<()>
+{
+    //Call the helper function to keep the patient within their lifetime benefit cap
+    check_lifetime_benefit_cap(patient, claim.claim_amount)?;
+
+    processor_stats.approved_claim_count += 1;
+    processor_stats.approved_claim_count_by_type[claim.hospital_type as usize] += 1;
+    processor_stats.approved_amount_buckets[get_approved_amount_bucket(claim.claim_amount)] += 1;
+    processor_stats.processed_claim_count += 1;
+    processor_stats.approved_claim_amount += claim.claim_amount;
+    leave_queue(claim_queue)?;
+    submitter.approved_claim_count += 1;
+    submitter.approved_claim_amount += claim.claim_amount;
+    patient.approved_claim_count += 1;
+    patient.approved_claim_amount += claim.claim_amount;
+    state.approved_claim_count += 1;
+    state.approved_claim_amount += claim.claim_amount;
+    hospital.approved_claim_count += 1;
+    hospital.approved_claim_amount += claim.claim_amount;
+    insurance_company.approved_claim_count += 1;
+    insurance_company.approved_claim_amount += claim.claim_amount;
+    
+    processed_claim.processed_claim_id = processor_stats.processed_claim_count;
+    processed_claim.claim_id = claim.id;
+    processed_claim.processor_count_index = processor.processed_claim_count;
+    processed_claim.status = Status::Approved as u8;
+    processed_claim.is_patient_record_created = true;
+    processed_claim.is_hospital_record_created = true;
+    processed_claim.is_insurance_company_record_created = true;
+    processed_claim.patient_record_index = claim.patient_record_index;
+    processed_claim.hospital_record_index = claim.hospital_record_index;
+    processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
+    processed_claim.processor_address = processor_address;
+    processed_claim.record_creator_address = patient_record.processor_address;
+    processed_claim.resolving_processor_address = processor_address;
+    processed_claim.submitter_address = claim.submitter_address;
+    processed_claim.patient_index = claim.patient_index;
+    processed_claim.country_index = claim.country_index;
+    processed_claim.state_index = claim.state_index;
+    processed_claim.hospital_index = claim.hospital_index;
+    processed_claim.hospital_type = claim.hospital_type;
+    processed_claim.hospital_name = claim.hospital_name.clone();
+    processed_claim.hospital_address = claim.hospital_address.clone();
+    processed_claim.hospital_city = claim.hospital_city.clone();
+    processed_claim.hospital_zip_code = claim.hospital_zip_code;
+    processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
+    processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+    processed_claim.note = claim.note.clone();
+    processed_claim.processor_notes = claim.processor_notes.clone();
+    processed_claim.processor_note_edit_count = claim.processor_note_edit_count;
+    processed_claim.claim_amount = claim.claim_amount;
+    processed_claim.copay_amount = claim.copay_amount;
+    processed_claim.deductible_amount = claim.deductible_amount;
+    processed_claim.insurance_covered_amount = get_insurance_covered_amount(claim.claim_amount, claim.copay_amount, claim.deductible_amount);
+    processed_claim.currency_code = claim.currency_code;
+    processed_claim.fee_mint = claim.fee_mint;
+    processed_claim.fee_amount_paid = claim.fee_amount_paid;
+    processed_claim.ailment = claim.ailment.clone();
+    processed_claim.diagnosis_codes = claim.diagnosis_codes.clone();
+    processed_claim.is_flagged_for_review = claim.is_flagged_for_review;
+    processed_claim.flag_reason = claim.flag_reason.clone();
+    processed_claim.insurance_company_index = claim.insurance_company_index;
+    processed_claim.insurance_company_name = claim.insurance_company_name.clone();
+    processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+    processed_claim.submitted_time = claim.submitted_time;
+    processed_claim.processed_time = Clock::get()?.unix_timestamp as u64;
+    record_sla_breach(processed_claim, processor_stats, claim.sla_deadline);
+
+    patient_record.status = Status::Approved as u8;
+    patient_record.processor_count_index = processor.processed_claim_count;
+    patient_record.processed_time = Clock::get()?.unix_timestamp as u64;
+
+    hospital_record.status = Status::Approved as u8;
+    hospital_record.processor_count_index = processor.processed_claim_count;
+    hospital_record.processed_claim_id = processor_stats.processed_claim_count;
+    hospital_record.processed_time = Clock::get()?.unix_timestamp as u64;
+
+    insurance_company_record.status = Status::Approved as u8;
+    insurance_company_record.processor_count_index = processor.processed_claim_count;
+    insurance_company_record.processed_time = Clock::get()?.unix_timestamp as u64;
+
+    processor.approved_claim_amount += claim.claim_amount;
+    processor.approved_claim_count += 1;
+    processor.processed_claim_count += 1;
+    processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+    update_processor_denial_rate(processor);
+
+    emit!(ClaimApproved
+    {
+        claim_id: processed_claim.claim_id,
+        submitter_address: processed_claim.submitter_address,
+        processor_address: processed_claim.processor_address,
+        processed_claim_id: processed_claim.processed_claim_id,
+        processor_count_index: processed_claim.processor_count_index
+    });
 
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
+    Ok(())
+}
 
-        claim_queue.submitted_claim_count += 1;
-        claim_queue.current_claim_queue_count += 1;
-        patient.submitted_claim_count += 1;
-        submitter.submitted_claim_count += 1;
-        
-        claim.id = claim_queue.submitted_claim_count;
-        claim.submitter_address = ctx.accounts.signer.key();
-        claim.patient_index = patient_index;
-        claim.country_index = country_index.clone();
-        claim.state_index = state_index.clone();
-        claim.hospital_index = hospital_index;
-        claim.hospital_type = hospital_type;
-        claim.hospital_name = hospital_name;
-        claim.hospital_address = hospital_address;
-        claim.hospital_city = hospital_city;
-        claim.hospital_zip_code = hospital_zip_code;
-        claim.hospital_phone_number = hospital_phone_number;
-        claim.hospital_bill_invoice_number = hospital_bill_invoice_number;
-        claim.note = note;
-        claim.claim_amount = claim_amount.clone();
-        claim.ailment = ailment.clone();
-        claim.insurance_company_index = insurance_company_index;
-        claim.insurance_company_name = insurance_company_name;
-        claim.submitted_time = Clock::get()?.unix_timestamp as u64;
-        
-        msg!("New Claim Submited to the Queue");
-        msg!("Submitter Address: {}", ctx.accounts.signer.key());
-        msg!("Patient First Name: {}", patient.patient_first_name);
-        msg!("Patient Last Name: {}", patient.patient_last_name);
-        msg!("Country Index: {}", country_index);
-        msg!("State Index: {}", state_index);
-        msg!("Hospital Index: {}", hospital_index);
-        msg!("Hospital Type: {}", hospital_type);
-        msg!("Claim Info: {}", ailment);
-        msg!("For: ${:.2}", claim_amount as f64/100.00);
-        msg!("Note: {}", claim.note);
+// Helper function to check that an account passed in a batch is the PDA it should be for its claim
+fn require_batch_pda(account: &AccountInfo, seeds: &[&[u8]]) -> Result<()>
+{
+    let (expected_address, _bump) = Pubkey::find_program_address(seeds, &ID);
+    require_keys_eq!(account.key(), expected_address, InvalidOperationError::InvalidBatchAccount);
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+    Ok(())
+}
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_fee_ata.to_account_info(),
-            accounts.treasurer_usdc_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+// Helper function to take a hospital out of the protocol and state type counts when it stops being active
+fn deduct_hospital_type_count(hospital_stats: &mut HospitalStats, state: &mut StateAccount, hospital_type: u8)
+{
+    hospital_stats.hospital_count_by_type[hospital_type as usize] -= 1;
+    state.hospital_count_by_type[hospital_type as usize] -= 1;
 
-        Ok(())
+    if hospital_type == HospitalType::General as u8
+    {
+        hospital_stats.general_hospital_count -= 1;
+        state.general_hospital_count -= 1;
     }
-
-    pub fn assign_claim_to_processor(ctx: Context<AssignClaimToProcessor>, submitter_address: Pubkey) -> Result<()> 
+    else if hospital_type == HospitalType::Dental as u8
     {
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let processor = &mut ctx.accounts.processor;
-        let claim = &mut ctx.accounts.claim;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        hospital_stats.dental_hospital_count -= 1;
+        state.dental_hospital_count -= 1;
+    }
+    else if hospital_type == HospitalType::Vision as u8
+    {
+        hospital_stats.vision_hospital_count -= 1;
+        state.vision_hospital_count -= 1;
+    }
+    else if hospital_type == HospitalType::Mental as u8
+    {
+        hospital_stats.mental_hospital_count -= 1;
+        state.mental_hospital_count -= 1;
+    }
+}
 
-        //Processor must not already be processing any other claim
-        require!(processor.is_processing_claim == false, AuthorizationError::ProcessorAlreadyWorkingOnClaim);
+// Helper function to add a hospital to the protocol and state type counts when it's created or becomes active
+fn add_hospital_type_count(hospital_stats: &mut HospitalStats, state: &mut StateAccount, hospital_type: u8)
+{
+    hospital_stats.hospital_count_by_type[hospital_type as usize] += 1;
+    state.hospital_count_by_type[hospital_type as usize] += 1;
 
-        //A claim can only have one processor
-        require_keys_eq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimAlreadyAssigned);
+    if hospital_type == HospitalType::General as u8
+    {
+        hospital_stats.general_hospital_count += 1;
+        state.general_hospital_count += 1;
+    }
+    else if hospital_type == HospitalType::Dental as u8
+    {
+        hospital_stats.dental_hospital_count += 1;
+        state.dental_hospital_count += 1;
+    }
+    else if hospital_type == HospitalType::Vision as u8
+    {
+        hospital_stats.vision_hospital_count += 1;
+        state.vision_hospital_count += 1;
+    }
+    else if hospital_type == HospitalType::Mental as u8
+    {
+        hospital_stats.mental_hospital_count += 1;
+        state.mental_hospital_count += 1;
+    }
+}
 
-        processor.is_processing_claim = true;
-        processor.submitter_address_of_claim_being_processed = submitter_address.key();
-        claim.processor_address = ctx.accounts.signer.key();
-        claim.status = Status::Processing as u8;
-        processor_stats.set_or_unset_processor_on_claim_count += 1;
+//Functions
+#[program]
+pub mod m_4_a_protocol 
+{
+    use super::*;
 
-        msg!("Claim Assigned To Processor Address: ");
-        msg!("{}", ctx.accounts.signer.key());
+    pub fn initialize_m4a_protocol_admin_accounts(ctx: Context<InitializeAdminAccounts>) -> Result<()> 
+    {
+        //Only the initial CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), INITIAL_CEO_ADDRESS, AuthorizationError::NotCEO);
+
+        let ceo = &mut ctx.accounts.ceo;
+        ceo.address = INITIAL_CEO_ADDRESS;
+
+        let treasurer = &mut ctx.accounts.treasurer;
+        treasurer.address = INITIAL_TREASURER_ADDRESS;
+
+        msg!("M4A Protocol Admin Accounts Initialized");
+        msg!("New CEO Address: {}", ceo.address.key());
+        msg!("New Treasurer Address: {}", treasurer.address.key());
 
         Ok(())
     }
 
-    pub fn reassign_claim_to_new_processor(ctx: Context<ReassignClaimToNewProcessor>, submitter_address: Pubkey) -> Result<()> 
+    pub fn pass_on_m4a_protocol_ceo(ctx: Context<PassOnM4AProtocolCEO>, new_ceo_address: Pubkey) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let new_processor = &mut ctx.accounts.new_processor;
-        let old_processor = &mut ctx.accounts.old_processor;
-        let claim = &mut ctx.accounts.claim;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only an Admin or the CEO can call this function
+        msg!("The M4A Protocol CEO has passed on the title to a new CEO");
+        msg!("New CEO: {}", new_ceo_address.key());
+
+        ceo.address = new_ceo_address.key();
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::PassOnM4AProtocolCEO, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn pass_on_m4a_protocol_treasurer(ctx: Context<PassOnM4AProtocolTreasurer>, new_treasurer_address: Pubkey) -> Result<()> 
+    {
+        let treasurer = &mut ctx.accounts.treasurer;
+        //Only the Treasurer can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
+
+        msg!("The M4A Protocol Treasurer has passed on the title to a new Treasurer");
+        msg!("New Treasurer: {}", new_treasurer_address.key());
+
+        treasurer.address = new_treasurer_address.key();
+
+        Ok(())
+    }
+
+    pub fn set_fees_enabled_flag(ctx: Context<SetFeesEnabledFlag>, fees_enabled: bool) -> Result<()> 
+    {
+        let treasurer = &ctx.accounts.treasurer;
+        //Only the Treasurer can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
+
+        let fee_config = &mut ctx.accounts.fee_config;
+
+        //Can't set flag to the same state
+        require!(fee_config.fees_enabled != fees_enabled, InvalidOperationError::FlagSameState);
+
+        fee_config.fees_enabled = fees_enabled;
+
+        msg!("Fees Enabled Flag Set To: {}", fees_enabled);
+        msg!("Set By Treasurer: {}", ctx.accounts.signer.key());
+
+        Ok(())
+    }
+
+    pub fn add_fee_token_entry(ctx: Context<AddFeeTokenEntry>, token_mint_address: Pubkey, decimal_amount: u8) -> Result<()> 
+    {
+        let ceo = &ctx.accounts.ceo;
+        let treasurer = &ctx.accounts.treasurer;
+        //Fee token curation is a treasury function, so the Treasurer can call this function as well as the CEO
         require!(ctx.accounts.signer.key() == ceo.address.key() ||
-        new_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+        ctx.accounts.signer.key() == treasurer.address.key(), AuthorizationError::NotCEOOrTreasurer);
 
-        //Processor must not already be processing any other claim
-        require!(new_processor.is_processing_claim == false, AuthorizationError::ProcessorAlreadyWorkingOnClaim);
+        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
+        fee_token_entry.token_mint_address = token_mint_address;
+        fee_token_entry.decimal_amount = decimal_amount;
 
-        //A claim can not be unassigned or reassigned if it isn't currently assigned
-        require_keys_neq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimNotAssigned);
+        msg!("Added Fee Token Entry");
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Decimal Amount: {}", decimal_amount);
+            
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::AddFeeTokenEntry, ctx.accounts.signer.key())?;
 
-        new_processor.is_processing_claim = true;
-        new_processor.submitter_address_of_claim_being_processed = submitter_address.key();
-        processor_stats.set_or_unset_processor_on_claim_count += 1;
+        Ok(())
+    }
 
-        //Check if processor is reassigning themself to the same claim for some weird ass reason, do nothing else if so
-        if new_processor.address != claim.processor_address
+    pub fn add_fee_token_entries_batch<'info>(ctx: Context<'_, '_, 'info, 'info, AddFeeTokenEntriesBatch<'info>>, entries: Vec<FeeTokenEntryInput>) -> Result<()> 
+    {
+        let ceo = &ctx.accounts.ceo;
+        let treasurer = &ctx.accounts.treasurer;
+        //Fee token curation is a treasury function, so the Treasurer can call this function as well as the CEO
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        ctx.accounts.signer.key() == treasurer.address.key(), AuthorizationError::NotCEOOrTreasurer);
+
+        //Each entry needs its fee token entry account, and the batch is bounded to stay under the compute limit
+        require!(entries.len() == ctx.remaining_accounts.len() &&
+        !entries.is_empty() &&
+        entries.len() <= MAX_FEE_TOKEN_ENTRY_BATCH_COUNT, InvalidOperationError::InvalidBatchSize);
+
+        let fee_token_entry_space = size_of::<FeeTokenEntry>() + 8;
+
+        for (entry, fee_token_entry_account) in entries.iter().zip(ctx.remaining_accounts.iter())
         {
-            old_processor.is_processing_claim = false;
-            old_processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
+            //Fee token entry is created the same way the init constraint on add fee token entry would
+            let (fee_token_entry_address, fee_token_entry_bump) = Pubkey::find_program_address(&[b"feeTokenEntry".as_ref(), entry.token_mint_address.as_ref()], &ID);
+            require_keys_eq!(fee_token_entry_account.key(), fee_token_entry_address, InvalidOperationError::InvalidBatchAccount);
+
+            //A mint that was already added, or shows up twice in the batch, fails the whole batch
+            require!(fee_token_entry_account.data_is_empty() == true, InvalidOperationError::FeeTokenEntryAlreadyExists);
+
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount
+                    {
+                        from: ctx.accounts.signer.to_account_info(),
+                        to: fee_token_entry_account.clone()
+                    },
+                    &[&[b"feeTokenEntry".as_ref(), entry.token_mint_address.as_ref(), &[fee_token_entry_bump]]]),
+                Rent::get()?.minimum_balance(fee_token_entry_space),
+                fee_token_entry_space as u64,
+                &ID)?;
+
+            let mut fee_token_entry = Account::<FeeTokenEntry>::try_from_unchecked(fee_token_entry_account)?;
+            fee_token_entry.token_mint_address = entry.token_mint_address;
+            fee_token_entry.decimal_amount = entry.decimal_amount;
+            fee_token_entry.exit(&ID)?;
+
+            msg!("Added Fee Token Entry");
+            msg!("Mint Address: {}", entry.token_mint_address);
+            msg!("Decimal Amount: {}", entry.decimal_amount);
         }
 
-        msg!("Claim Reassigned To New Processor Address: ");
-        msg!("{}", ctx.accounts.signer.key());
-        msg!("Old Processor Address: ");
-        msg!("{}", claim.processor_address);
+        msg!("Fee Token Entries Batch Added");
+        msg!("Entries In Batch: {}", entries.len());
 
-        claim.processor_address = ctx.accounts.signer.key();
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::AddFeeTokenEntriesBatch, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn unassign_claim_from_processor(ctx: Context<UnassignClaimFromProcessor>, _submitter_address: Pubkey) -> Result<()> 
+    pub fn update_fee_token_decimals(ctx: Context<UpdateFeeTokenDecimals>, token_mint_address: Pubkey, decimal_amount: u8) -> Result<()> 
     {
-        let ceo = &mut ctx.accounts.ceo;
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let admin_processor = &mut ctx.accounts.admin_processor;
-        let old_processor = &mut ctx.accounts.old_processor;
-        let claim = &mut ctx.accounts.claim;
+        let ceo = &ctx.accounts.ceo;
+        let treasurer = &ctx.accounts.treasurer;
+        //Fee token curation is a treasury function, so the Treasurer can call this function as well as the CEO
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        ctx.accounts.signer.key() == treasurer.address.key(), AuthorizationError::NotCEOOrTreasurer);
 
-        //Only an Admin or the CEO can call this function
+        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
+        fee_token_entry.decimal_amount = decimal_amount;
+
+        msg!("Updated Fee Token Decimals");
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Decimal Amount: {}", decimal_amount);
+            
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::UpdateFeeTokenDecimals, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn set_fee_token_valid_until(ctx: Context<SetFeeTokenValidUntil>, token_mint_address: Pubkey, valid_until: i64) -> Result<()> 
+    {
+        let ceo = &ctx.accounts.ceo;
+        let treasurer = &ctx.accounts.treasurer;
+        //Fee token curation is a treasury function, so the Treasurer can call this function as well as the CEO
         require!(ctx.accounts.signer.key() == ceo.address.key() ||
-        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+        ctx.accounts.signer.key() == treasurer.address.key(), AuthorizationError::NotCEOOrTreasurer);
 
-        //A claim can not be unassigned or reassigned if it isn't currently assigned
-        require_keys_neq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimNotAssigned);
+        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
+        fee_token_entry.valid_until = valid_until;
 
-        old_processor.is_processing_claim = false;
-        old_processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
-        claim.processor_address = SYSTEM_PROGRAM_ADDRESS;
-        claim.status = Status::Pending as u8;
+        msg!("Set Fee Token Valid Until");
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Valid Until: {}", valid_until);
+            
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetFeeTokenValidUntil, ctx.accounts.signer.key())?;
 
-        processor_stats.set_or_unset_processor_on_claim_count += 1;
+        Ok(())
+    }
 
-        msg!("Claim id: {} Unassigned By: ", claim.id);
-        msg!("{}", ctx.accounts.signer.key());
+    pub fn remove_fee_token_entry(ctx: Context<RemoveFeeTokenEntry>,
+        token_mint_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &ctx.accounts.ceo;
+        let treasurer = &ctx.accounts.treasurer;
+        //Fee token curation is a treasury function, so the Treasurer can call this function as well as the CEO
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        ctx.accounts.signer.key() == treasurer.address.key(), AuthorizationError::NotCEOOrTreasurer);
+
+        msg!("Removed Fee Token Entry");
+        msg!("Mint Address: {}", token_mint_address.key());
+            
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::RemoveFeeTokenEntry, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    //For in the event that the claim has already been denied some kind of way and the processor is stuck on a dead claim (Denial Hammer most likely)
-    pub fn set_processor_to_not_processing_claim_state(ctx: Context<SetProcessorToNotProcessingClaimState>, _processor_address: Pubkey) -> Result<()> 
+    pub fn grant_fee_exemption(ctx: Context<GrantFeeExemption>, submitter_address: Pubkey) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let admin_processor = &mut ctx.accounts.admin_processor;
-        let processor = &mut ctx.accounts.processor;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only an Admin or the CEO can call this function
-        require!(ctx.accounts.signer.key() == ceo.address.key() ||
-        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+        let fee_exemption = &mut ctx.accounts.fee_exemption;
+        fee_exemption.submitter_address = submitter_address;
 
-        processor.is_processing_claim = false;
-        processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
-        processor_stats.set_or_unset_processor_on_claim_count += 1;
+        msg!("Granted Fee Exemption");
+        msg!("Submitter Address: {}", submitter_address);
 
-        msg!("Processor Set To Not Processign Claim State By: ");
-        msg!("{}", ctx.accounts.signer.key());
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::GrantFeeExemption, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn create_state_account(ctx: Context<CreateStateAccount>, _submitter_address: Pubkey, country_index: u16, state_index: u32) -> Result<()> 
+    pub fn add_denial_reason_template(ctx: Context<AddDenialReasonTemplate>, code: u16, text: String) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
-        
-        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
-        let state = &mut ctx.accounts.state;
+        //Template text is stored as the denial reason, so it must not be longer than 144 characters
+        require!(text.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
 
-        m4a_protocol.state_account_total += 1;
-        state.id = m4a_protocol.state_account_total;
-        state.index = state_index;
-        
-        msg!("Initialized Country at Index: {}", country_index);
-        msg!("Initialized State at Index: {}", state_index);
-        msg!("State Id: {}", state_index);
+        let denial_reason_template = &mut ctx.accounts.denial_reason_template;
+        denial_reason_template.code = code;
+        denial_reason_template.text = text;
+
+        msg!("Added Denial Reason Template");
+        msg!("Reason Code: {}", code);
+        msg!("Template Text: {}", denial_reason_template.text);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::AddDenialReasonTemplate, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn create_hospital(ctx: Context<CreateHospital>, 
-        _submitter_address: Pubkey,
-        country_index: u16,
-        state_index: u32,
-        hospital_type: u8,
-        hospital_longitude: f64,
-        hospital_latitude: f64,
-        hospital_name: String,
-        hospital_address: String,
-        hospital_city: String,
-        hospital_zip_code: u32,
-        hospital_phone_number: u128,
-        note: String) -> Result<()> 
-    { 
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
-
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+    pub fn update_denial_reason_template(ctx: Context<UpdateDenialReasonTemplate>, code: u16, text: String) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Hospital type must be valid
-        require!((hospital_type == HospitalType::General as u8) ||
-        (hospital_type == HospitalType::Dental as u8) ||
-        (hospital_type == HospitalType::Vision as u8) ||
-        (hospital_type == HospitalType::Mental as u8), InvalidType::HospitalTypeInvalid);
+        //Template text is stored as the denial reason, so it must not be longer than 144 characters
+        require!(text.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
 
-        //Hospital name string must not be longer than 50 characters
-        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+        let denial_reason_template = &mut ctx.accounts.denial_reason_template;
+        denial_reason_template.text = text;
 
-        //Hospital address string must not be longer than 100 characters
-        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalAddressTooLong);
+        msg!("Updated Denial Reason Template");
+        msg!("Reason Code: {}", code);
+        msg!("Template Text: {}", denial_reason_template.text);
 
-        //Hospital city string must not be longer than 40 characters
-        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalCityTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::UpdateDenialReasonTemplate, ctx.accounts.signer.key())?;
 
-        //Note string must not be longer than 140 characters
-        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
-        
-        let hospital_stats = &mut ctx.accounts.hospital_stats;
-        let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        
-        hospital_stats.hospital_count += 1;
-        processor.created_hospital_count += 1;
+        Ok(())
+    }
 
-        claim.country_index = country_index;
-        claim.state_index = state_index;
-        claim.hospital_type = hospital_type;
-        claim.hospital_index = state.hospital_count as i32;
-        claim.hospital_name = hospital_name.clone();
-        claim.hospital_address = hospital_address.clone();
-        claim.hospital_city = hospital_city.clone();
-        claim.hospital_zip_code = hospital_zip_code;
-        claim.hospital_phone_number = hospital_phone_number.clone();
-
-        hospital.id = hospital_stats.hospital_count;
-        hospital.hospital_index = state.hospital_count;
-        hospital.is_active = true;
-        hospital.country_index = country_index;
-        hospital.state_index = state_index;
-        hospital.hospital_type = hospital_type;
-        hospital.hospital_longitude = hospital_longitude;
-        hospital.hospital_latitude = hospital_latitude;
-        hospital.hospital_name = hospital_name;
-        hospital.hospital_address = hospital_address;
-        hospital.hospital_city = hospital_city;
-        hospital.hospital_zip_code = hospital_zip_code;
-        hospital.hospital_phone_number = hospital_phone_number;
-        hospital.note = note;
-
-        state.hospital_count += 1;
+    pub fn revoke_fee_exemption(ctx: Context<RevokeFeeExemption>, submitter_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        if hospital_type == HospitalType::General as u8
-        {
-            hospital_stats.general_hospital_count += 1;
-            state.general_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Dental as u8
-        {
-            hospital_stats.dental_hospital_count += 1;
-            state.dental_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Vision as u8
-        {
-            hospital_stats.vision_hospital_count += 1;
-            state.vision_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Mental as u8
-        {
-            hospital_stats.mental_hospital_count += 1;
-            state.mental_hospital_count += 1;
-        }
+        msg!("Revoked Fee Exemption");
+        msg!("Submitter Address: {}", submitter_address);
 
-        msg!("Hospital Created #{}", hospital.id);
-        msg!("Country Index: {}", country_index);
-        msg!("State Index: {}", state_index);
-        msg!("Hospital Index: {}", state.hospital_count-1);
-        msg!("Hospital Type: {}", hospital.hospital_type);
-        msg!("Longitude: {}", hospital_longitude);
-        msg!("Latitude: {}", hospital_latitude);
-        msg!("Note: {}", hospital.note.clone());
-        msg!("State Hospital Count: {}", state.hospital_count);
-        msg!("M4A Protocol General Hospital Count: {}", hospital_stats.hospital_count);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::RevokeFeeExemption, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn edit_hospital(ctx: Context<EditHospital>, 
-        country_index: u16,
-        state_index: u32,
-        hospital_index: u32,
-        is_active: bool,
-        hospital_type: u8,
-        hospital_longitude: f64,
-        hospital_latitude: f64,
-        hospital_name: String,
-        hospital_address: String,
-        hospital_city: String,
-        hospital_zip_code: u32,
-        hospital_phone_number: u128,
-        note: String) -> Result<()> 
+    pub fn initialize_fee_config(ctx: Context<InitializeFeeConfig>) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
         //Only the CEO can call this function
         require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Hospital type must be valid
-        require!((hospital_type == HospitalType::General as u8) ||
-        (hospital_type == HospitalType::Dental as u8) ||
-        (hospital_type == HospitalType::Vision as u8) ||
-        (hospital_type == HospitalType::Mental as u8), InvalidType::HospitalTypeInvalid);
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.patient_only_appeal_fee_cents = DEFAULT_APPEAL_FEE_CENTS;
+        fee_config.full_appeal_fee_cents = DEFAULT_APPEAL_FEE_CENTS;
+        fee_config.fees_enabled = true;
 
-        //Hospital name string must not be longer than 50 characters
-        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+        msg!("Fee Config Initialized");
+        msg!("Initialized By User: {}", ctx.accounts.signer.key());
 
-        //Hospital address string must not be longer than 100 characters
-        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalNameTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::InitializeFeeConfig, ctx.accounts.signer.key())?;
 
-        //Hospital city string must not be longer than 40 characters
-        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalNameTooLong);
+        Ok(())
+    }
 
-        //Note string must not be longer than 140 characters
-        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::HospitalNameTooLong);
+    pub fn set_preferred_fee_mint(ctx: Context<SetPreferredFeeMint>, token_mint_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let hospital_stats = &mut ctx.accounts.hospital_stats;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.preferred_fee_mint = token_mint_address;
 
-        //Wait to deduct previous hospital type before setting it to the hospital
-        hospital.is_active = is_active;
-        hospital.hospital_longitude = hospital_longitude;
-        hospital.hospital_latitude = hospital_latitude;
-        hospital.hospital_name = hospital_name;
-        hospital.hospital_address = hospital_address;
-        hospital.hospital_city = hospital_city;
-        hospital.hospital_zip_code = hospital_zip_code;
-        hospital.hospital_phone_number = hospital_phone_number;
-        hospital.note = note;
+        msg!("Set Preferred Fee Mint");
+        msg!("Mint Address: {}", token_mint_address.key());
+            
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetPreferredFeeMint, ctx.accounts.signer.key())?;
 
-        //Deduct previous type from count
-        if hospital.hospital_type == HospitalType::General as u8
-        {
-            hospital_stats.general_hospital_count -= 1;
-            state.general_hospital_count -= 1;
-        }
-        else if hospital.hospital_type == HospitalType::Dental as u8
-        {
-            hospital_stats.dental_hospital_count -= 1;
-            state.dental_hospital_count -= 1;
-        }
-        else if hospital.hospital_type == HospitalType::Vision as u8
-        {
-            hospital_stats.vision_hospital_count -= 1;
-            state.vision_hospital_count -= 1;
-        }
-        else if hospital.hospital_type == HospitalType::Mental as u8
-        {
-            hospital_stats.mental_hospital_count -= 1;
-            state.mental_hospital_count -= 1;
-        }
+        Ok(())
+    }
 
-        //Add new type to count
-        if hospital_type == HospitalType::General as u8
-        {
-            hospital_stats.general_hospital_count += 1;
-            state.general_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Dental as u8
-        {
-            hospital_stats.dental_hospital_count += 1;
-            state.dental_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Vision as u8
-        {
-            hospital_stats.vision_hospital_count += 1;
-            state.vision_hospital_count += 1;
-        }
-        else if hospital_type == HospitalType::Mental as u8
-        {
-            hospital_stats.mental_hospital_count += 1;
-            state.mental_hospital_count += 1;
-        }
+    pub fn set_appeal_fees(ctx: Context<SetAppealFees>, patient_only_appeal_fee_cents: u64, full_appeal_fee_cents: u64) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        hospital_stats.edited_hospital_count += 1;
-        state.edited_hospital_count += 1;
-    
-        //Set new hospital type
-        hospital.hospital_type = hospital_type;
+        let fee_config = &mut ctx.accounts.fee_config;
+        fee_config.patient_only_appeal_fee_cents = patient_only_appeal_fee_cents;
+        fee_config.full_appeal_fee_cents = full_appeal_fee_cents;
 
-        msg!("Hospital Edited");
-        msg!("Country Index: {}", country_index);
-        msg!("State Index: {}", state_index);
-        msg!("Hospital Index: {}", hospital_index);
-        msg!("Hospital Active: {}", is_active);
-        msg!("Hospital Type: {}", hospital.hospital_type);
-        msg!("Hospital Name: {}", hospital.hospital_name);
-        msg!("Hospital Address: {}", hospital.hospital_address);
-        msg!("Hospital City: {}", hospital.hospital_city);
-        msg!("Hospital ZipCode: {}", hospital.hospital_zip_code);
-        msg!("Hospital PhoneNumber: {}", hospital.hospital_phone_number);
-        msg!("Longitude: {}", hospital_longitude);
-        msg!("Latitude: {}", hospital_latitude);
-        msg!("Note: {}", hospital.note.clone());
+        msg!("Set Appeal Fees");
+        msg!("Patient Only Appeal Fee: ${:.2}", patient_only_appeal_fee_cents as f64/100.00);
+        msg!("Full Appeal Fee: ${:.2}", full_appeal_fee_cents as f64/100.00);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetAppealFees, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn create_insurance_company(ctx: Context<CreateInsuranceCompany>, 
-        _submitter_address: Pubkey, 
-        insurance_company_index: u16,
-        insurance_company_name: String,
-        note: String) -> Result<()> 
+    pub fn initialize_field_limits(ctx: Context<InitializeFieldLimits>) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        //Start out at the hard caps so nothing changes until the CEO tunes them
+        let field_limits = &mut ctx.accounts.field_limits;
+        field_limits.hospital_name_length = MAX_HOSPITAL_NAME_LENGTH as u32;
+        field_limits.hospital_address_length = MAX_HOSPITAL_ADDRESS_LENGTH as u32;
+        field_limits.hospital_city_length = MAX_HOSPITAL_CITY_LENGTH as u32;
+        field_limits.hospital_bill_invoice_number_length = MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH as u32;
+        field_limits.note_length = MAX_NOTE_LENGTH as u32;
+        field_limits.ailment_length = MAX_AILMENT_LENGTH as u32;
+        field_limits.diagnosis_code_count = MAX_DIAGNOSIS_CODE_COUNT as u32;
+        field_limits.diagnosis_code_length = MAX_DIAGNOSIS_CODE_LENGTH as u32;
+        field_limits.insurance_company_name_length = MAX_INSURANCE_COMPANY_NAME_LENGTH as u32;
+
+        msg!("Field Limits Initialized");
+        msg!("Initialized By User: {}", ctx.accounts.signer.key());
 
-        //Insurance company name string must not be longer than 35 characters
-        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::InitializeFieldLimits, ctx.accounts.signer.key())?;
 
-        //Note string must not be longer than 140 characters
-        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        Ok(())
+    }
 
-        let insurance_company_stats = &mut ctx.accounts.insurance_company_stats;
-        let processor = &mut ctx.accounts.processor;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        
-        claim.insurance_company_index = insurance_company_index as i16;
-        claim.insurance_company_name = insurance_company_name.clone();
-   
-        insurance_company.is_active = true;
-        insurance_company.note = note;
-        insurance_company.insurance_company_name = insurance_company_name.clone();
-        
-        insurance_company_stats.initialized_insurance_company_count += 1;
-        insurance_company.id = insurance_company_stats.initialized_insurance_company_count;
-        insurance_company.insurance_company_index = insurance_company_index;
+    pub fn set_field_limits(ctx: Context<SetFieldLimits>, limits: FieldLimitsInput) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        if insurance_company_index > 10
+        //Limits can be lowered, but never raised past what the accounts are sized for
+        let limit_hard_caps = [
+            (limits.hospital_name_length, MAX_HOSPITAL_NAME_LENGTH),
+            (limits.hospital_address_length, MAX_HOSPITAL_ADDRESS_LENGTH),
+            (limits.hospital_city_length, MAX_HOSPITAL_CITY_LENGTH),
+            (limits.hospital_bill_invoice_number_length, MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH),
+            (limits.note_length, MAX_NOTE_LENGTH),
+            (limits.ailment_length, MAX_AILMENT_LENGTH),
+            (limits.diagnosis_code_count, MAX_DIAGNOSIS_CODE_COUNT),
+            (limits.diagnosis_code_length, MAX_DIAGNOSIS_CODE_LENGTH),
+            (limits.insurance_company_name_length, MAX_INSURANCE_COMPANY_NAME_LENGTH)];
+
+        for (limit, hard_cap) in limit_hard_caps
         {
-            insurance_company_stats.additional_insurance_company_count += 1;
+            require!(limit > 0 && limit as usize <= hard_cap, InvalidOperationError::FieldLimitOutOfRange);
         }
 
-        processor.created_insurance_company_count += 1;
+        let field_limits = &mut ctx.accounts.field_limits;
+        field_limits.hospital_name_length = limits.hospital_name_length;
+        field_limits.hospital_address_length = limits.hospital_address_length;
+        field_limits.hospital_city_length = limits.hospital_city_length;
+        field_limits.hospital_bill_invoice_number_length = limits.hospital_bill_invoice_number_length;
+        field_limits.note_length = limits.note_length;
+        field_limits.ailment_length = limits.ailment_length;
+        field_limits.diagnosis_code_count = limits.diagnosis_code_count;
+        field_limits.diagnosis_code_length = limits.diagnosis_code_length;
+        field_limits.insurance_company_name_length = limits.insurance_company_name_length;
 
-        msg!("Insurance Company Initialized");
-        msg!("Insurance Company Index: {}", insurance_company_index);
-        msg!("Insurance Company Name: {}", insurance_company_name.clone());
-        msg!("Note: {}", insurance_company.note.clone());
+        msg!("Set Field Limits");
+        msg!("Note Length: {}", field_limits.note_length);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetFieldLimits, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn edit_insurance_company(ctx: Context<EditInsuranceCompany>, 
-        insurance_company_index: u16,
-        is_active: bool,
-        insurance_company_name: String,
-        note: String) -> Result<()> 
+    pub fn correct_patient_record_count(ctx: Context<CorrectPatientRecordCount>, submitter_address: Pubkey, patient_index: u8, record_count: u32) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
         //Only the CEO can call this function
         require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Insurance company name string must not be longer than 35 characters
-        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
-
-        //Note string must not be longer than 140 characters
-        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
-
-        let insurance_company_stats = &mut ctx.accounts.insurance_company_stats;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        
-        insurance_company.is_active = is_active;
-        insurance_company.insurance_company_name = insurance_company_name.clone();
-        insurance_company.note = note.clone();
+        let patient = &mut ctx.accounts.patient;
+        patient.record_count = record_count;
 
-        insurance_company_stats.edited_insurance_company_count += 1;
+        msg!("Corrected Patient Record Count");
+        msg!("Submitter Address: {}", submitter_address);
+        msg!("Patient Index: {}", patient_index);
+        msg!("Record Count: {}", record_count);
 
-        msg!("Insurance Company Edited");
-        msg!("Insurance Company Index: {}", insurance_company_index);
-        msg!("Insurance Company Active: {}", is_active);
-        msg!("Insurance Company Name: {}", insurance_company_name);
-        msg!("Note: {}", note);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::CorrectPatientRecordCount, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn update_claim_hospital_index(ctx: Context<UpdateClaim>,
-        _submitter_address: Pubkey,
-        hospital_index: u32
-    ) -> Result<()> 
+    pub fn get_preferred_fee_mint(ctx: Context<GetFeeConfig>) -> Result<Pubkey> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        Ok(ctx.accounts.fee_config.preferred_fee_mint)
+    }
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+    pub fn get_roles(ctx: Context<GetRoles>, who: Pubkey) -> Result<u8> 
+    {
+        let mut roles: u8 = 0;
 
-        //Can't set different hospital index after hospital record has been created
-        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
- 
-        let processor_stats = &mut ctx.accounts.processor_stats;
+        if ctx.accounts.ceo.address == who
+        {
+            roles |= Role::Ceo as u8;
+        }
 
-        processor_stats.edited_claim_or_processed_claim_count += 1;
-        claim.hospital_index = hospital_index as i32;
-        
-        msg!("Claim Hospital Index updated");
-        msg!("Hospital Index: {}", hospital_index);
+        if ctx.accounts.treasurer.address == who
+        {
+            roles |= Role::Treasurer as u8;
+        }
 
-        Ok(())
+        //No processor account just means they aren't a processor
+        if ctx.accounts.processor.data_is_empty() == false
+        {
+            let processor_data = ctx.accounts.processor.try_borrow_data()?;
+            let processor = ProcessorAccount::try_deserialize(&mut &processor_data[..])?;
+
+            if processor.is_active == true
+            {
+                roles |= Role::Processor as u8;
+            }
+
+            if processor.is_super_admin == true
+            {
+                roles |= Role::SuperAdmin as u8;
+            }
+        }
+
+        Ok(roles)
     }
 
-    pub fn update_claim_insurance_company_index(ctx: Context<UpdateClaim>,
-        _submitter_address: Pubkey,
-        insurance_company_index: u16
-    ) -> Result<()> 
+    pub fn can_submit(ctx: Context<CanSubmit>, submitter_address: Pubkey, _patient_index: u8, _fee_mint: Pubkey) -> Result<CanSubmitResult> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let claim_queue = &ctx.accounts.claim_queue;
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        //The patient and fee token entry may not exist yet, so they're only loaded when they do
+        let patient = if ctx.accounts.patient.data_is_empty() == false
+        {
+            let patient_data = ctx.accounts.patient.try_borrow_data()?;
+            Some(PatientAccount::try_deserialize(&mut &patient_data[..])?)
+        }
+        else
+        {
+            None
+        };
 
-        //Can't set different insurance company index after insurance company record has been created
-        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        let fee_token_entry = if ctx.accounts.fee_token_entry.data_is_empty() == false
+        {
+            Some(load_fee_token_entry(&ctx.accounts.fee_token_entry)?)
+        }
+        else
+        {
+            None
+        };
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
+        //The gates submit_claim_to_queue would fail on, so the client doesn't have to combine them itself
+        let reason = if claim_queue.enabled == false
+        {
+            CanSubmitReason::ClaimQueueDisabled
+        }
+        else if claim_queue.current_claim_queue_count + 1 > claim_queue.queue_size_limit
+        {
+            CanSubmitReason::ClaimQueueFull
+        }
+        else if claim_queue.current_claim_queue_count + 1 > MAX_ABSOLUTE_QUEUE
+        {
+            CanSubmitReason::AbsoluteQueueLimitReached
+        }
+        else if ctx.accounts.submitter.data_is_empty() == true && ctx.accounts.m4a_protocol.account_creation_enabled == false
+        {
+            CanSubmitReason::AccountCreationDisabled
+        }
+        else if ctx.accounts.submitter.data_is_empty() == true
+        {
+            CanSubmitReason::SubmitterAccountMissing
+        }
+        //Submitters can only have 1 open claim at a time since the claim PDA is seeded by their address
+        else if ctx.accounts.claim.data_is_empty() == false
+        {
+            CanSubmitReason::ClaimAlreadyOpen
+        }
+        else if patient.is_none()
+        {
+            CanSubmitReason::PatientAccountMissing
+        }
+        else if patient.as_ref().is_some_and(|patient| patient.submitter_address.key() != submitter_address.key())
+        {
+            CanSubmitReason::NotPatientOwner
+        }
+        else if patient.as_ref().is_some_and(|patient| patient.consent_given == false)
+        {
+            CanSubmitReason::PatientConsentMissing
+        }
+        else if fee_token_entry.is_none()
+        {
+            CanSubmitReason::FeeTokenNotSupported
+        }
+        //Uses the same expiry check submit_claim_to_queue charges the fee with
+        else if fee_token_entry.as_ref().is_some_and(|fee_token_entry| check_fee_token_not_expired(fee_token_entry).is_err())
+        {
+            CanSubmitReason::FeeTokenExpired
+        }
+        else
+        {
+            CanSubmitReason::CanSubmit
+        };
 
-        processor_stats.edited_claim_or_processed_claim_count += 1;
-        claim.insurance_company_index = insurance_company_index as i16;
-        
-        msg!("Claim Insurance Company Index updated");
-        msg!("Insurance Company Index: {}", insurance_company_index);
+        Ok(CanSubmitResult
+        {
+            can_submit: matches!(reason, CanSubmitReason::CanSubmit),
+            reason_code: reason as u8
+        })
+    }
 
-        Ok(())
+    pub fn open_claim_rent_estimate(ctx: Context<OpenClaimRentEstimate>) -> Result<u64> 
+    {
+        //Every claim is allocated the same space, so this only drifts if that space changes while claims are open
+        let claim_space = size_of::<Claim>() + CLAIM_EXTRA_SIZE + 8;
+        let rent_per_claim = Rent::get()?.minimum_balance(claim_space);
+
+        Ok(ctx.accounts.claim_queue.current_claim_queue_count as u64 * rent_per_claim)
     }
 
-    pub fn create_patient_record(ctx: Context<CreatePatientRecord>, _submitter_address: Pubkey) -> Result<()> 
+    pub fn get_processor_current_claim(ctx: Context<GetProcessorCurrentClaim>, _processor_address: Pubkey) -> Result<ProcessorCurrentClaim> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let processor = &ctx.accounts.processor;
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        Ok(ProcessorCurrentClaim
+        {
+            submitter_address: processor.submitter_address_of_claim_being_processed,
+            is_processing_claim: processor.is_processing_claim
+        })
+    }
 
-        //Only create 1 patient record per claim
-        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+    pub fn get_processed_claim_summaries<'info>(ctx: Context<'_, '_, 'info, 'info, GetProcessedClaimSummaries<'info>>, processor_address: Pubkey, start_index: u64, count: u8) -> Result<Vec<ProcessedClaimSummary>> 
+    {
+        let processor = &ctx.accounts.processor;
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.created_patient_record_count += 1;
-        
-        let patient = &mut ctx.accounts.patient;
-        let patient_record = &mut ctx.accounts.patient_record;
+        //Keeps the summaries within the return data limit
+        require!(count as usize <= MAX_PROCESSED_CLAIM_SUMMARY_COUNT, InvalidOperationError::InvalidBatchSize);
 
-        claim.patient_record_index = patient.record_count;
-        claim.is_patient_record_created = true;
-        patient.record_count += 1;
-        patient_record.record_id = patient.record_count as u32;
-        patient_record.claim_id = claim.id as u32;
-        patient_record.status = Status::Processing as u8;
-        patient_record.patient_record_only = true;
-        patient_record.submitter_address = claim.submitter_address;
-        patient_record.processor_address = ctx.accounts.signer.key();
-        patient_record.country_index = claim.country_index;
-        patient_record.state_index = claim.state_index;
-        patient_record.hospital_index = claim.hospital_index as u32;
-        patient_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        patient_record.claim_amount = claim.claim_amount;
-        patient_record.ailment = claim.ailment.clone();
-        patient_record.note = claim.note.clone();
-        patient_record.submitted_time = claim.submitted_time;
-        patient_record.insurance_company_index = claim.insurance_company_index as u16;
+        //The processed claims in the range must be passed in, in processor count index order
+        require!(ctx.remaining_accounts.len() == count as usize &&
+        start_index.checked_add(count as u64).ok_or(InvalidOperationError::InvalidBatchSize)? <= processor.processed_claim_count, InvalidOperationError::InvalidBatchSize);
 
-        processor.created_patient_record_count += 1;
+        let mut summaries: Vec<ProcessedClaimSummary> = Vec::with_capacity(count as usize);
+        for (offset, processed_claim_account) in ctx.remaining_accounts.iter().enumerate()
+        {
+            let processor_count_index = start_index + offset as u64;
+            require_batch_pda(processed_claim_account, &[b"processedClaim".as_ref(), processor_address.as_ref(), processor_count_index.to_le_bytes().as_ref()])?;
 
-        msg!("Patient Record Created");
-        msg!("Record ID: {}", patient.record_count);
-        msg!("Claim ID: {}", patient_record.claim_id);
-        msg!("Submitter Address: {}", claim.submitter_address);
-        msg!("Patient Index: {}", claim.patient_index);
-        msg!("Claim Amount: ${:.2}", patient_record.claim_amount as f64/100.00);
-        
-        Ok(())
+            let processed_claim = Account::<ProcessedClaim>::try_from(processed_claim_account)?;
+            summaries.push(ProcessedClaimSummary
+            {
+                processed_claim_id: processed_claim.processed_claim_id,
+                status: processed_claim.status,
+                claim_amount: processed_claim.claim_amount,
+                processed_time: processed_claim.processed_time
+            });
+        }
+
+        Ok(summaries)
     }
 
-    pub fn create_hospital_and_insurance_company_records(ctx: Context<CreateHospitalAndInsuranceCompanyRecords>, _submitter_address: Pubkey) -> Result<()> 
+    pub fn build_patient_manifest<'info>(ctx: Context<'_, '_, 'info, 'info, BuildPatientManifest<'info>>, patient_index: u8) -> Result<[u8; 32]> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let patient = &ctx.accounts.patient;
+        let submitter_address = ctx.accounts.signer.key();
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        //Every record the patient has must be passed in, in record index order
+        require!(ctx.remaining_accounts.len() == patient.record_count as usize, InvalidOperationError::InvalidBatchSize);
 
-        //Patient Record must already exist
-        require!(claim.is_patient_record_created == true, InvalidOperationError::RecordNotCreated);
+        //Each record folds its ID and status into the running hash, so the manifest changes if any record is missing, reordered, or changes status
+        let mut manifest = [0u8; 32];
+        for (record_index, record_account) in ctx.remaining_accounts.iter().enumerate()
+        {
+            require_batch_pda(record_account, &[b"patientRecord".as_ref(), submitter_address.as_ref(), patient_index.to_le_bytes().as_ref(), (record_index as u32).to_le_bytes().as_ref()])?;
 
-        //Only create 1 hospital record per claim
-        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+            let patient_record = Account::<PatientRecord>::try_from(record_account)?;
+            manifest = hashv(&[manifest.as_ref(), patient_record.record_id.to_le_bytes().as_ref(), [patient_record.status].as_ref()]).to_bytes();
+        }
 
-        //Only create 1 insurance company record per claim
-        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        Ok(manifest)
+    }
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.created_hospital_and_insurance_company_records_count += 1;
+    pub fn initialize_m4a_protocol_and_claim_queue(ctx: Context<InitializeM4AProtocolAndClaimQueue>, initial_queue_size: u32) -> Result<()> 
+    {
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.m4a_protocol_initiator_address = ctx.accounts.signer.key();
+        m4a_protocol.max_appeals_per_claim = 1;//Set max appeals per claim to 1
+        m4a_protocol.max_upward_edit_bps = 0;//Upward claim amount edits aren't bounded until the CEO sets this
+        m4a_protocol.account_creation_enabled = true;
+        m4a_protocol.sla_seconds = DEFAULT_SLA_SECONDS;
+        m4a_protocol.min_claim_amount_cents = 0;//Claims of any amount are accepted until the CEO sets this
+        m4a_protocol.auto_flag_amount_cents = 0;//Claims aren't auto flagged until the CEO sets this
+        m4a_protocol.dual_signoff_amount_cents = 0;//Claims don't need a second approval until the CEO sets this
+        m4a_protocol.max_reassignments = 0;//Claims aren't auto flagged for being reassigned until the CEO sets this
+        m4a_protocol.assignment_cooldown_seconds = 0;//Processors can pick a claim right back up until the CEO sets this
+        m4a_protocol.max_daily_processing = 0;//Processors can resolve any number of claims a day until the CEO sets this
+        m4a_protocol.min_queue_size = DEFAULT_MIN_QUEUE_SIZE;
+        m4a_protocol.max_queue_size = DEFAULT_MAX_QUEUE_SIZE;
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.patient_record_only = false;
+        let claim_queue = &mut ctx.accounts.claim_queue;
+        claim_queue.enabled = true;
 
-        let hospital = &mut ctx.accounts.hospital;
-        let hospital_record = &mut ctx.accounts.hospital_record;
+        //Set Claim Queue initial size to 100 if no size was given
+        if initial_queue_size == 0
+        {
+            claim_queue.queue_size_limit = 100;
+        }
+        else
+        {
+            //Same range edit_claim_queue_size holds the size to, so the queue doesn't start out at a size it could never be edited to
+            require!(initial_queue_size >= m4a_protocol.min_queue_size &&
+            initial_queue_size <= m4a_protocol.max_queue_size, InvalidOperationError::QueueSizeOutOfRange);
 
-        claim.hospital_record_index = hospital.record_count;
-        claim.is_hospital_record_created = true;
-        hospital.record_count += 1;
-        hospital_record.record_id = hospital.record_count;
-        hospital_record.claim_id = claim.id;
-        hospital_record.status = Status::Processing as u8;
-        hospital_record.submitter_address = claim.submitter_address;
-        hospital_record.patient_index = claim.patient_index;
-        hospital_record.processor_address = ctx.accounts.signer.key();
-        hospital_record.claim_amount = claim.claim_amount;
-        hospital_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        hospital_record.ailment = claim.ailment.clone();
-        hospital_record.note = claim.note.clone();
-        hospital_record.submitted_time = claim.submitted_time;
-        hospital_record.insurance_company_index = claim.insurance_company_index  as u16;
-        
-        processor.created_hospital_record_count += 1;
+            claim_queue.queue_size_limit = initial_queue_size;
+        }
 
-        msg!("Hospital Record Created");
-        msg!("Record ID: {}", hospital.record_count);
-        msg!("Claim ID: {}", hospital_record.claim_id);
-        msg!("Hospital Index: {}", claim.hospital_index);
-        msg!("Claim Amount: ${:.2}", hospital_record.claim_amount as f64/100.00);
+        msg!("M4A Protocol And Claim Que Initialized");
+        msg!("Claim Queue Size Limit: {}", claim_queue.queue_size_limit);
+        msg!("Initialized By User: {}", ctx.accounts.signer.key());
 
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        Ok(())
+    }
 
-        claim.insurance_company_record_index = insurance_company.record_count;
-        claim.is_insurance_company_record_created = true;
-        insurance_company.record_count += 1;
-        insurance_company_record.record_id = insurance_company.record_count;
-        insurance_company_record.claim_id = claim.id;
-        insurance_company_record.status = Status::Processing as u8;
-        insurance_company_record.submitter_address = claim.submitter_address;
-        insurance_company_record.patient_index = claim.patient_index;
-        insurance_company_record.processor_address = ctx.accounts.signer.key();
-        insurance_company_record.country_index = claim.country_index;
-        insurance_company_record.state_index = claim.state_index;
-        insurance_company_record.hospital_index = claim.hospital_index as u32;
-        insurance_company_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        insurance_company_record.claim_amount = claim.claim_amount;
-        insurance_company_record.ailment = claim.ailment.clone();
-        insurance_company_record.note = claim.note.clone();
-        insurance_company_record.submitted_time = claim.submitted_time;
+    pub fn initialize_protocol_stats(ctx: Context<InitializeProtocolStats>) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        processor.created_insurance_company_record_count += 1;
+        msg!("Protocol Stats Initialized");
+        msg!("Initialized By User: {}", ctx.accounts.signer.key());
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::InitializeProtocolStats, ctx.accounts.signer.key())?;
 
-        msg!("Insurance Company Record Created");
-        msg!("Record ID: {}", insurance_company.record_count);
-        msg!("Claim ID: {}", insurance_company_record.claim_id);
-        msg!("Insurance Company Index: {}", claim.insurance_company_index);
-        msg!("Claim Amount: ${:.2}", insurance_company_record.claim_amount as f64/100.00);
-        
         Ok(())
     }
 
-    pub fn approve_claim(ctx: Context<ApproveClaim>, _submitter_address: Pubkey) -> Result<()> 
+    pub fn set_claim_queue_flag(ctx: Context<SetClaimQueueFlag>, is_enabled: bool) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
-
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
         let claim_queue = &mut ctx.accounts.claim_queue;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
+        //The flag can't be set to the same state
+        require!(claim_queue.enabled != is_enabled, InvalidOperationError::FlagSameState);
 
-        processor_stats.approved_claim_count += 1;
-        processor_stats.processed_claim_count += 1;
-        processor_stats.approved_claim_amount += claim.claim_amount;
-        claim_queue.current_claim_queue_count -= 1;
-        submitter.approved_claim_count += 1;
-        submitter.approved_claim_amount += claim.claim_amount;
-        patient.approved_claim_count += 1;
-        patient.approved_claim_amount += claim.claim_amount;
-        state.approved_claim_count += 1;
-        state.approved_claim_amount += claim.claim_amount;
-        hospital.approved_claim_count += 1;
-        hospital.approved_claim_amount += claim.claim_amount;
-        insurance_company.approved_claim_count += 1;
-        insurance_company.approved_claim_amount += claim.claim_amount;
+        claim_queue.enabled = is_enabled;
         
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
-        processed_claim.claim_id = claim.id;
-        processed_claim.processor_count_index = processor.processed_claim_count;
-        processed_claim.status = Status::Approved as u8;
-        processed_claim.is_patient_record_created = true;
-        processed_claim.is_hospital_record_created = true;
-        processed_claim.is_insurance_company_record_created = true;
-        processed_claim.patient_record_index = claim.patient_record_index;
-        processed_claim.hospital_record_index = claim.hospital_record_index;
-        processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
-        processed_claim.processor_address = ctx.accounts.signer.key();
-        processed_claim.submitter_address = claim.submitter_address;
-        processed_claim.patient_index = claim.patient_index;
-        processed_claim.country_index = claim.country_index;
-        processed_claim.state_index = claim.state_index;
-        processed_claim.hospital_index = claim.hospital_index;
-        processed_claim.hospital_type = claim.hospital_type;
-        processed_claim.hospital_name = claim.hospital_name.clone();
-        processed_claim.hospital_address = claim.hospital_address.clone();
-        processed_claim.hospital_city = claim.hospital_city.clone();
-        processed_claim.hospital_zip_code = claim.hospital_zip_code;
-        processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
-        processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        processed_claim.note = claim.note.clone();
-        processed_claim.claim_amount = claim.claim_amount;
-        processed_claim.ailment = claim.ailment.clone();
-        processed_claim.insurance_company_index = claim.insurance_company_index;
-        processed_claim.insurance_company_name = claim.insurance_company_name.clone();
-        processed_claim.submitted_time = claim.submitted_time;
-        processed_claim.processed_time = Clock::get()?.unix_timestamp as u64;
+        msg!("Set Claim Queue Flag");
+        msg!("Set to {}", is_enabled);
+        
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetClaimQueueFlag, ctx.accounts.signer.key())?;
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Approved as u8;
-        patient_record.processor_count_index = processor.processed_claim_count;
-        patient_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        Ok(())
+    }
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.status = Status::Approved as u8;
-        hospital_record.processor_count_index = processor.processed_claim_count;
-        hospital_record.processed_time = Clock::get()?.unix_timestamp as u64;
+    pub fn edit_claim_queue_size(ctx: Context<EditClaimQueueSize>, new_size_limit: u32) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.status = Status::Approved as u8;
-        insurance_company_record.processor_count_index = processor.processed_claim_count;
-        insurance_company_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        //A 0 size would quietly turn off submissions and a huge one would overflow downstream math, so the size has to be in range
+        require!(new_size_limit >= ctx.accounts.m4a_protocol.min_queue_size &&
+        new_size_limit <= ctx.accounts.m4a_protocol.max_queue_size, InvalidOperationError::QueueSizeOutOfRange);
 
-        processor.approved_claim_amount += claim.claim_amount;
-        processor.approved_claim_count += 1;
-        processor.processed_claim_count += 1;
-        processor.is_processing_claim = false;
+        let claim_queue = &mut ctx.accounts.claim_queue;
+        claim_queue.queue_size_limit = new_size_limit;
 
-        msg!("New Claim Approved");
-        msg!("For: ${:.2}", processed_claim.claim_amount as f64/100.00);
-        msg!("Approved Claim Count: {}", processor_stats.approved_claim_count);
-        msg!("User Address: {}", processed_claim.submitter_address);
-        msg!("Patient First Name: {}", patient.patient_first_name);
-        msg!("Patient Last Name: {}", patient.patient_last_name);
+        msg!("Claim Queue Initialized");
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::EditClaimQueueSize, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn approve_claim_with_edits(ctx: Context<ApproveClaimWithEdits>, 
-        _submitter_address: Pubkey,
-        hospital_type: u8,
-        hospital_longitude: f64,
-        hospital_latitude: f64,
-        hospital_name: String,
-        hospital_address: String,
-        hospital_city: String,
-        hospital_zip_code: u32,
-        hospital_phone_number: u128,
-        hospital_bill_invoice_number: String,
-        claim_note: String,
-        claim_amount: u64,
-        ailment: String,
-        insurance_company_name: String,) -> Result<()> 
+    pub fn set_queue_size_bounds(ctx: Context<SetQueueSizeBounds>, min_queue_size: u32, max_queue_size: u32) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        //Min can't be 0 and can't be more than the max
+        require!(min_queue_size >= 1 && min_queue_size <= max_queue_size, InvalidOperationError::QueueSizeOutOfRange);
 
-        //Hospital type must be valid
-        require!((hospital_type == HospitalType::General as u8) ||
-        (hospital_type == HospitalType::Dental as u8) ||
-        (hospital_type == HospitalType::Vision as u8) ||
-        (hospital_type == HospitalType::Mental as u8), InvalidType::HospitalTypeInvalid);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.min_queue_size = min_queue_size;
+        m4a_protocol.max_queue_size = max_queue_size;
 
-        //Hospital name string must not be longer than 50 characters
-        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+        msg!("Set Queue Size Bounds");
+        msg!("Min Queue Size: {}", min_queue_size);
+        msg!("Max Queue Size: {}", max_queue_size);
 
-        //Hospital address string must not be longer than 100 characters
-        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalAddressTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetQueueSizeBounds, ctx.accounts.signer.key())?;
 
-        //Hospital city string must not be longer than 40 characters
-        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalCityTooLong);
+        Ok(())
+    }
 
-        //Hospital bill invoice number string must not be longer than 20 characters
-        require!(hospital_bill_invoice_number.len() <= MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH, InvalidLengthError::HospitalBillInvoiceNumberTooLong);
+    pub fn set_max_appeals_per_claim(ctx: Context<SetMaxAppealsPerClaim>, max_appeals_per_claim: u8) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Ailment string must not be longer than 45 characters
-        require!(ailment.len() <= MAX_AILMENT_LENGTH, InvalidLengthError::AilmentTooLong);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.max_appeals_per_claim = max_appeals_per_claim;
 
-        //Note string must not be longer than 140 characters
-        require!(claim_note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        msg!("Set Max Appeals Per Claim");
+        msg!("Set to {}", max_appeals_per_claim);
 
-        //Insurance company name string must not be longer than 35 characters
-        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetMaxAppealsPerClaim, ctx.accounts.signer.key())?;
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let claim_queue = &mut ctx.accounts.claim_queue;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
+        Ok(())
+    }
 
-        //Update Amount Totals & Counts
-        processor_stats.approved_claim_count += 1;
-        processor_stats.processed_claim_count += 1;
-        processor_stats.approved_claim_amount += claim_amount;
-        claim_queue.current_claim_queue_count -= 1;
-        submitter.approved_claim_count += 1;
-        submitter.approved_claim_amount += claim_amount;
-        patient.approved_claim_count += 1;
-        patient.approved_claim_amount += claim_amount;
-        state.approved_claim_count += 1;
-        state.approved_claim_amount += claim_amount;
-        hospital.approved_claim_count += 1;
-        hospital.approved_claim_amount += claim_amount;
-        insurance_company.approved_claim_count += 1;
-        insurance_company.approved_claim_amount += claim_amount;
-        
-        //Update Hospital
-        hospital.hospital_type = hospital_type;
-        hospital.hospital_longitude = hospital_longitude;
-        hospital.hospital_latitude = hospital_latitude;
-        hospital.hospital_name = hospital_name.clone();
-        hospital.hospital_address = hospital_address.clone();
-        hospital.hospital_city = hospital_city.clone();
-        hospital.hospital_zip_code = hospital_zip_code;
-        hospital.hospital_phone_number = hospital_phone_number.clone();
+    pub fn set_max_upward_edit_bps(ctx: Context<SetMaxUpwardEditBps>, max_upward_edit_bps: u16) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Update Insurance Company
-        insurance_company.insurance_company_name = insurance_company_name.clone();
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.max_upward_edit_bps = max_upward_edit_bps;
 
-        //Update Records
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Approved as u8;
-        patient_record.processor_count_index = processor.processed_claim_count;
-        patient_record.hospital_index = claim.hospital_index as u32;
-        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        patient_record.claim_amount = claim_amount;
-        patient_record.ailment = ailment.clone();
-        patient_record.note = claim_note.clone();
-        patient_record.processed_time = Clock::get()?.unix_timestamp as u64;
-        patient_record.insurance_company_index = claim.insurance_company_index as u16;
+        msg!("Set Max Upward Edit BPS");
+        msg!("Set to {}", max_upward_edit_bps);
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.status = Status::Approved as u8;
-        hospital_record.processor_count_index = processor.processed_claim_count;
-        hospital_record.claim_amount = claim_amount;
-        hospital_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        hospital_record.ailment = ailment.clone();
-        hospital_record.note = claim_note.clone();
-        hospital_record.processed_time = Clock::get()?.unix_timestamp as u64;
-        hospital_record.insurance_company_index = claim.insurance_company_index as u16;
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetMaxUpwardEditBps, ctx.accounts.signer.key())?;
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.status = Status::Approved as u8;
-        insurance_company_record.processor_count_index = processor.processed_claim_count;
-        insurance_company_record.hospital_index = claim.hospital_index as u32;
-        insurance_company_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        insurance_company_record.claim_amount = claim_amount;
-        insurance_company_record.ailment = ailment.clone();
-        insurance_company_record.note = claim_note.clone();
-        insurance_company_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        Ok(())
+    }
 
-        //Create Processed Claim
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
-        processed_claim.claim_id = claim.id;
-        processed_claim.processor_count_index = processor.processed_claim_count;
-        processed_claim.status = Status::Approved as u8;
-        processed_claim.is_patient_record_created = true;
-        processed_claim.is_hospital_record_created = true;
-        processed_claim.is_insurance_company_record_created = true;
-        processed_claim.patient_record_index = claim.patient_record_index;
-        processed_claim.hospital_record_index = claim.hospital_record_index;
-        processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
-        processed_claim.processor_address = ctx.accounts.signer.key();
-        processed_claim.submitter_address = claim.submitter_address;
-        processed_claim.patient_index = claim.patient_index;
-        processed_claim.country_index = claim.country_index;
-        processed_claim.state_index = claim.state_index;
-        processed_claim.hospital_index = claim.hospital_index;
-        processed_claim.hospital_type = hospital_type;
-        processed_claim.hospital_name = hospital_name;
-        processed_claim.hospital_address = hospital_address;
-        processed_claim.hospital_city = hospital_city;
-        processed_claim.hospital_zip_code = hospital_zip_code;
-        processed_claim.hospital_phone_number = hospital_phone_number;
-        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number;
-        processed_claim.note = claim_note;
-        processed_claim.claim_amount = claim_amount;
-        processed_claim.ailment = ailment;
-        processed_claim.insurance_company_index = claim.insurance_company_index;
-        processed_claim.insurance_company_name = insurance_company_name;
-        processed_claim.submitted_time = claim.submitted_time;
-        processed_claim.processed_time = Clock::get()?.unix_timestamp as u64;
+    pub fn set_sla_seconds(ctx: Context<SetSlaSeconds>, sla_seconds: u64) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        processor.approved_claim_amount += claim.claim_amount;
-        processor.approved_claim_count += 1;
-        processor.processed_claim_count += 1;
-        processor.is_processing_claim = false;
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.sla_seconds = sla_seconds;
+
+        msg!("Set SLA Seconds");
+        msg!("Set to {}", sla_seconds);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetSlaSeconds, ctx.accounts.signer.key())?;
 
-        msg!("New Claim Approved With Edits");
-        msg!("For: ${:.2}", claim_amount as f64/100.00);
-        msg!("Approved Claim Count: {}", processor_stats.approved_claim_count);
-        msg!("User Address: {}", claim.submitter_address);
-        msg!("Patient First Name: {}", patient.patient_first_name);
-        msg!("Patient Last Name: {}", patient.patient_last_name);
         Ok(())
     }
 
-    pub fn max_deny_pending_claim(ctx: Context<MaxDenyPendingClaim>, submitter_address: Pubkey) -> Result<()> 
+    pub fn set_assignment_cooldown_seconds(ctx: Context<SetAssignmentCooldownSeconds>, assignment_cooldown_seconds: u64) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
-        let claim = &mut ctx.accounts.claim;
-        let admin_processor = &mut ctx.accounts.admin_processor;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only an Admin or the CEO can call this function
-        require!(ctx.accounts.signer.key() == ceo.address.key() ||
-        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.assignment_cooldown_seconds = assignment_cooldown_seconds;
 
-        //Claim must be in a pending state to use this Max Deny
-        require!(claim.status == Status::Pending as u8, InvalidOperationError::ClaimNotPending);
+        msg!("Set Assignment Cooldown Seconds");
+        msg!("Set to {}", assignment_cooldown_seconds);
 
-        //Can't max deny claim if patient record was created
-        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetAssignmentCooldownSeconds, ctx.accounts.signer.key())?;
 
-        //Can't max deny claim if hospital record was created
-        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        Ok(())
+    }
 
-        //Can't max deny claim if insurance company record was created
-        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+    pub fn set_max_daily_processing(ctx: Context<SetMaxDailyProcessing>, max_daily_processing: u32) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        processor_stats.max_denied_claim_count += 1;
-        submitter.max_denied_claim_count += 1;
-        patient.max_denied_claim_count += 1;
-        admin_processor.max_denied_claim_count += 1;
-     
-        let claim_queue = &mut ctx.accounts.claim_queue; 
-        claim_queue.current_claim_queue_count -= 1;
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.max_daily_processing = max_daily_processing;
+
+        msg!("Set Max Daily Processing");
+        msg!("Set to {}", max_daily_processing);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetMaxDailyProcessing, ctx.accounts.signer.key())?;
 
-        msg!("New Max Pending Claim Denial");
-        msg!("Max Denied Claim Count: {}", processor_stats.max_denied_claim_count);
-        msg!("User Address: {}", submitter_address);
-        
         Ok(())
     }
 
-    pub fn max_deny_in_progress_claim(ctx: Context<MaxDenyInProgressClaim>, submitter_address: Pubkey) -> Result<()> 
+    pub fn set_min_claim_amount_cents(ctx: Context<SetMinClaimAmountCents>, min_claim_amount_cents: u64) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
-        let claim = &mut ctx.accounts.claim;
-        let admin_processor = &mut ctx.accounts.admin_processor;
-        let claim_processor = &mut ctx.accounts.claim_processor;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only an Admin or the CEO can call this function
-        require!(ctx.accounts.signer.key() == ceo.address.key() ||
-        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.min_claim_amount_cents = min_claim_amount_cents;
 
-        //Claim must be in a processing state to use this Max Deny
-        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+        msg!("Set Min Claim Amount");
+        msg!("Set to ${:.2}", min_claim_amount_cents as f64/100.00);
 
-        //Can't max deny claim if patient record was created
-        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetMinClaimAmountCents, ctx.accounts.signer.key())?;
 
-        //Can't max deny claim if hospital record was created
-        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        Ok(())
+    }
 
-        //Can't max deny claim if insurance company record was created
-        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+    pub fn set_auto_flag_amount_cents(ctx: Context<SetAutoFlagAmountCents>, auto_flag_amount_cents: u64) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        processor_stats.max_denied_claim_count += 1;
-        submitter.max_denied_claim_count += 1;
-        patient.max_denied_claim_count += 1;
-        admin_processor.max_denied_claim_count += 1;
-     
-        let claim_queue = &mut ctx.accounts.claim_queue; 
-        claim_queue.current_claim_queue_count -= 1;
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.auto_flag_amount_cents = auto_flag_amount_cents;
 
-        if claim.status == Status::Processing as u8
-        {
-            claim_processor.is_processing_claim = false;
+        msg!("Set Auto Flag Amount");
+        msg!("Set to ${:.2}", auto_flag_amount_cents as f64/100.00);
 
-            //Check if Signer was the processor on the claim, they can't exist in 2 processor variables in this function, so have to do an extra check
-            if claim.processor_address == ctx.accounts.signer.key()
-            {
-                admin_processor.is_processing_claim = false;
-                claim_processor.max_denied_claim_count += 1;
-            }
-        }
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetAutoFlagAmountCents, ctx.accounts.signer.key())?;
 
-        msg!("New Max In Progress Claim Denial");
-        msg!("Max Denied Claim Count: {}", processor_stats.max_denied_claim_count);
-        msg!("User Address: {}", submitter_address);
-        
         Ok(())
     }
 
-    pub fn create_patient_record_and_deny_claim(ctx: Context<CreatePatientRecordAndDenyClaim>, _submitter_address: Pubkey, denial_reason: String) -> Result<()> 
+    pub fn set_dual_signoff_amount_cents(ctx: Context<SetDualSignoffAmountCents>, dual_signoff_amount_cents: u64) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.dual_signoff_amount_cents = dual_signoff_amount_cents;
 
-        let state = &mut ctx.accounts.state;
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        processor_stats.denied_claim_count += 1;
-        state.denied_claim_count += 1;
-        processor_stats.processed_claim_count += 1;
-        processor_stats.created_patient_record_count += 1;
+        msg!("Set Dual Signoff Amount");
+        msg!("Set to ${:.2}", dual_signoff_amount_cents as f64/100.00);
 
-        //Only create 1 patient record per claim
-        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetDualSignoffAmountCents, ctx.accounts.signer.key())?;
 
-        //Denial note string must not be longer than 140 characters
-        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        Ok(())
+    }
 
-        let claim_queue = &mut ctx.accounts.claim_queue; 
-        claim_queue.current_claim_queue_count -= 1;
+    pub fn set_max_reassignments(ctx: Context<SetMaxReassignments>, max_reassignments: u16) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        let patient = &mut ctx.accounts.patient;
-        let submitter = &mut ctx.accounts.submitter;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.max_reassignments = max_reassignments;
 
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
-        processed_claim.claim_id = claim.id;
-        processed_claim.processor_count_index = processor.processed_claim_count;
-        processed_claim.status = Status::Denied as u8;
-        processed_claim.denial_reason = denial_reason.clone();
-        processed_claim.is_patient_record_created = true;
-        processed_claim.patient_record_index = patient.record_count;
-        processed_claim.processor_address = ctx.accounts.signer.key();
-        processed_claim.submitter_address = claim.submitter_address;
-        processed_claim.patient_index = claim.patient_index;
-        processed_claim.country_index = claim.country_index;
-        processed_claim.state_index = claim.state_index;
-        processed_claim.hospital_index = claim.hospital_index;
-        processed_claim.hospital_name = claim.hospital_name.clone();
-        processed_claim.hospital_address = claim.hospital_address.clone();
-        processed_claim.hospital_city = claim.hospital_city.clone();
-        processed_claim.hospital_zip_code = claim.hospital_zip_code;
-        processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
-        processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        processed_claim.note = claim.note.clone();
-        processed_claim.claim_amount = claim.claim_amount;
-        processed_claim.ailment = claim.ailment.clone();
-        processed_claim.insurance_company_index = claim.insurance_company_index;
-        processed_claim.insurance_company_name = claim.insurance_company_name.clone();
-        processed_claim.submitted_time = claim.submitted_time;
-        processed_claim.processed_time = time_stamp;
-        
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient.record_count += 1;
-        patient_record.record_id = patient.record_count as u32;
-        patient_record.claim_id = claim.id as u32;
-        patient_record.status = Status::Denied as u8;
-        patient_record.patient_record_only = true;
-        patient_record.submitter_address = claim.submitter_address;
-        patient_record.processor_address = ctx.accounts.signer.key();
-        patient_record.processor_count_index = processor.processed_claim_count;
-        patient_record.denial_reason = denial_reason.clone();
-        patient_record.country_index = claim.country_index;
-        patient_record.state_index = claim.state_index;
-        patient_record.hospital_index = claim.hospital_index as u32;
-        patient_record.insurance_company_index = claim.insurance_company_index as u16;
-        patient_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        patient_record.claim_amount = claim.claim_amount;
-        patient_record.ailment = claim.ailment.clone();
-        patient_record.note = claim.note.clone();
-        patient_record.submitted_time = claim.submitted_time;
-        patient_record.processed_time = time_stamp;
-        
-        submitter.denied_claim_count += 1;
-        patient.denied_claim_count += 1;
+        msg!("Set Max Reassignments");
+        msg!("Set to {}", max_reassignments);
 
-        processor.created_patient_record_count += 1;
-        processor.denied_claim_count += 1;
-        processor.processed_claim_count += 1;
-        processor.is_processing_claim = false;
-        
-        msg!("New Patient Record And Claim Denial");
-        msg!("Denied Claim Count: {}", processor_stats.denied_claim_count);
-        msg!("User Address: {}", claim.submitter_address);
-        msg!("Reason: {}", denial_reason.clone());
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetMaxReassignments, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn deny_claim_with_all_records(ctx: Context<DenyClaimWithAllRecords>, _submitter_address: Pubkey, denial_reason: String) -> Result<()> 
+    pub fn set_account_creation_flag(ctx: Context<SetAccountCreationFlag>, is_enabled: bool) -> Result<()> 
     {
-        let claim = &mut ctx.accounts.claim;
-        let processor = &mut ctx.accounts.processor;
-        
-        //Only an active Processor can call this function
-        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only the Processor can call this function
-        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        //The flag can't be set to the same state
+        require!(m4a_protocol.account_creation_enabled != is_enabled, InvalidOperationError::FlagSameState);
 
-        //Only claims being processed can be denied
-        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
-        
-        //Can't deny claim if patient record wasn't created
-        require!(claim.is_patient_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+        m4a_protocol.account_creation_enabled = is_enabled;
 
-        //Can't deny claim if hospital record wasn't created
-        require!(claim.is_hospital_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+        msg!("Set Account Creation Flag");
+        msg!("Set to {}", is_enabled);
 
-        //Can't deny claim if insurance company record wasn't created
-        require!(claim.is_insurance_company_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetAccountCreationFlag, ctx.accounts.signer.key())?;
 
-        //Denial note string must not be longer than 140 characters
-        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        Ok(())
+    }
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let claim_queue = &mut ctx.accounts.claim_queue; 
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+    pub fn create_submitter_account(ctx: Context<CreateSubmitterAccount>) -> Result<()> 
+    {
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
 
-        processor_stats.denied_claim_count += 1;
-        processor_stats.processed_claim_count += 1;
-        claim_queue.current_claim_queue_count -= 1;
-        submitter.denied_claim_count += 1;
-        patient.denied_claim_count += 1;
-        state.denied_claim_count += 1;
-        hospital.denied_claim_count += 1;
-        insurance_company.denied_claim_count += 1;
+        //Account creation must be enabled
+        require!(m4a_protocol.account_creation_enabled == true, InvalidOperationError::AccountCreationDisabled);
 
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
-        processed_claim.claim_id = claim.id;
-        processed_claim.processor_count_index = processor.processed_claim_count;
-        processed_claim.status = Status::Denied as u8;
-        processed_claim.denial_reason = denial_reason.clone();
-        processed_claim.is_patient_record_created = true;
-        processed_claim.is_hospital_record_created = true;
-        processed_claim.is_insurance_company_record_created = true;
-        processed_claim.patient_record_index = claim.patient_record_index;
-        processed_claim.hospital_record_index = claim.hospital_record_index;
-        processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
-        processed_claim.processor_address = ctx.accounts.signer.key();
-        processed_claim.submitter_address = claim.submitter_address;
-        processed_claim.patient_index = claim.patient_index;
-        processed_claim.country_index = claim.country_index;
-        processed_claim.state_index = claim.state_index;
-        processed_claim.hospital_index = claim.hospital_index;
-        processed_claim.hospital_name = claim.hospital_name.clone();
-        processed_claim.hospital_address = claim.hospital_address.clone();
-        processed_claim.hospital_city = claim.hospital_city.clone();
-        processed_claim.hospital_zip_code = claim.hospital_zip_code;
-        processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
-        processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
-        processed_claim.note = claim.note.clone();
-        processed_claim.claim_amount = claim.claim_amount;
-        processed_claim.ailment = claim.ailment.clone();
-        processed_claim.insurance_company_index = claim.insurance_company_index;
-        processed_claim.insurance_company_name = claim.insurance_company_name.clone();
-        processed_claim.submitted_time = claim.submitted_time;
-        processed_claim.processed_time = time_stamp;
+        m4a_protocol.submitter_account_total += 1;
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Denied as u8;
-        patient_record.processor_count_index = processor.processed_claim_count;
-        patient_record.denial_reason = denial_reason.clone();
-        patient_record.processed_time = time_stamp;
+        //Ids come from their own counter since the total goes back down when a submitter account is closed
+        m4a_protocol.submitter_id_counter += 1;
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.status = Status::Denied as u8;
-        hospital_record.processor_count_index = processor.processed_claim_count;
-        hospital_record.denial_reason = denial_reason.clone();
-        hospital_record.processed_time = time_stamp;
+        let submitter = &mut ctx.accounts.submitter;
+        submitter.id = m4a_protocol.submitter_id_counter;
+        submitter.address = ctx.accounts.signer.key();
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.status = Status::Denied as u8;
-        insurance_company_record.processor_count_index = processor.processed_claim_count;
-        insurance_company_record.denial_reason = denial_reason.clone();
-        insurance_company_record.processed_time = time_stamp;
+        msg!("Sumitter Account Initialized");
+        msg!("User Address: {}", ctx.accounts.signer.key());
 
-        processor.denied_claim_count += 1;
-        processor.processed_claim_count += 1;
-        processor.is_processing_claim = false;
-        
-        msg!("New Claim Denial");
-        msg!("Denied Claim Count: {}", processor_stats.denied_claim_count);
-        msg!("User Address: {}", claim.submitter_address);
-        msg!("Reason: {}", denial_reason.clone());
-        
         Ok(())
-    } 
+    }
 
-    pub fn appeal_denied_claim_with_only_patient_record(ctx: Context<AppealDeniedClaimWithOnlyPatientRecord>,
-        _processor_address: Pubkey,
-        _processor_count_index: u64,
-        _token_mint_address: Pubkey,
-        appeal_reason: String) -> Result<()> 
+    pub fn close_submitter_account(ctx: Context<CloseSubmitterAccount>) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
-
-        //Only the person who submitted the claim can appeal it
-        require_keys_eq!(ctx.accounts.signer.key(), processed_claim.submitter_address, AuthorizationError::NotSubmitter);
-
-        //Only denied claims can be appealed
-        require!(processed_claim.status == Status::Denied as u8, InvalidOperationError::ClaimNotDenied);
-        
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
-
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_hospital_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
-
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_insurance_company_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
-
-        //Appeal note string must not be longer than 140 characters
-        require!(appeal_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
-
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let patient_record = &mut ctx.accounts.patient_record;
-        let state = &mut ctx.accounts.state;
+        let submitter = &ctx.accounts.submitter;
 
-        processor_stats.submitted_appeal_count += 1;
-        submitter.submitted_appeal_count += 1;
-        patient.submitted_appeal_count += 1;
-        state.submitted_appeal_count += 1;
-        patient_record.status = Status::Appealed as u8;
-        patient_record.appeal_reason = appeal_reason.clone();
-        processed_claim.status = Status::Appealed as u8;
-        processed_claim.appeal_reason = appeal_reason.clone();
-        
-        msg!("New Appeal For Denied Claim With Only Patient Record");
-        msg!("Appeal Reason {}", appeal_reason);
-        msg!("Submitted Appeals Count {}", processor_stats.submitted_appeal_count);
+        //Only empty submitter accounts can be closed, patients are never closed so a submitter with one keeps their account
+        //Claims are always submitted for a patient, so this also covers open and unsettled claims
+        require!(submitter.patient_count == 0, InvalidOperationError::SubmitterHasPatients);
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        m4a_protocol.submitter_account_total -= 1;
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_fee_ata.to_account_info(),
-            accounts.treasurer_usdc_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+        msg!("Submitter Account Closed");
+        msg!("User Address: {}", ctx.accounts.signer.key());
 
         Ok(())
     }
 
-    pub fn deny_appealed_claim_with_only_patient_record(ctx: Context<DenyAppealedClaimWithOnlyPatientRecord>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String) -> Result<()> 
+    pub fn create_patient_account(ctx: Context<CreatePatientAccount>, patient_first_name: String, patient_last_name: String) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
+        //Patient first name string must not be longer than 52 characters
+        require!(patient_first_name.len() <= MAX_PATIENT_FIRST_NAME_LENGTH, InvalidLengthError::PatientFirstNameTooLong);
 
-        //Can't deny appeal of a claim that isn't in an appealed state
-        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+        //Patient last name string must not be longer than 52 characters
+        require!(patient_last_name.len() <= MAX_PATIENT_LAST_NAME_LENGTH, InvalidLengthError::PatientLastNameTooLong);
 
-        //Can't deny appeal of a claim that isn't in an appealed state
-        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
-
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_hospital_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
-
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_insurance_company_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
+        //Account creation must be enabled
+        require!(m4a_protocol.account_creation_enabled == true, InvalidOperationError::AccountCreationDisabled);
 
-        //Denital note string must not be longer than 140 characters
-        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
-
-        let processor_stats = &mut ctx.accounts.processor_stats;
         let submitter = &mut ctx.accounts.submitter;
         let patient = &mut ctx.accounts.patient;
-        let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let patient_record = &mut ctx.accounts.patient_record;
-        
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        processor_stats.denied_appeal_count += 1;
-        submitter.denied_appeal_count += 1;
-        patient.denied_appeal_count += 1;
-        processor.denied_appeal_count += 1;
-        state.denied_appeal_count += 1;
-        patient_record.status = Status::Denied as u8;
-        patient_record.denial_reason = denial_reason.clone();
-        patient_record.processed_time = time_stamp;
-        processed_claim.status = Status::Denied as u8;
-        processed_claim.denial_reason = denial_reason.clone();
-        processed_claim.processed_time = time_stamp;
+        patient.is_active = true;
+        patient.submitter_address = ctx.accounts.signer.key();
+        patient.patient_first_name = patient_first_name.clone();
+        patient.patient_last_name = patient_last_name.clone();
+
+        m4a_protocol.patient_account_total += 1;
+        patient.id = m4a_protocol.patient_account_total;
+        submitter.active_patient_count += 1;
         
-        msg!("An Appeal With Only A Patient Record Has Been Denied");
-        msg!("Denital Reason {}", denial_reason);
-        msg!("Submitted Appeals Count {}", processor_stats.denied_appeal_count);
+        msg!("Patient Account Initialized");
+        msg!("Submitter Address: {}", ctx.accounts.signer.key());
+        msg!("Patient Index: {}", submitter.patient_count);
+        msg!("Patient First Name: {}", patient_first_name);
+        msg!("Patient Last Name: {}", patient_last_name);
 
+        submitter.patient_count += 1;
+        
         Ok(())
     }
 
-    pub fn appeal_denied_claim_with_all_records(ctx: Context<AppealDeniedClaimWithAllRecords>,
-        _processor_address: Pubkey,
-        _processor_count_index: u64,
-        _token_mint_address: Pubkey,
-        appeal_reason: String) -> Result<()> 
+    pub fn set_lifetime_benefit_cap(ctx: Context<SetLifetimeBenefitCap>, submitter_address: Pubkey, patient_index: u8, lifetime_benefit_cap_cents: u64) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
-
-        //Only the person who submitted the claim can appeal it
-        require_keys_eq!(ctx.accounts.signer.key(), processed_claim.submitter_address, AuthorizationError::NotSubmitter);
+        let is_active_processor = match &ctx.accounts.processor
+        {
+            Some(processor) => processor.is_active == true,
+            None => false
+        };
 
-        //Only denied claims can be appealed
-        require!(processed_claim.status == Status::Denied as u8, InvalidOperationError::ClaimNotDenied);
+        //Only an active Processor or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ctx.accounts.ceo.address.key() ||
+        is_active_processor == true, AuthorizationError::NotProcessorOrCEO);
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        let patient = &mut ctx.accounts.patient;
+        patient.lifetime_benefit_cap_cents = lifetime_benefit_cap_cents;
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_hospital_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        msg!("Set Lifetime Benefit Cap");
+        msg!("Submitter Address: {}", submitter_address);
+        msg!("Patient Index: {}", patient_index);
+        msg!("Lifetime Benefit Cap: ${:.2}", lifetime_benefit_cap_cents as f64/100.00);
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_insurance_company_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetLifetimeBenefitCap, ctx.accounts.signer.key())?;
 
-        //Appeal note string must not be longer than 140 characters
-        require!(appeal_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        Ok(())
+    }
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let state = &mut ctx.accounts.state;
+    pub fn set_patient_flag(ctx: Context<SetPatientFlag>, _patient_index: u8, is_enabled: bool) -> Result<()> 
+    {
         let patient = &mut ctx.accounts.patient;
-        let patient_record = &mut ctx.accounts.patient_record;
-        let hospital = &mut ctx.accounts.hospital;
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        
-        processor_stats.submitted_appeal_count += 1;
-        state.submitted_appeal_count += 1;
-        processed_claim.status = Status::Appealed as u8;
-        processed_claim.appeal_reason = appeal_reason.clone();
-        patient.submitted_appeal_count += 1;
-        patient_record.status = Status::Appealed as u8;
-        patient_record.appeal_reason = appeal_reason.clone();
-        hospital.submitted_appeal_count += 1;
-        hospital_record.status = Status::Appealed as u8;
-        hospital_record.appeal_reason = appeal_reason.clone();
-        insurance_company.submitted_appeal_count += 1;
-        insurance_company_record.status = Status::Appealed as u8;
-        insurance_company_record.appeal_reason = appeal_reason.clone();
-        
-        msg!("New Appeal For Denied Claim With All Records");
-        msg!("Appeal Reason {}", appeal_reason);
-        msg!("Submitted Appeals Count {}", processor_stats.submitted_appeal_count);
+        //Can't set patient to the same state because of the counter
+        require!(patient.is_active != is_enabled, InvalidOperationError::FlagSameState);
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+        let submitter = &mut ctx.accounts.submitter;
+        
+        patient.is_active = is_enabled;
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_fee_ata.to_account_info(),
-            accounts.treasurer_usdc_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+        if is_enabled
+        {
+            submitter.active_patient_count += 1; 
+        }
+        else
+        {
+            submitter.active_patient_count = submitter.active_patient_count.checked_sub(1).ok_or(InvalidOperationError::ActivePatientCountInvalid)?; 
+        }
 
+        //A submitter can never have more active patients than patients
+        require!(submitter.active_patient_count <= submitter.patient_count, InvalidOperationError::ActivePatientCountInvalid);
+        
+        msg!("Patient Flag Updated To: {}", is_enabled);
+        msg!("Patient First Name: {}", patient.patient_first_name);
+        msg!("Patient Last Name: {}", patient.patient_last_name);
+        msg!("Submitter Address: {}", ctx.accounts.signer.key());
+        
         Ok(())
     }
-
-    pub fn deny_appealed_claim_with_all_records(ctx: Context<DenyAppealedClaimWithAllRecords>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String) -> Result<()> 
+    
+    //Patient record PDAs are seeded by the submitter, so a patient moved to a new submitter needs their records recreated under the new one
+    pub fn migrate_patient_record(ctx: Context<MigratePatientRecord>,
+        old_submitter_address: Pubkey,
+        old_patient_index: u8,
+        record_index: u32,
+        new_submitter_address: Pubkey,
+        new_patient_index: u8) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
-
         let ceo = &mut ctx.accounts.ceo;
         //Only the CEO can call this function
         require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Can't deny appeal of a claim that isn't in an appealed state
-        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+        //Records already under the submitter don't need to be migrated
+        require_keys_neq!(old_submitter_address, new_submitter_address, InvalidOperationError::PatientRecordMigrationToSameSubmitter);
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        //The migrated record is a copy of the old one that points at its new submitter, the old record gets closed
+        let mut patient_record = PatientRecord::clone(&ctx.accounts.old_patient_record);
+        patient_record.submitter_address = new_submitter_address;
+        ctx.accounts.new_patient_record.set_inner(patient_record);
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_hospital_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        let new_patient = &mut ctx.accounts.new_patient;
+        let new_record_index = new_patient.record_count;
+        new_patient.record_count = new_patient.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
 
-        //Prevent Rat Fuckery
-        require!(processed_claim.is_insurance_company_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+        msg!("Patient Record Migrated");
+        msg!("Old Submitter Address: {}", old_submitter_address);
+        msg!("Old Patient Index: {}", old_patient_index);
+        msg!("Old Record Index: {}", record_index);
+        msg!("New Submitter Address: {}", new_submitter_address);
+        msg!("New Patient Index: {}", new_patient_index);
+        msg!("New Record Index: {}", new_record_index);
 
-        //Denial note string must not be longer than 140 characters
-        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::MigratePatientRecord, ctx.accounts.signer.key())?;
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
+        Ok(())
+    }
+
+    pub fn give_patient_consent(ctx: Context<GivePatientConsent>, patient_index: u8) -> Result<()> 
+    {
         let patient = &mut ctx.accounts.patient;
-        let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let patient_record = &mut ctx.accounts.patient_record;
-        let hospital = &mut ctx.accounts.hospital;
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        processor_stats.denied_appeal_count += 1;
-        processor.denied_appeal_count += 1;
-        submitter.denied_appeal_count += 1;
-        patient.denied_appeal_count += 1;
-        processor.denied_appeal_count += 1;
-        state.denied_appeal_count += 1;
-        patient_record.status = Status::Denied as u8;
-        patient_record.denial_reason = denial_reason.clone();
-        patient_record.processed_time = time_stamp;
-        hospital.denied_appeal_count += 1;
-        hospital_record.status = Status::Denied as u8;
-        hospital_record.denial_reason = denial_reason.clone();
-        hospital_record.processed_time = time_stamp;
-        insurance_company.denied_appeal_count += 1;
-        insurance_company_record.status = Status::Denied as u8;
-        insurance_company_record.denial_reason = denial_reason.clone();
-        insurance_company_record.processed_time = time_stamp;
-        processed_claim.status = Status::Denied as u8;
-        processed_claim.denial_reason = denial_reason.clone();
-        processed_claim.processed_time = time_stamp;
-        
-        msg!("An Appeal With Only All Records Has Been Denied");
-        msg!("Denital Reason {}", denial_reason);
-        msg!("Submitted Appeals Count {}", processor_stats.denied_appeal_count);
+        //Consent only needs to be given once
+        require!(patient.consent_given == false, InvalidOperationError::FlagSameState);
+
+        patient.consent_given = true;
+        patient.consent_time = Clock::get()?.unix_timestamp as u64;
+
+        msg!("Patient Consent Given");
+        msg!("Patient Index: {}", patient_index);
+        msg!("Consent Time: {}", patient.consent_time);
 
         Ok(())
     }
 
-    pub fn undeny_claim_and_create_hospital_and_insurance_company_records(ctx: Context<UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    pub fn rename_patient(ctx: Context<RenamePatient>, patient_index: u8, patient_first_name: String, patient_last_name: String) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
+        //Patient first name string must not be longer than 52 characters
+        require!(patient_first_name.len() <= MAX_PATIENT_FIRST_NAME_LENGTH, InvalidLengthError::PatientFirstNameTooLong);
+
+        //Patient last name string must not be longer than 52 characters
+        require!(patient_last_name.len() <= MAX_PATIENT_LAST_NAME_LENGTH, InvalidLengthError::PatientLastNameTooLong);
+
+        //Only the patient account is renamed, records are historical so they're left as is
+        let patient = &mut ctx.accounts.patient;
+        patient.patient_first_name = patient_first_name.clone();
+        patient.patient_last_name = patient_last_name.clone();
+
+        msg!("Patient Renamed");
+        msg!("Patient Index: {}", patient_index);
+        msg!("Patient First Name: {}", patient_first_name);
+        msg!("Patient Last Name: {}", patient_last_name);
+
+        Ok(())
+    }
 
+    pub fn create_processor_account(ctx: Context<CreateProcessorAccount>, processor_address: Pubkey) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
         //Only the CEO can call this function
         require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only denied or appealed claims can be undenied
-        require!((processed_claim.status == Status::Denied as u8) || (processed_claim.status == Status::Appealed as u8), InvalidOperationError::ClaimNotDeniedOrAppealed);
-
         let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
+        processor_stats.processor_account_total += 1;
+        processor_stats.processor_active_account_total += 1;
+
         let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        processor.id = processor_stats.processor_account_total;
+        processor.address = processor_address.key();
+        processor.is_active = true;
+        processor.onboarded_time = Clock::get()?.unix_timestamp as u64;
 
-        processor_stats.approved_claim_amount += processed_claim.claim_amount;
-        processor_stats.undenied_claim_count += 1;
-        processor_stats.approved_claim_count += 1;
-        processor_stats.denied_claim_count -= 1;
-        processor_stats.created_hospital_and_insurance_company_records_count += 1;
-        submitter.undenied_claim_count += 1;
-        submitter.approved_claim_count += 1;
-        submitter.denied_claim_count -= 1;
-        submitter.approved_claim_amount += processed_claim.claim_amount;
-        patient.undenied_claim_count += 1;
-        patient.approved_claim_count += 1;
-        patient.denied_claim_count -= 1;
-        patient.approved_claim_amount += processed_claim.claim_amount;
-        processor.undenied_claim_count += 1;
-        processor.approved_claim_amount += processed_claim.claim_amount;
-        state.undenied_claim_count += 1;
-        state.approved_claim_count += 1;
-        state.denied_claim_count -= 1;
-        state.approved_claim_amount += processed_claim.claim_amount;
-        hospital.undenied_claim_count += 1;
-        hospital.approved_claim_count += 1;
-        hospital.approved_claim_amount += processed_claim.claim_amount;
-        insurance_company.undenied_claim_count += 1;
-        insurance_company.approved_claim_count += 1;
-        insurance_company.approved_claim_amount += processed_claim.claim_amount;
+        let processor_claim_index = &mut ctx.accounts.processor_claim_index;
+        processor_claim_index.processor_address = processor_address.key();
 
-        processed_claim.status = Status::Approved as u8;
-        processed_claim.hospital_record_index = hospital.record_count;
-        processed_claim.insurance_company_record_index = insurance_company.record_count;
-        processed_claim.is_hospital_record_created = true;
-        processed_claim.is_insurance_company_record_created = true;
-        processed_claim.processed_time = time_stamp;
+        msg!("Processor Account Initialized");
+        msg!("Processor Address: {}", processor_address.key());
+        msg!("Processor Account Count: {}", processor_stats.processor_account_total);
+        
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::CreateProcessorAccount, ctx.accounts.signer.key())?;
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Approved as u8;
-        patient_record.patient_record_only = false;
-        patient_record.processed_time = time_stamp;
+        Ok(())
+    }
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital.record_count += 1;
-        hospital_record.record_id = hospital.record_count;
-        hospital_record.claim_id = processed_claim.claim_id;
-        hospital_record.status = Status::Approved as u8;
-        hospital_record.submitter_address = processed_claim.submitter_address;
-        hospital_record.patient_index = processed_claim.patient_index;
-        hospital_record.processor_address = ctx.accounts.signer.key();
-        hospital_record.insurance_company_index = processed_claim.insurance_company_index as u16;
-        hospital_record.hospital_bill_invoice_number = processed_claim.hospital_bill_invoice_number.clone();
-        hospital_record.claim_amount = processed_claim.claim_amount;
-        hospital_record.ailment = processed_claim.ailment.clone();
-        hospital_record.note = processed_claim.note.clone();
-        hospital_record.submitted_time = processed_claim.submitted_time;
-        hospital_record.processed_time = time_stamp;
+    pub fn set_processor_account_active_flag(ctx: Context<SetProcessorAccountActiveFlag>, processor_address: Pubkey, is_active: bool) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        let processor = &mut ctx.accounts.processor;
+        //The flag can't be set to the same state to keep the counters safe
+        require!(processor.is_active != is_active, InvalidOperationError::FlagSameState);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.edited_processor_count += 1;
+        processor.is_active = is_active;
+
+        if is_active == false
+        {
+            processor_stats.processor_active_account_total -= 1;
+            processor.deactivated_time = Clock::get()?.unix_timestamp as u64;
+
+            if processor.is_super_admin == true
+            {
+                processor.is_super_admin = false;
+                processor_stats.processor_super_admin_account_total -= 1;
+
+                //Deactivating the last super admin demotes them too
+                if processor_stats.processor_super_admin_account_total == 0
+                {
+                    emit!(LastSuperAdminDemoted
+                    {
+                        processor_address: processor_address.key(),
+                        timestamp: Clock::get()?.unix_timestamp as u64
+                    });
+                }
+            }
+        }
+        else
+        {
+            processor_stats.processor_active_account_total += 1;
+            processor.deactivated_time = 0;//Back to active, so there's no deactivation to report
+        }
         
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company.record_count += 1;
-        insurance_company_record.record_id = insurance_company.record_count;
-        insurance_company_record.claim_id = processed_claim.claim_id;
-        insurance_company_record.status = Status::Approved as u8;
-        insurance_company_record.submitter_address = processed_claim.submitter_address;
-        insurance_company_record.patient_index = processed_claim.patient_index;
-        insurance_company_record.processor_address = ctx.accounts.signer.key();
-        insurance_company_record.country_index = processed_claim.country_index;
-        insurance_company_record.state_index = processed_claim.state_index;
-        insurance_company_record.hospital_index = processed_claim.hospital_index as u32;
-        insurance_company_record.hospital_bill_invoice_number = processed_claim.hospital_bill_invoice_number.clone();
-        insurance_company_record.claim_amount = processed_claim.claim_amount;
-        insurance_company_record.ailment = processed_claim.ailment.clone();
-        insurance_company_record.note = processed_claim.note.clone();
-        insurance_company_record.submitted_time = processed_claim.submitted_time;
-        insurance_company_record.processed_time = time_stamp;
+        msg!("Processor Account Is Active Flag Set To: {}", is_active);
+        msg!("Processor Address: {}", processor_address.key());
 
-        msg!("New Undenied Claim");
-        msg!("New Hospital Record Created");
-        msg!("New Insurance Company Record Created");
-        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetProcessorAccountActiveFlag, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn undeny_claim_with_all_records(ctx: Context<UndenyClaimWithAllRecords>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    pub fn set_processor_account_privilege(ctx: Context<SetProcessorAccountPrivilege>, processor_address: Pubkey, is_super_admin: bool) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
-
         let ceo = &mut ctx.accounts.ceo;
         //Only the CEO can call this function
         require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        //Only denied or appealed claims can be undenied
-        require!((processed_claim.status == Status::Denied as u8) || (processed_claim.status == Status::Appealed as u8), InvalidOperationError::ClaimNotDeniedOrAppealed);
+        let processor = &mut ctx.accounts.processor;
+        //The flag can't be set to the same state to keep the counters safe
+        require!(processor.is_super_admin != is_super_admin, InvalidOperationError::FlagSameState);
 
         let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        processor_stats.approved_claim_amount += processed_claim.claim_amount;
-        processor_stats.undenied_claim_count += 1;
-        processor_stats.approved_claim_count += 1;
-        processor_stats.denied_claim_count -= 1;
-        submitter.undenied_claim_count += 1;
-        submitter.approved_claim_count += 1;
-        submitter.denied_claim_count -= 1;
-        submitter.approved_claim_amount += processed_claim.claim_amount;
-        patient.undenied_claim_count += 1;
-        patient.approved_claim_count += 1;
-        patient.denied_claim_count -= 1;
-        patient.approved_claim_amount += processed_claim.claim_amount;
-        processor.undenied_claim_count += 1;
-        processor.approved_claim_amount += processed_claim.claim_amount;
-        state.undenied_claim_count += 1;
-        state.approved_claim_count += 1;
-        state.denied_claim_count -= 1;
-        state.approved_claim_amount += processed_claim.claim_amount;
-        hospital.undenied_claim_count += 1;
-        hospital.approved_claim_count += 1;
-        hospital.denied_claim_count -= 1;
-        hospital.approved_claim_amount += processed_claim.claim_amount;
-        insurance_company.undenied_claim_count += 1;
-        insurance_company.approved_claim_count += 1;
-        insurance_company.denied_claim_count -= 1;
-        insurance_company.approved_claim_amount += processed_claim.claim_amount;
+        processor_stats.edited_processor_count += 1;
+        processor.is_super_admin = is_super_admin;
 
-        processed_claim.status = Status::Approved as u8;
-        processed_claim.processed_time = time_stamp;
+        if is_super_admin == false
+        {
+            processor_stats.processor_super_admin_account_total -= 1;
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Approved as u8;
-        patient_record.processed_time = time_stamp;
+            //Break glass flows fall back to only the CEO once there are no super admins left
+            if processor_stats.processor_super_admin_account_total == 0
+            {
+                emit!(LastSuperAdminDemoted
+                {
+                    processor_address: processor_address.key(),
+                    timestamp: Clock::get()?.unix_timestamp as u64
+                });
+            }
+        }
+        else
+        {
+            processor_stats.processor_super_admin_account_total += 1;
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.status = Status::Approved as u8;
-        hospital_record.processed_time = time_stamp;
+            if processor.is_active == false
+            {
+                processor.is_active = true;
+                processor_stats.processor_active_account_total += 1;
+            }
+        }
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.status = Status::Approved as u8;
-        insurance_company_record.processed_time = time_stamp;
-        
-        msg!("New Undenied Claim");
-        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        msg!("Processor Account Admin Flag Set To: {}", is_super_admin);
+        msg!("Processor Address: {}", processor_address.key());
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetProcessorAccountPrivilege, ctx.accounts.signer.key())?;
 
         Ok(())
     }
 
-    pub fn edit_processed_claim_and_patient_record(ctx: Context<EditProcessedClaimAndPatientRecord>, 
-        _processor_address: Pubkey,
-        _processor_count_index: u64,
-        hospital_index: u32,
-        insurance_company_index: u16,
+    pub fn set_processor_name(ctx: Context<SetProcessorName>, processor_address: Pubkey, name: String) -> Result<()> 
+    {
+        let ceo = &ctx.accounts.ceo;
+        //Only the CEO or the processor themselves can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        ctx.accounts.signer.key() == processor_address.key(), AuthorizationError::NotCEOOrProcessorOwner);
+
+        //Processor name string must not be longer than 40 characters
+        require!(name.len() <= MAX_PROCESSOR_NAME_LENGTH, InvalidLengthError::ProcessorNameTooLong);
+
+        let processor = &mut ctx.accounts.processor;
+        processor.name = name;
+
+        msg!("Processor Name Set To: {}", processor.name);
+        msg!("Processor Address: {}", processor_address.key());
+
+        Ok(())
+    }
+
+    pub fn submit_claim_to_queue(ctx: Context<SubmitClaimToQueue>,
+        patient_index: u8,
+        _token_mint_address: Pubkey,
+        country_index: u16,
+        state_index: u32,
+        hospital_index: i32,
+        hospital_type: u8,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
         hospital_bill_invoice_number: String,
-        claim_note: String,
+        note: String,
         claim_amount: u64,
-        ailment: String) -> Result<()> 
+        copay_amount: u64,
+        deductible_amount: u64,
+        currency_code: [u8; 3],
+        ailment: String,
+        insurance_company_index: i16,
+        insurance_company_name: String,
+        diagnosis_codes: Vec<String>
+    ) -> Result<()> 
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        let fee_token_entry = load_fee_token_entry(&ctx.accounts.fee_token_entry)?;
+        check_fee_token_not_expired(&fee_token_entry)?;
+
+        //Call the helper function to validate and fill out the claim
+        submit_claim(
+            &mut ctx.accounts.claim_queue,
+            &mut ctx.accounts.claim,
+            &mut ctx.accounts.submitter,
+            &mut ctx.accounts.patient,
+            ctx.accounts.signer.key(),
+            patient_index,
+            country_index,
+            state_index,
+            hospital_index,
+            hospital_type,
+            hospital_name,
+            hospital_address,
+            hospital_city,
+            hospital_zip_code,
+            hospital_phone_number,
+            hospital_bill_invoice_number,
+            note,
+            claim_amount,
+            copay_amount,
+            deductible_amount,
+            currency_code,
+            ailment,
+            insurance_company_index,
+            insurance_company_name,
+            diagnosis_codes,
+            ctx.accounts.m4a_protocol.sla_seconds,
+            ctx.accounts.m4a_protocol.min_claim_amount_cents,
+            ctx.accounts.m4a_protocol.auto_flag_amount_cents,
+            &ctx.accounts.field_limits
+        )?;
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        let patient = &mut ctx.accounts.patient;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        let accounts = &ctx.accounts;
+        let treasurer = ctx.accounts.treasurer.clone();
+        let fee_mint = accounts.user_fee_ata.mint;
 
-        //An edit count is kept to help stream line the table listeners on the front end
-        patient.edited_record_count += 1;
-        processor_stats.edited_claim_or_processed_claim_count += 1;
+        //Both fee token accounts have to be for the same mint, in case crafted accounts get past the constraints
+        require_keys_eq!(fee_mint, accounts.treasurer_fee_ata.mint, InvalidOperationError::FeeMintMismatch);
 
-        //Update Processed Claim
-        processed_claim.hospital_index = hospital_index as i32;
-        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        processed_claim.note = claim_note.clone();
-        processed_claim.claim_amount = claim_amount;
-        processed_claim.ailment = ailment.clone();
-        processed_claim.insurance_company_index = insurance_company_index as i16;
-        processed_claim.hospital_name = hospital.hospital_name.clone();
-        processed_claim.hospital_address = hospital.hospital_address.clone();
-        processed_claim.hospital_city = hospital.hospital_city.clone();
-        processed_claim.hospital_zip_code = hospital.hospital_zip_code;
-        processed_claim.hospital_phone_number = hospital.hospital_phone_number.clone();
-        processed_claim.insurance_company_name = insurance_company.insurance_company_name.clone();
-        processed_claim.processed_time = time_stamp;
+        //Call the helper function to transfer the fee
+        let fee_amount_paid = apply_fee(
+            accounts.user_fee_ata.to_account_info(),
+            accounts.treasurer_fee_ata.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            treasurer,
+            FEE_4CENTS,
+            fee_token_entry.decimal_amount,
+            accounts.fee_exemption.as_ref(),
+            accounts.fee_config.fees_enabled,
+            accounts.signer.key()
+        )?;
 
-        //Update Records
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.hospital_index = hospital_index;
-        patient_record.insurance_company_index = insurance_company_index;
-        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        patient_record.claim_amount = claim_amount;
-        patient_record.ailment = ailment.clone();
-        patient_record.note = claim_note.clone();
-        patient_record.processed_time = time_stamp;
-        
-        msg!("Processed Claim And Patient Record Updated");
-        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        //Record which token paid for the claim and how much, for reconciliation and refunds
+        let claim = &mut ctx.accounts.claim;
+        claim.fee_mint = fee_mint;
+        claim.fee_amount_paid = fee_amount_paid;
 
         Ok(())
     }
 
-    pub fn edit_processed_claim_and_all_records(ctx: Context<EditProcessedClaimAndAllRecords>, 
-        _processor_address: Pubkey,
-        _processor_count_index: u64,
-        hospital_bill_invoice_number: String,
-        claim_note: String,
-        claim_amount: u64,
-        ailment: String) -> Result<()> 
+    pub fn create_submitter_delegate(ctx: Context<CreateSubmitterDelegate>, delegate_address: Pubkey) -> Result<()> 
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        let submitter_delegate = &mut ctx.accounts.submitter_delegate;
+        submitter_delegate.submitter_address = ctx.accounts.signer.key();
+        submitter_delegate.delegate_address = delegate_address;
+        submitter_delegate.is_active = true;
 
-        let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
-        let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let processed_claim = &mut ctx.accounts.processed_claim;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("Submitter Delegate Created");
+        msg!("Submitter Address: {}", ctx.accounts.signer.key());
+        msg!("Delegate Address: {}", delegate_address);
 
-        //An edit count is kept to help stream line the table listeners on the front end
-        patient.edited_record_count += 1;
-        hospital.edited_record_count += 1;
-        insurance_company.edited_record_count += 1;
-        processor_stats.edited_claim_or_processed_claim_count += 1;
+        Ok(())
+    }
 
-        //Update Previous Amounts If Amounts Were Already Approved
-        if processed_claim.status == Status::Approved as u8
-        {
-            processor_stats.approved_claim_amount -= processed_claim.claim_amount;
-            processor_stats.approved_claim_amount += claim_amount;
-            submitter.approved_claim_amount -= processed_claim.claim_amount;
-            submitter.approved_claim_amount += claim_amount;
-            patient.approved_claim_amount -= processed_claim.claim_amount;
-            patient.approved_claim_amount += claim_amount;
-            processor.approved_claim_amount -= processed_claim.claim_amount;
-            processor.approved_claim_amount += claim_amount;
-            hospital.approved_claim_amount -= processed_claim.claim_amount;
-            hospital.approved_claim_amount += claim_amount;
-            state.approved_claim_amount -= processed_claim.claim_amount;
-            state.approved_claim_amount += claim_amount;
-            insurance_company.approved_claim_amount -= processed_claim.claim_amount;
-            insurance_company.approved_claim_amount += claim_amount;
-        }
-
-        //Update Processed Claim
-        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        processed_claim.note = claim_note.clone();
-        processed_claim.claim_amount = claim_amount;
-        processed_claim.ailment = ailment.clone();
-        processed_claim.processed_time = time_stamp;
-
-        //Update Records
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        patient_record.claim_amount = claim_amount;
-        patient_record.ailment = ailment.clone();
-        patient_record.note = claim_note.clone();
-        patient_record.processed_time = time_stamp;
+    pub fn set_submitter_delegate_flag(ctx: Context<SetSubmitterDelegateFlag>, delegate_address: Pubkey, is_active: bool) -> Result<()> 
+    {
+        let submitter_delegate = &mut ctx.accounts.submitter_delegate;
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        hospital_record.claim_amount = claim_amount; 
-        hospital_record.ailment = ailment.clone();
-        hospital_record.note = claim_note.clone();
-        hospital_record.processed_time = time_stamp;
+        //Can't set flag to the same state
+        require!(submitter_delegate.is_active != is_active, InvalidOperationError::FlagSameState);
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
-        insurance_company_record.claim_amount = claim_amount;
-        insurance_company_record.ailment = ailment;
-        insurance_company_record.note = claim_note;
-        insurance_company_record.processed_time = time_stamp;
+        submitter_delegate.is_active = is_active;
 
-        msg!("Processed Claim And All Records Updated");
-        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        msg!("Submitter Delegate Flag Set To: {}", is_active);
+        msg!("Delegate Address: {}", delegate_address);
 
         Ok(())
     }
 
-    pub fn revoke_approval(ctx: Context<RevokeApproval>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String) -> Result<()> 
+    pub fn submit_claim_as_delegate(ctx: Context<SubmitClaimAsDelegate>,
+        _submitter_address: Pubkey,
+        patient_index: u8,
+        _token_mint_address: Pubkey,
+        country_index: u16,
+        state_index: u32,
+        hospital_index: i32,
+        hospital_type: u8,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
+        hospital_bill_invoice_number: String,
+        note: String,
+        claim_amount: u64,
+        copay_amount: u64,
+        deductible_amount: u64,
+        currency_code: [u8; 3],
+        ailment: String,
+        insurance_company_index: i16,
+        insurance_company_name: String,
+        diagnosis_codes: Vec<String>
+    ) -> Result<()> 
     {
-        let processed_claim = &mut ctx.accounts.processed_claim;
+        let fee_token_entry = load_fee_token_entry(&ctx.accounts.fee_token_entry)?;
+        check_fee_token_not_expired(&fee_token_entry)?;
+
+        //Only an active delegate of the submitter can submit claims for them
+        require!(ctx.accounts.submitter_delegate.is_active == true, AuthorizationError::NotSubmitterDelegate);
+
+        //Call the helper function to validate and fill out the claim
+        submit_claim(
+            &mut ctx.accounts.claim_queue,
+            &mut ctx.accounts.claim,
+            &mut ctx.accounts.submitter,
+            &mut ctx.accounts.patient,
+            ctx.accounts.submitter_delegate.submitter_address,
+            patient_index,
+            country_index,
+            state_index,
+            hospital_index,
+            hospital_type,
+            hospital_name,
+            hospital_address,
+            hospital_city,
+            hospital_zip_code,
+            hospital_phone_number,
+            hospital_bill_invoice_number,
+            note,
+            claim_amount,
+            copay_amount,
+            deductible_amount,
+            currency_code,
+            ailment,
+            insurance_company_index,
+            insurance_company_name,
+            diagnosis_codes,
+            ctx.accounts.m4a_protocol.sla_seconds,
+            ctx.accounts.m4a_protocol.min_claim_amount_cents,
+            ctx.accounts.m4a_protocol.auto_flag_amount_cents,
+            &ctx.accounts.field_limits
+        )?;
 
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        msg!("Submitted By Delegate: {}", ctx.accounts.signer.key());
 
-        //Only approved claims can be revoked
-        require!(processed_claim.status == Status::Approved as u8, InvalidOperationError::ClaimNotApproved);
+        let accounts = &ctx.accounts;
+        let treasurer = ctx.accounts.treasurer.clone();
+        let fee_mint = accounts.user_fee_ata.mint;
 
-        //Denial note string must not be longer than 140 characters
-        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        //Both fee token accounts have to be for the same mint, in case crafted accounts get past the constraints
+        require_keys_eq!(fee_mint, accounts.treasurer_fee_ata.mint, InvalidOperationError::FeeMintMismatch);
+
+        //Call the helper function to transfer the fee
+        let fee_amount_paid = apply_fee(
+            accounts.user_fee_ata.to_account_info(),
+            accounts.treasurer_fee_ata.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            treasurer,
+            FEE_4CENTS,
+            fee_token_entry.decimal_amount,
+            accounts.fee_exemption.as_ref(),
+            accounts.fee_config.fees_enabled,
+            accounts.submitter_delegate.submitter_address
+        )?;
+
+        //Record which token paid for the claim and how much, for reconciliation and refunds
+        let claim = &mut ctx.accounts.claim;
+        claim.fee_mint = fee_mint;
+        claim.fee_amount_paid = fee_amount_paid;
+
+        Ok(())
+    }
 
+    pub fn assign_claim_to_processor(ctx: Context<AssignClaimToProcessor>, submitter_address: Pubkey) -> Result<()> 
+    {
         let processor_stats = &mut ctx.accounts.processor_stats;
-        let submitter = &mut ctx.accounts.submitter;
-        let patient = &mut ctx.accounts.patient;
         let processor = &mut ctx.accounts.processor;
-        let state = &mut ctx.accounts.state;
-        let hospital = &mut ctx.accounts.hospital;
-        let insurance_company = &mut ctx.accounts.insurance_company;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        let claim = &mut ctx.accounts.claim;
         
-        processor_stats.approved_claim_amount -= processed_claim.claim_amount;
-        processor_stats.revoked_approval_count += 1;
-        processor_stats.approved_claim_count -= 1;
-        processor_stats.denied_claim_count += 1;
-        submitter.revoked_approval_count += 1;
-        submitter.approved_claim_count -= 1;
-        submitter.denied_claim_count += 1;
-        submitter.approved_claim_amount -= processed_claim.claim_amount;
-        patient.revoked_approval_count += 1;
-        patient.approved_claim_count -= 1;
-        patient.denied_claim_count += 1;
-        patient.approved_claim_amount -= processed_claim.claim_amount;
-        processor.revoked_approval_count += 1;
-        processor.approved_claim_amount -= processed_claim.claim_amount;
-        state.revoked_approval_count += 1;
-        state.approved_claim_count -= 1;
-        state.denied_claim_count += 1;
-        state.approved_claim_amount -= processed_claim.claim_amount;
-        hospital.revoked_approval_count += 1;
-        hospital.approved_claim_count -= 1;
-        hospital.denied_claim_count += 1;
-        hospital.approved_claim_amount -= processed_claim.claim_amount;
-        insurance_company.revoked_approval_count += 1;
-        insurance_company.approved_claim_count -= 1;
-        insurance_company.denied_claim_count += 1;
-        insurance_company.approved_claim_amount -= processed_claim.claim_amount;
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
 
-        processed_claim.status = Status::Denied as u8;
-        processed_claim.denial_reason = denial_reason.clone();
-        processed_claim.processed_time = time_stamp;
+        //Processor must not already be processing any other claim
+        require!(processor.is_processing_claim == false, AuthorizationError::ProcessorAlreadyWorkingOnClaim);
 
-        let patient_record = &mut ctx.accounts.patient_record;
-        patient_record.status = Status::Denied as u8;
-        patient_record.denial_reason = denial_reason.clone();
-        patient_record.processed_time = time_stamp;
+        //A claim can only have one processor
+        require_keys_eq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimAlreadyAssigned);
 
-        let hospital_record = &mut ctx.accounts.hospital_record;
-        hospital_record.status = Status::Denied as u8;
-        hospital_record.denial_reason = denial_reason.clone();
-        hospital_record.processed_time = time_stamp;
+        //A claim returned for correction has to be resubmitted by the submitter before it can be picked up again
+        require!(claim.status != Status::ReturnedForCorrection as u8, InvalidOperationError::ClaimReturnedForCorrection);
 
-        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
-        insurance_company_record.status = Status::Denied as u8;
-        insurance_company_record.denial_reason = denial_reason.clone();
-        insurance_company_record.processed_time = time_stamp;
-        
-        msg!("New Revoked Approval");
-        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        //Processor can't grab a new claim until the cooldown from their last dropped claim has passed
+        require!(Clock::get()?.unix_timestamp as u64 >= processor.last_unassigned_time + ctx.accounts.m4a_protocol.assignment_cooldown_seconds, InvalidOperationError::AssignmentCooldownActive);
+
+        processor.is_processing_claim = true;
+        processor.submitter_address_of_claim_being_processed = submitter_address.key();
+        add_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, submitter_address)?;
+        claim.processor_address = ctx.accounts.signer.key();
+        claim.status = Status::Assigned as u8;
+        processor_stats.set_or_unset_processor_on_claim_count += 1;
+
+        msg!("Claim Assigned To Processor Address: ");
+        msg!("{}", ctx.accounts.signer.key());
 
         Ok(())
     }
 
-    pub fn drop_denial_hammer(ctx: Context<DropDenialHammer>) -> Result<()> 
+    pub fn acknowledge_claim(ctx: Context<AcknowledgeClaim>, submitter_address: Pubkey) -> Result<()> 
+    {
+        let processor = &ctx.accounts.processor;
+        let claim = &mut ctx.accounts.claim;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor the claim is assigned to can acknowledge it
+        require_keys_eq!(claim.processor_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotTheProcessor);
+
+        //Claim must be assigned and waiting on the processor to start working it
+        require!(claim.status == Status::Assigned as u8, InvalidOperationError::ClaimNotAwaitingAcknowledgement);
+
+        claim.status = Status::Processing as u8;
+
+        msg!("Claim Acknowledged By Processor Address: ");
+        msg!("{}", ctx.accounts.signer.key());
+        msg!("User Address: {}", submitter_address);
+
+        Ok(())
+    }
+
+    pub fn reassign_claim_to_new_processor(ctx: Context<ReassignClaimToNewProcessor>, submitter_address: Pubkey, reason: Option<String>) -> Result<()> 
     {
         let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let new_processor = &mut ctx.accounts.new_processor;
+        let old_processor = &mut ctx.accounts.old_processor;
+        let claim = &mut ctx.accounts.claim;
 
-        for claim_account in ctx.remaining_accounts.iter()
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        new_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //Processor must not already be processing any other claim
+        require!(new_processor.is_processing_claim == false, AuthorizationError::ProcessorAlreadyWorkingOnClaim);
+
+        //A claim can not be unassigned or reassigned if it isn't currently assigned
+        require_keys_neq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimNotAssigned);
+
+        //The reason is only emitted, claims reassigned without one emit an empty reason
+        let reason = reason.unwrap_or_default();
+
+        //Reassignment reason string must not be longer than 100 characters
+        require!(reason.len() <= MAX_REASSIGNMENT_REASON_LENGTH, InvalidLengthError::ReassignmentReasonTooLong);
+
+        new_processor.is_processing_claim = true;
+        new_processor.submitter_address_of_claim_being_processed = submitter_address.key();
+        add_claim_in_progress(new_processor, &mut ctx.accounts.new_processor_claim_index, submitter_address)?;
+        processor_stats.set_or_unset_processor_on_claim_count += 1;
+
+        //Check if processor is reassigning themself to the same claim for some weird ass reason, do nothing else if so
+        if new_processor.address != claim.processor_address
         {
-            //Transfer tokens from the account to the sol_destination.
-            let dest_starting_lamports = ctx.accounts.signer.lamports();
-            **ctx.accounts.signer.lamports.borrow_mut() = 
-                dest_starting_lamports.checked_add(claim_account.lamports()).unwrap();
-            **claim_account.lamports.borrow_mut() = 0;
-            
-            claim_account.assign(&system_program::ID);
-            let _ = claim_account.realloc(0, false);
+            old_processor.is_processing_claim = false;
+            old_processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
+            old_processor.last_unassigned_time = Clock::get()?.unix_timestamp as u64;
+            remove_claim_in_progress(old_processor, &mut ctx.accounts.old_processor_claim_index, submitter_address);
         }
 
+        msg!("Claim Reassigned To New Processor Address: ");
+        msg!("{}", ctx.accounts.signer.key());
+        msg!("Old Processor Address: ");
+        msg!("{}", claim.processor_address);
+
+        emit!(ClaimReassigned
+        {
+            claim_id: claim.id,
+            submitter_address: claim.submitter_address,
+            old_processor_address: claim.processor_address,
+            new_processor_address: ctx.accounts.signer.key(),
+            reassignment_count: claim.reassignment_count + 1,
+            reason
+        });
+
+        claim.processor_address = ctx.accounts.signer.key();
+        claim.reassignment_count += 1;
+
+        //Call the helper function to flag a claim that keeps bouncing between processors
+        auto_flag_claim_reassignments(claim, ctx.accounts.m4a_protocol.max_reassignments);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::ReassignClaimToNewProcessor, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn unassign_claim_from_processor(ctx: Context<UnassignClaimFromProcessor>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
         let processor_stats = &mut ctx.accounts.processor_stats;
-        let claim_queue = &mut ctx.accounts.claim_queue;
+        let admin_processor = &mut ctx.accounts.admin_processor;
+        let old_processor = &mut ctx.accounts.old_processor;
+        let claim = &mut ctx.accounts.claim;
+
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //A claim can not be unassigned or reassigned if it isn't currently assigned
+        require_keys_neq!(claim.processor_address.key(), SYSTEM_PROGRAM_ADDRESS.key(), InvalidOperationError::ClaimNotAssigned);
+
+        old_processor.is_processing_claim = false;
+        old_processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
+        old_processor.last_unassigned_time = Clock::get()?.unix_timestamp as u64;
+        remove_claim_in_progress(old_processor, &mut ctx.accounts.old_processor_claim_index, claim.submitter_address);
+        claim.processor_address = SYSTEM_PROGRAM_ADDRESS;
+        claim.status = Status::Pending as u8;
+
+        processor_stats.set_or_unset_processor_on_claim_count += 1;
+
+        msg!("Claim id: {} Unassigned By: ", claim.id);
+        msg!("{}", ctx.accounts.signer.key());
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::UnassignClaimFromProcessor, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    //For in the event that the claim has already been denied some kind of way and the processor is stuck on a dead claim (Denial Hammer most likely)
+    pub fn set_processor_to_not_processing_claim_state(ctx: Context<SetProcessorToNotProcessingClaimState>, _processor_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let admin_processor = &mut ctx.accounts.admin_processor;
         let processor = &mut ctx.accounts.processor;
 
-        processor_stats.denial_hammer_dropped_count += 1;
-        claim_queue.current_claim_queue_count = claim_queue.current_claim_queue_count - ctx.remaining_accounts.len() as u32;
-        processor.denial_hammer_dropped_count += 1;
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //A closed claim is the dead claim case this is for, a live claim the processor is still on has to be unassigned so the claim gets reset too
+        if ctx.accounts.claim.data_is_empty() == false
+        {
+            let claim_data = ctx.accounts.claim.try_borrow_data()?;
+            let claim = Claim::try_deserialize(&mut &claim_data[..])?;
+
+            require!((claim.status != Status::Processing as u8 && claim.status != Status::Assigned as u8) ||
+            claim.processor_address != processor.address, InvalidOperationError::ClaimStillBeingProcessed);
+        }
+
+        processor.is_processing_claim = false;
+        processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
+        processor.claims_in_progress.clear();
+        ctx.accounts.processor_claim_index.submitter_addresses.clear();
+        processor_stats.set_or_unset_processor_on_claim_count += 1;
+
+        msg!("Processor Set To Not Processign Claim State By: ");
+        msg!("{}", ctx.accounts.signer.key());
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetProcessorToNotProcessingClaimState, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn return_claim_for_correction(ctx: Context<ReturnClaimForCorrection>, _submitter_address: Pubkey, reason: String) -> Result<()> 
+    {
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let processor = &mut ctx.accounts.processor;
+        let claim = &mut ctx.accounts.claim;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Claim must still be being processed to return it
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Records are built from the submitted details, so the claim can only be returned before any of them are created
+        require!(claim.is_patient_record_created == false &&
+        claim.is_hospital_record_created == false &&
+        claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Reason string must not be longer than 140 characters
+        require!(reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        processor.is_processing_claim = false;
+        processor.submitter_address_of_claim_being_processed = SYSTEM_PROGRAM_ADDRESS;
+        processor.last_unassigned_time = Clock::get()?.unix_timestamp as u64;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+        claim.processor_address = SYSTEM_PROGRAM_ADDRESS;
+        claim.status = Status::ReturnedForCorrection as u8;
+        claim.correction_reason = reason;
+        processor_stats.set_or_unset_processor_on_claim_count += 1;
+
+        msg!("Claim id: {} Returned For Correction By: ", claim.id);
+        msg!("{}", ctx.accounts.signer.key());
+        msg!("Reason: {}", claim.correction_reason);
+
+        Ok(())
+    }
+
+    pub fn resubmit_corrected_claim(ctx: Context<ResubmitCorrectedClaim>, 
+        hospital_type: u8,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
+        hospital_bill_invoice_number: String,
+        note: String,
+        claim_amount: u64,
+        copay_amount: u64,
+        deductible_amount: u64,
+        ailment: String,
+        insurance_company_name: String,
+        diagnosis_codes: Vec<String>
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+
+        //Only a claim that was returned for correction can be resubmitted, the original fee already covers it
+        require!(claim.status == Status::ReturnedForCorrection as u8, InvalidOperationError::ClaimNotReturnedForCorrection);
+
+        //Call the helper function to validate the corrected claim details
+        validate_claim_details(
+            hospital_type,
+            &hospital_name,
+            &hospital_address,
+            &hospital_city,
+            &hospital_bill_invoice_number,
+            &note,
+            claim_amount,
+            copay_amount,
+            deductible_amount,
+            &ailment,
+            &diagnosis_codes,
+            &insurance_company_name,
+            ctx.accounts.m4a_protocol.min_claim_amount_cents,
+            &ctx.accounts.field_limits
+        )?;
+
+        claim.status = Status::Pending as u8;
+        claim.hospital_type = hospital_type;
+        claim.hospital_name = hospital_name;
+        claim.hospital_address = hospital_address;
+        claim.hospital_city = hospital_city;
+        claim.hospital_zip_code = hospital_zip_code;
+        claim.hospital_phone_number = hospital_phone_number;
+        claim.hospital_bill_invoice_number = hospital_bill_invoice_number;
+        claim.note = note;
+        claim.claim_amount = claim_amount;
+        claim.copay_amount = copay_amount;
+        claim.deductible_amount = deductible_amount;
+        claim.ailment = ailment;
+        claim.insurance_company_name = insurance_company_name;
+        claim.content_hash = get_claim_content_hash(claim);
+        claim.diagnosis_codes = diagnosis_codes;
+        claim.correction_reason = String::new();
+
+        //The time the claim spent with the submitter doesn't count against the SLA
+        if ctx.accounts.m4a_protocol.sla_seconds != 0
+        {
+            claim.sla_deadline = Clock::get()?.unix_timestamp as u64 + ctx.accounts.m4a_protocol.sla_seconds;
+        }
+
+        //Call the helper function to flag the corrected claim for review if the amount is over the auto flag amount
+        auto_flag_claim_amount(claim, ctx.accounts.m4a_protocol.auto_flag_amount_cents);
+
+        msg!("Corrected Claim Resubmitted to the Queue");
+        msg!("Claim id: {}", claim.id);
+        msg!("Submitter Address: {}", ctx.accounts.signer.key());
+        msg!("For: ${:.2}", claim_amount as f64/100.00);
+
+        Ok(())
+    }
+
+    pub fn create_state_account(ctx: Context<CreateStateAccount>, _submitter_address: Pubkey, country_index: u16, state_index: u32) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
         
-        msg!("Denial Hammer Dropped");
-        msg!("Denial Hammer Use Count: {}", processor_stats.denial_hammer_dropped_count);
-        msg!("Number of Accounts Hammered: {}", ctx.remaining_accounts.len());
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //The claim must also be assigned to the signer, not just be a claim from the submitter the processor is working on
+        require_keys_eq!(claim.processor_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotTheProcessor);
+        
+        let m4a_protocol = &mut ctx.accounts.m4a_protocol;
+        let state = &mut ctx.accounts.state;
+
+        m4a_protocol.state_account_total += 1;
+        state.id = m4a_protocol.state_account_total;
+        state.index = state_index;
+        
+        msg!("Initialized Country at Index: {}", country_index);
+        msg!("Initialized State at Index: {}", state_index);
+        msg!("State Id: {}", state_index);
+
+        Ok(())
+    }
+
+    pub fn set_state_metadata(ctx: Context<SetStateMetadata>, country_index: u16, state_index: u32, state_name: String, state_abbreviation: [u8; 2]) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //State name string must not be longer than 32 characters
+        require!(state_name.len() <= MAX_STATE_NAME_LENGTH, InvalidLengthError::StateNameTooLong);
+
+        //State abbreviation must be 2 uppercase letters
+        require!(state_abbreviation.iter().all(|letter| letter.is_ascii_uppercase()), InvalidType::InvalidStateAbbreviation);
+
+        let state = &mut ctx.accounts.state;
+        state.state_name = state_name;
+        state.state_abbreviation = state_abbreviation;
+
+        msg!("Set State Metadata");
+        msg!("Country Index: {}", country_index);
+        msg!("State Index: {}", state_index);
+        msg!("State Name: {}", state.state_name);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::SetStateMetadata, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn create_hospital(ctx: Context<CreateHospital>, 
+        _submitter_address: Pubkey,
+        country_index: u16,
+        state_index: u32,
+        hospital_type: u8,
+        hospital_longitude: f64,
+        hospital_latitude: f64,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
+        note: String) -> Result<()> 
+    { 
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Hospital type must be valid
+        require!((hospital_type == HospitalType::General as u8) ||
+        (hospital_type == HospitalType::Dental as u8) ||
+        (hospital_type == HospitalType::Vision as u8) ||
+        (hospital_type == HospitalType::Mental as u8) ||
+        (hospital_type == HospitalType::Other as u8), InvalidType::HospitalTypeInvalid);
+
+        //Call the helper function to make sure the hospital coordinates are on the map
+        check_hospital_coordinates(hospital_latitude, hospital_longitude)?;
+
+        //Hospital name string must not be longer than 50 characters
+        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        //Hospital address string must not be longer than 100 characters
+        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalAddressTooLong);
+
+        //Hospital city string must not be longer than 40 characters
+        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalCityTooLong);
+
+        //Hospital phone number must not be longer than 15 digits (E.164)
+        require!(hospital_phone_number < 10u128.pow(MAX_HOSPITAL_PHONE_NUMBER_DIGITS), InvalidLengthError::HospitalPhoneNumberTooLong);
+
+        //Note string must not be longer than 140 characters
+        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+        
+        let hospital_stats = &mut ctx.accounts.hospital_stats;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        
+        hospital_stats.hospital_count += 1;
+        processor.created_hospital_count += 1;
+
+        claim.country_index = country_index;
+        claim.state_index = state_index;
+        claim.hospital_type = hospital_type;
+        claim.hospital_index = state.hospital_count as i32;
+        claim.hospital_name = hospital_name.clone();
+        claim.content_hash = get_claim_content_hash(claim);
+        claim.hospital_address = hospital_address.clone();
+        claim.hospital_city = hospital_city.clone();
+        claim.hospital_zip_code = hospital_zip_code;
+        claim.hospital_phone_number = hospital_phone_number.clone();
+
+        hospital.id = hospital_stats.hospital_count;
+        hospital.hospital_index = state.hospital_count;
+        hospital.is_active = true;
+        hospital.created_by = ctx.accounts.signer.key();
+        hospital.country_index = country_index;
+        hospital.state_index = state_index;
+        hospital.hospital_type = hospital_type;
+        hospital.hospital_longitude = hospital_longitude;
+        hospital.hospital_latitude = hospital_latitude;
+        hospital.hospital_name = hospital_name;
+        hospital.hospital_address = hospital_address;
+        hospital.hospital_city = hospital_city;
+        hospital.hospital_zip_code = hospital_zip_code;
+        hospital.hospital_phone_number = hospital_phone_number;
+        hospital.note = note;
+
+        state.hospital_count += 1;
+
+        //Call the helper function to count the hospital under its type
+        add_hospital_type_count(hospital_stats, state, hospital_type);
+
+        msg!("Hospital Created #{}", hospital.id);
+        msg!("Country Index: {}", country_index);
+        msg!("State Index: {}", state_index);
+        msg!("Hospital Index: {}", state.hospital_count-1);
+        msg!("Hospital Type: {}", hospital.hospital_type);
+        msg!("Longitude: {}", hospital_longitude);
+        msg!("Latitude: {}", hospital_latitude);
+        msg!("Note: {}", hospital.note.clone());
+        msg!("State Hospital Count: {}", state.hospital_count);
+        msg!("M4A Protocol General Hospital Count: {}", hospital_stats.hospital_count);
+
+        Ok(())
+    }
+
+    pub fn edit_hospital(ctx: Context<EditHospital>, 
+        country_index: u16,
+        state_index: u32,
+        hospital_index: u32,
+        is_active: bool,
+        hospital_type: u8,
+        hospital_longitude: f64,
+        hospital_latitude: f64,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
+        note: String) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Hospital type must be valid
+        require!((hospital_type == HospitalType::General as u8) ||
+        (hospital_type == HospitalType::Dental as u8) ||
+        (hospital_type == HospitalType::Vision as u8) ||
+        (hospital_type == HospitalType::Mental as u8) ||
+        (hospital_type == HospitalType::Other as u8), InvalidType::HospitalTypeInvalid);
+
+        //Call the helper function to make sure the hospital coordinates are on the map
+        check_hospital_coordinates(hospital_latitude, hospital_longitude)?;
+
+        //Hospital name string must not be longer than 50 characters
+        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        //Hospital address string must not be longer than 100 characters
+        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        //Hospital city string must not be longer than 40 characters
+        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        //Hospital phone number must not be longer than 15 digits (E.164)
+        require!(hospital_phone_number < 10u128.pow(MAX_HOSPITAL_PHONE_NUMBER_DIGITS), InvalidLengthError::HospitalPhoneNumberTooLong);
+
+        //Note string must not be longer than 140 characters
+        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        let hospital_stats = &mut ctx.accounts.hospital_stats;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+
+        //Type counts only track active hospitals, so remember if this one was counted before flipping the flag
+        let was_active = hospital.is_active;
+
+        //Wait to deduct previous hospital type before setting it to the hospital
+        hospital.is_active = is_active;
+        hospital.hospital_longitude = hospital_longitude;
+        hospital.hospital_latitude = hospital_latitude;
+        hospital.hospital_name = hospital_name;
+        hospital.hospital_address = hospital_address;
+        hospital.hospital_city = hospital_city;
+        hospital.hospital_zip_code = hospital_zip_code;
+        hospital.hospital_phone_number = hospital_phone_number;
+
+        //Keep the last few replaced notes so an edit doesn't lose the prior context
+        if hospital.note_history.len() >= MAX_HOSPITAL_NOTE_HISTORY_COUNT
+        {
+            hospital.note_history.remove(0);
+        }
+
+        let replaced_note = hospital.note.clone();
+        hospital.note_history.push(HospitalNoteEntry
+        {
+            note: replaced_note,
+            time: Clock::get()?.unix_timestamp as u64
+        });
+        hospital.note = note;
+
+        //Deduct previous type from count
+        if was_active == true
+        {
+            //Call the helper function to take the hospital out of its old type count
+            deduct_hospital_type_count(hospital_stats, state, hospital.hospital_type);
+        }
+
+        //Add new type to count, inactive hospitals aren't counted by type
+        if is_active == true
+        {
+            //Call the helper function to count the hospital under its new type
+            add_hospital_type_count(hospital_stats, state, hospital_type);
+        }
+
+        hospital_stats.edited_hospital_count += 1;
+        state.edited_hospital_count += 1;
+    
+        //Set new hospital type
+        hospital.hospital_type = hospital_type;
+
+        msg!("Hospital Edited");
+        msg!("Country Index: {}", country_index);
+        msg!("State Index: {}", state_index);
+        msg!("Hospital Index: {}", hospital_index);
+        msg!("Hospital Active: {}", is_active);
+        msg!("Hospital Type: {}", hospital.hospital_type);
+        msg!("Hospital Name: {}", hospital.hospital_name);
+        msg!("Hospital Address: {}", hospital.hospital_address);
+        msg!("Hospital City: {}", hospital.hospital_city);
+        msg!("Hospital ZipCode: {}", hospital.hospital_zip_code);
+        msg!("Hospital PhoneNumber: {}", hospital.hospital_phone_number);
+        msg!("Longitude: {}", hospital_longitude);
+        msg!("Latitude: {}", hospital_latitude);
+        msg!("Note: {}", hospital.note.clone());
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::EditHospital, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn create_insurance_company(ctx: Context<CreateInsuranceCompany>, 
+        _submitter_address: Pubkey, 
+        insurance_company_index: u16,
+        insurance_company_name: String,
+        note: String) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Only the CEO can create the reserved default insurance companies
+        require!(insurance_company_index > MAX_RESERVED_INSURANCE_COMPANY_INDEX ||
+        ctx.accounts.signer.key() == ctx.accounts.ceo.address.key(), InvalidOperationError::ReservedInsuranceIndex);
+
+        //Insurance company name string must not be longer than 35 characters
+        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+
+        //Note string must not be longer than 140 characters
+        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let insurance_company_stats = &mut ctx.accounts.insurance_company_stats;
+        let processor = &mut ctx.accounts.processor;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        
+        claim.insurance_company_index = insurance_company_index as i16;
+        claim.insurance_company_name = insurance_company_name.clone();
+        claim.content_hash = get_claim_content_hash(claim);
+   
+        insurance_company.is_active = true;
+        insurance_company.created_by = ctx.accounts.signer.key();
+        insurance_company.note = note;
+        insurance_company.insurance_company_name = insurance_company_name.clone();
+        
+        insurance_company_stats.initialized_insurance_company_count += 1;
+        insurance_company.id = insurance_company_stats.initialized_insurance_company_count;
+        insurance_company.insurance_company_index = insurance_company_index;
+
+        if insurance_company_index > MAX_RESERVED_INSURANCE_COMPANY_INDEX
+        {
+            insurance_company_stats.additional_insurance_company_count += 1;
+        }
+
+        processor.created_insurance_company_count += 1;
+
+        msg!("Insurance Company Initialized");
+        msg!("Insurance Company Index: {}", insurance_company_index);
+        msg!("Insurance Company Name: {}", insurance_company_name.clone());
+        msg!("Note: {}", insurance_company.note.clone());
+
+        Ok(())
+    }
+
+    pub fn edit_insurance_company(ctx: Context<EditInsuranceCompany>, 
+        insurance_company_index: u16,
+        is_active: bool,
+        insurance_company_name: String,
+        note: String) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Insurance company name string must not be longer than 35 characters
+        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+
+        //Note string must not be longer than 140 characters
+        require!(note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let insurance_company_stats = &mut ctx.accounts.insurance_company_stats;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        
+        insurance_company.is_active = is_active;
+        insurance_company.insurance_company_name = insurance_company_name.clone();
+        insurance_company.note = note.clone();
+
+        insurance_company_stats.edited_insurance_company_count += 1;
+
+        msg!("Insurance Company Edited");
+        msg!("Insurance Company Index: {}", insurance_company_index);
+        msg!("Insurance Company Active: {}", is_active);
+        msg!("Insurance Company Name: {}", insurance_company_name);
+        msg!("Note: {}", note);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::EditInsuranceCompany, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn update_claim_hospital_index(ctx: Context<UpdateClaim>,
+        _submitter_address: Pubkey,
+        hospital_index: u32
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Can't set different hospital index after hospital record has been created
+        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //The reserved hospital record slot is tied to the current hospital index
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+ 
+        let processor_stats = &mut ctx.accounts.processor_stats;
+
+        processor_stats.edited_claim_or_processed_claim_count += 1;
+        claim.hospital_index = hospital_index as i32;
+        claim.content_hash = get_claim_content_hash(claim);
+        
+        msg!("Claim Hospital Index updated");
+        msg!("Hospital Index: {}", hospital_index);
+
+        Ok(())
+    }
+
+    pub fn update_claim_insurance_company_index(ctx: Context<UpdateClaim>,
+        _submitter_address: Pubkey,
+        insurance_company_index: u16
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Can't set different insurance company index after insurance company record has been created
+        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //The reserved insurance company record slot is tied to the current insurance company index
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+
+        processor_stats.edited_claim_or_processed_claim_count += 1;
+        claim.insurance_company_index = insurance_company_index as i16;
+        claim.content_hash = get_claim_content_hash(claim);
+        
+        msg!("Claim Insurance Company Index updated");
+        msg!("Insurance Company Index: {}", insurance_company_index);
+
+        Ok(())
+    }
+
+    pub fn update_claim_location(ctx: Context<UpdateClaim>,
+        _submitter_address: Pubkey,
+        country_index: u16,
+        state_index: u32
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), claim.processor_address.key(), AuthorizationError::NotTheProcessor);
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Records are tied to the claim's location, so it can only change before any of them are created
+        require!(claim.is_patient_record_created == false &&
+        claim.is_hospital_record_created == false &&
+        claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //The reserved record slots are tied to the current location
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+
+        processor_stats.edited_claim_or_processed_claim_count += 1;
+        claim.country_index = country_index;
+        claim.state_index = state_index;
+        
+        msg!("Claim Location updated");
+        msg!("Country Index: {}", country_index);
+        msg!("State Index: {}", state_index);
+
+        Ok(())
+    }
+
+    pub fn flag_claim(ctx: Context<UpdateClaim>,
+        _submitter_address: Pubkey,
+        reason: String
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Can't flag a claim that's already flagged
+        require!(claim.is_flagged_for_review == false, InvalidOperationError::FlagSameState);
+
+        //Flag reason string must not be longer than 144 characters
+        require!(reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.flagged_claim_count += 1;
+
+        claim.is_flagged_for_review = true;
+        claim.flag_reason = reason;
+
+        msg!("Claim Flagged For Review");
+        msg!("Claim id: {}", claim.id);
+        msg!("Flag Reason: {}", claim.flag_reason);
+
+        Ok(())
+    }
+
+    pub fn append_processor_note(ctx: Context<UpdateClaim>,
+        _submitter_address: Pubkey,
+        processor_note: String
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor assigned to the claim can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), claim.processor_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Processor note string must not be longer than 144 characters
+        require!(processor_note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        //Notes are overwritten, the edit count lets the front end know the note has changed
+        claim.processor_notes = processor_note;
+        claim.processor_note_edit_count += 1;
+        
+        msg!("Claim Processor Note updated");
+        msg!("Processor Note: {}", claim.processor_notes);
+        msg!("Processor Note Edit Count: {}", claim.processor_note_edit_count);
+
+        Ok(())
+    }
+
+    pub fn add_claim_comment(ctx: Context<AddClaimComment>,
+        _submitter_address: Pubkey,
+        text: String
+    ) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+
+        //Only the submitter or the Processor assigned to the claim can call this function
+        require!(ctx.accounts.signer.key() == claim.submitter_address.key() ||
+        ctx.accounts.signer.key() == claim.processor_address.key(), AuthorizationError::NotSubmitterOrProcessor);
+
+        //Comment string must not be longer than 140 characters
+        require!(text.len() <= MAX_CLAIM_COMMENT_LENGTH, InvalidLengthError::ClaimCommentTooLong);
+
+        //Claims only have room for 5 comments
+        require!(claim.comments.len() < MAX_CLAIM_COMMENT_COUNT, InvalidOperationError::TooManyClaimComments);
+
+        claim.comments.push(ClaimComment
+        {
+            author: ctx.accounts.signer.key(),
+            text,
+            time: Clock::get()?.unix_timestamp as u64
+        });
+        
+        msg!("Claim Comment Added");
+        msg!("Author: {}", ctx.accounts.signer.key());
+        msg!("Comment Count: {}", claim.comments.len());
+
+        Ok(())
+    }
+
+    pub fn create_patient_record(ctx: Context<CreatePatientRecord>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Processor has to acknowledge the claim before creating its records
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Only create 1 patient record per claim
+        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Reserved record slots get populated instead, creating here would orphan the reserved records
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.created_patient_record_count += 1;
+        
+        let patient = &mut ctx.accounts.patient;
+        let patient_record = &mut ctx.accounts.patient_record;
+
+        claim.patient_record_index = patient.record_count;
+        claim.is_patient_record_created = true;
+        patient.record_count = patient.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        fill_patient_record(patient_record, claim, patient.record_count as u32, ctx.accounts.signer.key());
+
+        processor.created_patient_record_count += 1;
+
+        msg!("Patient Record Created");
+        msg!("Record ID: {}", patient.record_count);
+        msg!("Claim ID: {}", patient_record.claim_id);
+        msg!("Submitter Address: {}", claim.submitter_address);
+        msg!("Patient Index: {}", claim.patient_index);
+        msg!("Claim Amount: ${:.2}", patient_record.claim_amount as f64/100.00);
+        
+        Ok(())
+    }
+
+    pub fn create_hospital_and_insurance_company_records(ctx: Context<CreateHospitalAndInsuranceCompanyRecords>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Processor has to acknowledge the claim before creating its records
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Patient Record must already exist
+        require!(claim.is_patient_record_created == true, InvalidOperationError::RecordNotCreated);
+
+        //Only create 1 hospital record per claim
+        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Only create 1 insurance company record per claim
+        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Reserved record slots get populated instead, creating here would orphan the reserved records
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        //Records can't be attached to a deactivated hospital, the CEO has to edit it back to active first
+        require!(ctx.accounts.hospital.is_active == true, InvalidOperationError::HospitalInactive);
+
+        //Records can't be attached to a deactivated insurance company, the CEO has to edit it back to active first
+        require!(ctx.accounts.insurance_company.is_active == true, InvalidOperationError::InsuranceCompanyInactive);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.created_hospital_and_insurance_company_records_count += 1;
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.patient_record_only = false;
+
+        let hospital = &mut ctx.accounts.hospital;
+        let hospital_record = &mut ctx.accounts.hospital_record;
+
+        claim.hospital_record_index = hospital.record_count;
+        claim.is_hospital_record_created = true;
+        hospital.record_count = hospital.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        fill_hospital_record(hospital_record, claim, hospital.record_count, ctx.accounts.signer.key());
+        
+        processor.created_hospital_record_count += 1;
+
+        msg!("Hospital Record Created");
+        msg!("Record ID: {}", hospital.record_count);
+        msg!("Claim ID: {}", hospital_record.claim_id);
+        msg!("Hospital Index: {}", claim.hospital_index);
+        msg!("Claim Amount: ${:.2}", hospital_record.claim_amount as f64/100.00);
+
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+
+        claim.insurance_company_record_index = insurance_company.record_count;
+        claim.is_insurance_company_record_created = true;
+        insurance_company.record_count = insurance_company.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        fill_insurance_company_record(insurance_company_record, claim, insurance_company.record_count, ctx.accounts.signer.key());
+
+        processor.created_insurance_company_record_count += 1;
+
+        msg!("Insurance Company Record Created");
+        msg!("Record ID: {}", insurance_company.record_count);
+        msg!("Claim ID: {}", insurance_company_record.claim_id);
+        msg!("Insurance Company Index: {}", claim.insurance_company_index);
+        msg!("Claim Amount: ${:.2}", insurance_company_record.claim_amount as f64/100.00);
+        
+        Ok(())
+    }
+
+    pub fn reserve_record_slots(ctx: Context<ReserveRecordSlots>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Processor has to acknowledge the claim before creating its records
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Only reserve 1 set of record slots per claim
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        //Slots can't be reserved once records have been created the old way
+        require!(claim.is_patient_record_created == false &&
+        claim.is_hospital_record_created == false &&
+        claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        let patient = &mut ctx.accounts.patient;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+
+        //Move every record cursor now so the populate step never has to init an account
+        claim.patient_record_index = patient.record_count;
+        claim.hospital_record_index = hospital.record_count;
+        claim.insurance_company_record_index = insurance_company.record_count;
+        claim.are_record_slots_reserved = true;
+        patient.record_count = patient.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        hospital.record_count = hospital.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        insurance_company.record_count = insurance_company.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+
+        ctx.accounts.patient_record.status = Status::Processing as u8;
+        ctx.accounts.hospital_record.status = Status::Processing as u8;
+        ctx.accounts.insurance_company_record.status = Status::Processing as u8;
+
+        msg!("Record Slots Reserved");
+        msg!("Claim ID: {}", claim.id);
+        msg!("Patient Record Index: {}", claim.patient_record_index);
+        msg!("Hospital Record Index: {}", claim.hospital_record_index);
+        msg!("Insurance Company Record Index: {}", claim.insurance_company_record_index);
+
+        Ok(())
+    }
+
+    pub fn populate_reserved_records(ctx: Context<PopulateReservedRecords>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Processor has to acknowledge the claim before creating its records
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Records can only be populated into reserved slots
+        require!(claim.are_record_slots_reserved == true, InvalidOperationError::RecordSlotsNotReserved);
+
+        //A retried populate is a no-op so it can be safely resent
+        if claim.is_patient_record_created == true &&
+        claim.is_hospital_record_created == true &&
+        claim.is_insurance_company_record_created == true
+        {
+            msg!("Reserved Records Already Populated");
+            msg!("Claim ID: {}", claim.id);
+
+            return Ok(());
+        }
+
+        //Records can't be attached to a deactivated hospital, the CEO has to edit it back to active first
+        require!(ctx.accounts.hospital.is_active == true, InvalidOperationError::HospitalInactive);
+
+        //Records can't be attached to a deactivated insurance company, the CEO has to edit it back to active first
+        require!(ctx.accounts.insurance_company.is_active == true, InvalidOperationError::InsuranceCompanyInactive);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.created_patient_record_count += 1;
+        processor_stats.created_hospital_and_insurance_company_records_count += 1;
+
+        fill_patient_record(&mut ctx.accounts.patient_record, claim, claim.patient_record_index + 1, ctx.accounts.signer.key());
+        ctx.accounts.patient_record.patient_record_only = false;
+        fill_hospital_record(&mut ctx.accounts.hospital_record, claim, claim.hospital_record_index + 1, ctx.accounts.signer.key());
+        fill_insurance_company_record(&mut ctx.accounts.insurance_company_record, claim, claim.insurance_company_record_index + 1, ctx.accounts.signer.key());
+
+        claim.is_patient_record_created = true;
+        claim.is_hospital_record_created = true;
+        claim.is_insurance_company_record_created = true;
+
+        processor.created_patient_record_count += 1;
+        processor.created_hospital_record_count += 1;
+        processor.created_insurance_company_record_count += 1;
+
+        msg!("Reserved Records Populated");
+        msg!("Claim ID: {}", claim.id);
+        msg!("Submitter Address: {}", claim.submitter_address);
+        msg!("Claim Amount: ${:.2}", claim.claim_amount as f64/100.00);
+        
+        Ok(())
+    }
+
+    pub fn approve_claim(ctx: Context<ApproveClaim>, _submitter_address: Pubkey, eob_hash: Option<[u8; 32]>) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must still be being processed, guards against a claim being approved or denied twice
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Flagged claims need a second look, so only a Super Admin can approve them
+        require!(claim.is_flagged_for_review == false || processor.is_super_admin == true, AuthorizationError::NotSuperAdmin);
+
+        //Claims over the dual signoff amount need two processors, so they go through first approve claim instead
+        require!(requires_dual_signoff(&ctx.accounts.m4a_protocol, claim.claim_amount) == false, InvalidOperationError::DualSignoffRequired);
+
+        //Call the helper function to create the processed claim, one that already exists at this processor count index gets ProcessedClaimAlreadyExists
+        let mut processed_claim = create_processed_claim(&ctx.accounts.processed_claim, &ctx.accounts.signer, &ctx.accounts.system_program, processor.processed_claim_count, ctx.bumps.processed_claim)?;
+
+        //Call the helper function to load the submitter account, it's written back once the approval is done
+        let mut submitter = load_submitter_account(&ctx.accounts.submitter)?;
+
+        //Call the helper function to count this claim against the processor's daily limit
+        record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+        approve_claim_and_records(&mut ctx.accounts.processor_stats,
+            &mut ctx.accounts.claim_queue,
+            &mut submitter,
+            &mut ctx.accounts.patient,
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.hospital,
+            &mut ctx.accounts.insurance_company,
+            &mut ctx.accounts.patient_record,
+            &mut ctx.accounts.hospital_record,
+            &mut ctx.accounts.insurance_company_record,
+            &mut processed_claim,
+            processor,
+            claim,
+            ctx.accounts.signer.key())?;
+
+        store_submitter_account(&ctx.accounts.submitter, &submitter)?;
+
+        processor.is_processing_claim = false;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, processed_claim.submitter_address);
+
+        //The EOB hash is informational, claims approved without one keep all zeros
+        processed_claim.eob_hash = eob_hash.unwrap_or_default();
+        ctx.accounts.insurance_company_record.eob_hash = eob_hash.unwrap_or_default();
+
+        //The processed claim isn't an Anchor account, so it's written back by hand
+        store_processed_claim(&ctx.accounts.processed_claim, &processed_claim)?;
+
+        msg!("New Claim Approved");
+        msg!("For: ${:.2}", processed_claim.claim_amount as f64/100.00);
+        msg!("Approved Claim Count: {}", ctx.accounts.processor_stats.approved_claim_count);
+        msg!("User Address: {}", processed_claim.submitter_address);
+        msg!("Patient First Name: {}", ctx.accounts.patient.patient_first_name);
+        msg!("Patient Last Name: {}", ctx.accounts.patient.patient_last_name);
+
+        Ok(())
+    }
+
+    pub fn first_approve_claim(ctx: Context<FirstApproveClaim>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must still be being processed, guards against a claim being approved or denied twice
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Flagged claims need a second look, so only a Super Admin can approve them
+        require!(claim.is_flagged_for_review == false || processor.is_super_admin == true, AuthorizationError::NotSuperAdmin);
+
+        //Only claims over the dual signoff amount take two approvals, everything else goes through approve claim
+        require!(requires_dual_signoff(&ctx.accounts.m4a_protocol, claim.claim_amount) == true, InvalidOperationError::DualSignoffNotRequired);
+
+        //All records must be created before a claim can be approved, the second approval can't create them
+        require!(claim.is_patient_record_created == true &&
+        claim.is_hospital_record_created == true &&
+        claim.is_insurance_company_record_created == true, InvalidOperationError::RecordNotCreated);
+
+        //Nothing is processed yet, a second distinct processor creates the processed claim when they finalize it
+        claim.status = Status::PendingSecondApproval as u8;
+        claim.first_approver_address = ctx.accounts.signer.key();
+
+        processor.is_processing_claim = false;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+
+        msg!("First Approval Recorded");
+        msg!("For: ${:.2}", claim.claim_amount as f64/100.00);
+        msg!("User Address: {}", claim.submitter_address);
+
+        Ok(())
+    }
+
+    pub fn reject_first_approval(ctx: Context<RejectFirstApproval>, _submitter_address: Pubkey) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &ctx.accounts.processor;
+
+        //Claim must have its first approval to reject it
+        require!(claim.status == Status::PendingSecondApproval as u8, InvalidOperationError::ClaimNotPendingSecondApproval);
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //The first approver can't overrule their own approval, it takes a different processor
+        require_keys_neq!(ctx.accounts.signer.key(), claim.first_approver_address.key(), AuthorizationError::SameProcessorSecondApproval);
+
+        //Back in the queue unassigned, its records stay so whoever picks it up next can deny it with all records
+        claim.status = Status::Pending as u8;
+        claim.processor_address = SYSTEM_PROGRAM_ADDRESS;
+        claim.first_approver_address = SYSTEM_PROGRAM_ADDRESS;
+
+        msg!("First Approval Rejected By: ");
+        msg!("{}", ctx.accounts.signer.key());
+        msg!("User Address: {}", claim.submitter_address);
+
+        Ok(())
+    }
+
+    pub fn second_approve_claim(ctx: Context<SecondApproveClaim>, _submitter_address: Pubkey, eob_hash: Option<[u8; 32]>) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must have its first approval, guards against a claim being finalized twice
+        require!(claim.status == Status::PendingSecondApproval as u8, InvalidOperationError::ClaimNotPendingSecondApproval);
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //The second approval has to come from a different processor
+        require_keys_neq!(ctx.accounts.signer.key(), claim.first_approver_address.key(), AuthorizationError::SameProcessorSecondApproval);
+
+        //Flagged claims need a second look, so only a Super Admin can approve them
+        require!(claim.is_flagged_for_review == false || processor.is_super_admin == true, AuthorizationError::NotSuperAdmin);
+
+        //Call the helper function to create the processed claim, one that already exists at this processor count index gets ProcessedClaimAlreadyExists
+        let mut processed_claim = create_processed_claim(&ctx.accounts.processed_claim, &ctx.accounts.signer, &ctx.accounts.system_program, processor.processed_claim_count, ctx.bumps.processed_claim)?;
+
+        //Call the helper function to load the submitter account, it's written back once the approval is done
+        let mut submitter = load_submitter_account(&ctx.accounts.submitter)?;
+
+        //Call the helper function to count this claim against the processor's daily limit
+        record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+        approve_claim_and_records(&mut ctx.accounts.processor_stats,
+            &mut ctx.accounts.claim_queue,
+            &mut submitter,
+            &mut ctx.accounts.patient,
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.hospital,
+            &mut ctx.accounts.insurance_company,
+            &mut ctx.accounts.patient_record,
+            &mut ctx.accounts.hospital_record,
+            &mut ctx.accounts.insurance_company_record,
+            &mut processed_claim,
+            processor,
+            claim,
+            ctx.accounts.signer.key())?;
+
+        store_submitter_account(&ctx.accounts.submitter, &submitter)?;
+
+        processed_claim.first_approver_address = claim.first_approver_address;
+
+        //The EOB hash is informational, claims approved without one keep all zeros
+        processed_claim.eob_hash = eob_hash.unwrap_or_default();
+        ctx.accounts.insurance_company_record.eob_hash = eob_hash.unwrap_or_default();
+
+        //The processed claim isn't an Anchor account, so it's written back by hand
+        store_processed_claim(&ctx.accounts.processed_claim, &processed_claim)?;
+
+        msg!("Second Approval Recorded, Claim Approved");
+        msg!("For: ${:.2}", processed_claim.claim_amount as f64/100.00);
+        msg!("First Approver: {}", processed_claim.first_approver_address);
+        msg!("User Address: {}", processed_claim.submitter_address);
+
+        Ok(())
+    }
+
+    pub fn approve_claims_batch<'info>(ctx: Context<'_, '_, 'info, 'info, ApproveClaimsBatch<'info>>) -> Result<()> 
+    {
+        let processor = &mut ctx.accounts.processor;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only a Super Admin can approve claims in a batch
+        require!(processor.is_super_admin == true, AuthorizationError::NotSuperAdmin);
+
+        //Each claim needs its full set of accounts, and the batch is bounded to stay under the compute limit
+        let batch_claim_count = ctx.remaining_accounts.len() / BATCH_APPROVAL_ACCOUNTS_PER_CLAIM;
+        require!(ctx.remaining_accounts.len().is_multiple_of(BATCH_APPROVAL_ACCOUNTS_PER_CLAIM) &&
+        batch_claim_count > 0 &&
+        batch_claim_count <= MAX_BATCH_APPROVAL_CLAIM_COUNT, InvalidOperationError::InvalidBatchSize);
+
+        let signer_address = ctx.accounts.signer.key();
+
+        //Accounts per claim: claim, submitter, patient, state, patient record, hospital, hospital record, insurance company, insurance company record, claim processor, processed claim, submitter wallet, claim processor's claim index
+        for claim_accounts in ctx.remaining_accounts.chunks(BATCH_APPROVAL_ACCOUNTS_PER_CLAIM)
+        {
+            let claim = Box::new(Account::<Claim>::try_from(&claim_accounts[0])?);
+            require_batch_pda(&claim_accounts[0], &[b"claim".as_ref(), claim.submitter_address.as_ref()])?;
+
+            //Claim must still be being processed, guards against a claim being approved or denied twice
+            require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+            //Claims over the dual signoff amount need two processors, so they can't be approved in a batch
+            require!(requires_dual_signoff(&ctx.accounts.m4a_protocol, claim.claim_amount) == false, InvalidOperationError::DualSignoffRequired);
+
+            //All records must be created before a claim can be approved
+            require!(claim.is_patient_record_created == true &&
+            claim.is_hospital_record_created == true &&
+            claim.is_insurance_company_record_created == true, InvalidOperationError::RecordNotCreated);
+
+            let country_index_bytes = claim.country_index.to_le_bytes();
+            let state_index_bytes = claim.state_index.to_le_bytes();
+            let hospital_index_bytes = claim.hospital_index.to_le_bytes();
+            let insurance_company_index_bytes = claim.insurance_company_index.to_le_bytes();
+
+            require_batch_pda(&claim_accounts[1], &[b"submitter".as_ref(), claim.submitter_address.as_ref()])?;
+            require_batch_pda(&claim_accounts[2], &[b"patient".as_ref(), claim.submitter_address.as_ref(), claim.patient_index.to_le_bytes().as_ref()])?;
+            require_batch_pda(&claim_accounts[3], &[b"state".as_ref(), country_index_bytes.as_ref(), state_index_bytes.as_ref()])?;
+            require_batch_pda(&claim_accounts[4], &[b"patientRecord".as_ref(), claim.submitter_address.as_ref(), claim.patient_index.to_le_bytes().as_ref(), claim.patient_record_index.to_le_bytes().as_ref()])?;
+            require_batch_pda(&claim_accounts[5], &[b"hospital".as_ref(), country_index_bytes.as_ref(), state_index_bytes.as_ref(), hospital_index_bytes.as_ref()])?;
+            require_batch_pda(&claim_accounts[6], &[b"hospitalRecord".as_ref(), country_index_bytes.as_ref(), state_index_bytes.as_ref(), hospital_index_bytes.as_ref(), claim.hospital_record_index.to_le_bytes().as_ref()])?;
+            require_batch_pda(&claim_accounts[7], &[b"insuranceCompany".as_ref(), insurance_company_index_bytes.as_ref()])?;
+            require_batch_pda(&claim_accounts[8], &[b"insuranceCompanyRecord".as_ref(), insurance_company_index_bytes.as_ref(), claim.insurance_company_record_index.to_le_bytes().as_ref()])?;
+            require_batch_pda(&claim_accounts[9], &[b"processor".as_ref(), claim.processor_address.as_ref()])?;
+            require_batch_pda(&claim_accounts[12], &[b"processorClaimIndex".as_ref(), claim.processor_address.as_ref()])?;
+
+            //The claim's rent goes back to the submitter, so the wallet passed in has to be theirs
+            require_keys_eq!(claim_accounts[11].key(), claim.submitter_address, InvalidOperationError::InvalidBatchAccount);
+
+            let mut submitter = Box::new(Account::<SubmitterAccount>::try_from(&claim_accounts[1])?);
+            let mut patient = Box::new(Account::<PatientAccount>::try_from(&claim_accounts[2])?);
+            let mut state = Box::new(Account::<StateAccount>::try_from(&claim_accounts[3])?);
+            let mut patient_record = Box::new(Account::<PatientRecord>::try_from(&claim_accounts[4])?);
+            let mut hospital = Box::new(Account::<Hospital>::try_from(&claim_accounts[5])?);
+            let mut hospital_record = Box::new(Account::<HospitalRecord>::try_from(&claim_accounts[6])?);
+            let mut insurance_company = Box::new(Account::<InsuranceCompany>::try_from(&claim_accounts[7])?);
+            let mut insurance_company_record = Box::new(Account::<InsuranceCompanyRecord>::try_from(&claim_accounts[8])?);
+
+            //Processed claim is created the same way the init constraint on approve claim would
+            let processor_count_index_bytes = processor.processed_claim_count.to_le_bytes();
+            let (processed_claim_address, processed_claim_bump) = Pubkey::find_program_address(&[b"processedClaim".as_ref(), signer_address.as_ref(), processor_count_index_bytes.as_ref()], &ID);
+            require_keys_eq!(claim_accounts[10].key(), processed_claim_address, InvalidOperationError::InvalidBatchAccount);
+
+            //Call the helper function to create the processed claim, lamports sent to the PDA ahead of time can't block it
+            create_processed_claim_account(&claim_accounts[10],
+                &ctx.accounts.signer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[b"processedClaim".as_ref(), signer_address.as_ref(), processor_count_index_bytes.as_ref(), &[processed_claim_bump]])?;
+
+            let mut processed_claim = Box::new(Account::<ProcessedClaim>::try_from_unchecked(&claim_accounts[10])?);
+
+            //Call the helper function to count this claim against the signer's daily limit
+            record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+            approve_claim_and_records(&mut ctx.accounts.processor_stats,
+                &mut ctx.accounts.claim_queue,
+                &mut submitter,
+                &mut patient,
+                &mut state,
+                &mut hospital,
+                &mut insurance_company,
+                &mut patient_record,
+                &mut hospital_record,
+                &mut insurance_company_record,
+                &mut processed_claim,
+                processor,
+                &claim,
+                signer_address)?;
+
+            //Free up whoever was processing the claim, the signer can't exist in 2 processor variables so has to be checked
+            if claim.processor_address == signer_address
+            {
+                processor.is_processing_claim = false;
+                remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+            }
+            else
+            {
+                let mut claim_processor = Box::new(Account::<ProcessorAccount>::try_from(&claim_accounts[9])?);
+                let mut claim_processor_claim_index = Box::new(Account::<ProcessorClaimIndex>::try_from(&claim_accounts[12])?);
+                claim_processor.is_processing_claim = false;
+                remove_claim_in_progress(&mut claim_processor, &mut claim_processor_claim_index, claim.submitter_address);
+                claim_processor.exit(&ID)?;
+                claim_processor_claim_index.exit(&ID)?;
+            }
+
+            //Written back right away so a state, hospital, or insurance company shared by claims later in the batch sees these updates
+            submitter.exit(&ID)?;
+            patient.exit(&ID)?;
+            state.exit(&ID)?;
+            patient_record.exit(&ID)?;
+            hospital.exit(&ID)?;
+            hospital_record.exit(&ID)?;
+            insurance_company.exit(&ID)?;
+            insurance_company_record.exit(&ID)?;
+            processed_claim.exit(&ID)?;
+            claim.close(claim_accounts[11].clone())?;
+
+            msg!("Claim Approved In Batch For: ${:.2}", processed_claim.claim_amount as f64/100.00);
+            msg!("User Address: {}", processed_claim.submitter_address);
+        }
+
+        msg!("Claims Batch Approved");
+        msg!("Claims In Batch: {}", batch_claim_count);
+        msg!("Approved Claim Count: {}", ctx.accounts.processor_stats.approved_claim_count);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::ApproveClaimsBatch, signer_address)?;
+
+        Ok(())
+    }
+
+    pub fn approve_claim_with_edits(ctx: Context<ApproveClaimWithEdits>, 
+        _submitter_address: Pubkey,
+        hospital_type: u8,
+        hospital_longitude: f64,
+        hospital_latitude: f64,
+        hospital_name: String,
+        hospital_address: String,
+        hospital_city: String,
+        hospital_zip_code: u32,
+        hospital_phone_number: u128,
+        hospital_bill_invoice_number: String,
+        claim_note: String,
+        claim_amount: u64,
+        copay_amount: u64,
+        deductible_amount: u64,
+        ailment: String,
+        insurance_company_name: String,
+        eob_hash: Option<[u8; 32]>) -> Result<()> 
+    {
+        //Only logged in debug-compute builds so mainnet doesn't pay for it
+        #[cfg(feature = "debug-compute")]
+        sol_log_compute_units();
+
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must still be being processed, guards against a claim being approved or denied twice
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Call the helper function to create the processed claim, one that already exists at this processor count index gets ProcessedClaimAlreadyExists
+        let mut processed_claim = create_processed_claim(&ctx.accounts.processed_claim, &ctx.accounts.signer, &ctx.accounts.system_program, processor.processed_claim_count, ctx.bumps.processed_claim)?;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Flagged claims need a second look, so only a Super Admin can approve them
+        require!(claim.is_flagged_for_review == false || processor.is_super_admin == true, AuthorizationError::NotSuperAdmin);
+
+        //Claims over the dual signoff amount need two processors, so the edited amount can't be over it
+        require!(requires_dual_signoff(&ctx.accounts.m4a_protocol, claim_amount) == false, InvalidOperationError::DualSignoffRequired);
+
+        //Call the helper function to count this claim against the processor's daily limit
+        record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+        //Hospital type must be valid
+        require!((hospital_type == HospitalType::General as u8) ||
+        (hospital_type == HospitalType::Dental as u8) ||
+        (hospital_type == HospitalType::Vision as u8) ||
+        (hospital_type == HospitalType::Mental as u8) ||
+        (hospital_type == HospitalType::Other as u8), InvalidType::HospitalTypeInvalid);
+
+        //Call the helper function to make sure the hospital coordinates are on the map
+        check_hospital_coordinates(hospital_latitude, hospital_longitude)?;
+
+        //Hospital name string must not be longer than 50 characters
+        require!(hospital_name.len() <= MAX_HOSPITAL_NAME_LENGTH, InvalidLengthError::HospitalNameTooLong);
+
+        //Hospital address string must not be longer than 100 characters
+        require!(hospital_address.len() <= MAX_HOSPITAL_ADDRESS_LENGTH, InvalidLengthError::HospitalAddressTooLong);
+
+        //Hospital city string must not be longer than 40 characters
+        require!(hospital_city.len() <= MAX_HOSPITAL_CITY_LENGTH, InvalidLengthError::HospitalCityTooLong);
+
+        //Hospital phone number must not be longer than 15 digits (E.164)
+        require!(hospital_phone_number < 10u128.pow(MAX_HOSPITAL_PHONE_NUMBER_DIGITS), InvalidLengthError::HospitalPhoneNumberTooLong);
+
+        //Hospital bill invoice number string must not be longer than 20 characters
+        require!(hospital_bill_invoice_number.len() <= MAX_HOSPITAL_BILL_INVOICE_NUMBER_LENGTH, InvalidLengthError::HospitalBillInvoiceNumberTooLong);
+
+        //Ailment string must not be longer than 45 characters
+        require!(ailment.len() <= MAX_AILMENT_LENGTH, InvalidLengthError::AilmentTooLong);
+
+        //Note string must not be longer than 140 characters
+        require!(claim_note.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        //Insurance company name string must not be longer than 35 characters
+        require!(insurance_company_name.len() <= MAX_INSURANCE_COMPANY_NAME_LENGTH, InvalidLengthError::InsuranceCompanyNameTooLong);
+
+        //Copay and deductible are part of the claim amount, so together they can't be more than it
+        require!(copay_amount as u128 + deductible_amount as u128 <= claim_amount as u128, InvalidOperationError::CopayAndDeductibleExceedClaimAmount);
+
+        //Claim amount can only be edited up by the max upward edit bps unless an active super admin co-signs, 0 means there's no bound
+        let max_upward_edit_bps = ctx.accounts.m4a_protocol.max_upward_edit_bps;
+        if max_upward_edit_bps > 0 && claim_amount > claim.claim_amount
+        {
+            let is_super_admin_co_signed = match (&ctx.accounts.super_admin_co_signer, &ctx.accounts.super_admin_co_signer_processor)
+            {
+                (Some(co_signer), Some(co_signer_processor)) => co_signer_processor.address == co_signer.key() &&
+                co_signer_processor.is_super_admin == true &&
+                co_signer_processor.is_active == true,
+                _ => false
+            };
+
+            require!(is_super_admin_co_signed == true ||
+            (claim_amount - claim.claim_amount) as u128 * 10000 <= claim.claim_amount as u128 * max_upward_edit_bps as u128, InvalidOperationError::AmountEditTooLarge);
+        }
+
+        //Call the helper function to keep the patient within their lifetime benefit cap using the edited amount
+        check_lifetime_benefit_cap(&ctx.accounts.patient, claim_amount)?;
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let claim_queue = &mut ctx.accounts.claim_queue;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+
+        //Update Amount Totals & Counts
+        processor_stats.approved_claim_count += 1;
+        processor_stats.approved_claim_count_by_type[hospital_type as usize] += 1;
+        processor_stats.approved_amount_buckets[get_approved_amount_bucket(claim_amount)] += 1;
+        processor_stats.processed_claim_count += 1;
+        processor_stats.approved_claim_amount += claim_amount;
+        leave_queue(claim_queue)?;
+        submitter.approved_claim_count += 1;
+        submitter.approved_claim_amount += claim_amount;
+        patient.approved_claim_count += 1;
+        patient.approved_claim_amount += claim_amount;
+        state.approved_claim_count += 1;
+        state.approved_claim_amount += claim_amount;
+        hospital.approved_claim_count += 1;
+        hospital.approved_claim_amount += claim_amount;
+        insurance_company.approved_claim_count += 1;
+        insurance_company.approved_claim_amount += claim_amount;
+        
+        //Update Hospital
+        hospital.hospital_type = hospital_type;
+        hospital.hospital_longitude = hospital_longitude;
+        hospital.hospital_latitude = hospital_latitude;
+        hospital.hospital_name = hospital_name.clone();
+        hospital.hospital_address = hospital_address.clone();
+        hospital.hospital_city = hospital_city.clone();
+        hospital.hospital_zip_code = hospital_zip_code;
+        hospital.hospital_phone_number = hospital_phone_number.clone();
+
+        //Update Insurance Company
+        insurance_company.insurance_company_name = insurance_company_name.clone();
+
+        //Update Records
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Approved as u8;
+        patient_record.processor_count_index = processor.processed_claim_count;
+        patient_record.hospital_index = claim.hospital_index as u32;
+        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        patient_record.claim_amount = claim_amount;
+        patient_record.copay_amount = copay_amount;
+        patient_record.deductible_amount = deductible_amount;
+        patient_record.ailment = ailment.clone();
+        patient_record.diagnosis_codes = claim.diagnosis_codes.clone();
+        patient_record.note = claim_note.clone();
+        patient_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        patient_record.insurance_company_index = claim.insurance_company_index as u16;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.status = Status::Approved as u8;
+        hospital_record.processor_count_index = processor.processed_claim_count;
+        hospital_record.processed_claim_id = processor_stats.processed_claim_count;
+        hospital_record.claim_amount = claim_amount;
+        hospital_record.copay_amount = copay_amount;
+        hospital_record.deductible_amount = deductible_amount;
+        hospital_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        hospital_record.ailment = ailment.clone();
+        hospital_record.diagnosis_codes = claim.diagnosis_codes.clone();
+        hospital_record.note = claim_note.clone();
+        hospital_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        hospital_record.insurance_company_index = claim.insurance_company_index as u16;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.status = Status::Approved as u8;
+        insurance_company_record.processor_count_index = processor.processed_claim_count;
+        insurance_company_record.hospital_index = claim.hospital_index as u32;
+        insurance_company_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        insurance_company_record.claim_amount = claim_amount;
+        insurance_company_record.copay_amount = copay_amount;
+        insurance_company_record.deductible_amount = deductible_amount;
+        insurance_company_record.ailment = ailment.clone();
+        insurance_company_record.diagnosis_codes = claim.diagnosis_codes.clone();
+        insurance_company_record.note = claim_note.clone();
+        insurance_company_record.processed_time = Clock::get()?.unix_timestamp as u64;
+        insurance_company_record.eob_hash = eob_hash.unwrap_or_default();
+
+        //Create Processed Claim
+        let processed_claim = &mut processed_claim;
+        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
+        processed_claim.claim_id = claim.id;
+        processed_claim.processor_count_index = processor.processed_claim_count;
+        processed_claim.status = Status::Approved as u8;
+        processed_claim.is_patient_record_created = true;
+        processed_claim.is_hospital_record_created = true;
+        processed_claim.is_insurance_company_record_created = true;
+        processed_claim.patient_record_index = claim.patient_record_index;
+        processed_claim.hospital_record_index = claim.hospital_record_index;
+        processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
+        processed_claim.processor_address = ctx.accounts.signer.key();
+        processed_claim.record_creator_address = ctx.accounts.patient_record.processor_address;
+        processed_claim.resolving_processor_address = ctx.accounts.signer.key();
+        processed_claim.submitter_address = claim.submitter_address;
+        processed_claim.patient_index = claim.patient_index;
+        processed_claim.country_index = claim.country_index;
+        processed_claim.state_index = claim.state_index;
+        processed_claim.hospital_index = claim.hospital_index;
+        processed_claim.hospital_type = hospital_type;
+        processed_claim.hospital_name = hospital_name;
+        processed_claim.hospital_address = hospital_address;
+        processed_claim.hospital_city = hospital_city;
+        processed_claim.hospital_zip_code = hospital_zip_code;
+        processed_claim.hospital_phone_number = hospital_phone_number;
+        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number;
+        processed_claim.note = claim_note;
+        processed_claim.processor_notes = claim.processor_notes.clone();
+        processed_claim.processor_note_edit_count = claim.processor_note_edit_count;
+        processed_claim.claim_amount = claim_amount;
+        processed_claim.copay_amount = copay_amount;
+        processed_claim.deductible_amount = deductible_amount;
+        processed_claim.insurance_covered_amount = get_insurance_covered_amount(claim_amount, copay_amount, deductible_amount);
+        processed_claim.currency_code = claim.currency_code;
+        processed_claim.fee_mint = claim.fee_mint;
+        processed_claim.fee_amount_paid = claim.fee_amount_paid;
+        processed_claim.ailment = ailment;
+        processed_claim.diagnosis_codes = claim.diagnosis_codes.clone();
+        processed_claim.is_flagged_for_review = claim.is_flagged_for_review;
+        processed_claim.flag_reason = claim.flag_reason.clone();
+        processed_claim.eob_hash = eob_hash.unwrap_or_default();
+        processed_claim.insurance_company_index = claim.insurance_company_index;
+        processed_claim.insurance_company_name = insurance_company_name;
+        processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+        processed_claim.submitted_time = claim.submitted_time;
+        processed_claim.processed_time = Clock::get()?.unix_timestamp as u64;
+        record_sla_breach(processed_claim, processor_stats, claim.sla_deadline);
+
+        //The edits only reach the claim details, who the claim belongs to has to carry over from the claim untouched
+        require_keys_eq!(processed_claim.submitter_address.key(), claim.submitter_address.key(), InvalidOperationError::ClaimIdentityChanged);
+        require!(processed_claim.patient_index == claim.patient_index &&
+        processed_claim.claim_id == claim.id, InvalidOperationError::ClaimIdentityChanged);
+
+        processor.approved_claim_amount += claim.claim_amount;
+        processor.approved_claim_count += 1;
+        processor.processed_claim_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        update_processor_denial_rate(processor);
+        processor.is_processing_claim = false;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+
+        msg!("New Claim Approved With Edits");
+        msg!("For: ${:.2}", claim_amount as f64/100.00);
+        msg!("Approved Claim Count: {}", processor_stats.approved_claim_count);
+        msg!("User Address: {}", claim.submitter_address);
+        msg!("Patient First Name: {}", patient.patient_first_name);
+        msg!("Patient Last Name: {}", patient.patient_last_name);
+        #[cfg(feature = "debug-compute")]
+        sol_log_compute_units();
+
+        emit!(ClaimApproved
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        //The processed claim isn't an Anchor account, so it's written back by hand
+        store_processed_claim(&ctx.accounts.processed_claim, processed_claim)?;
+
+        Ok(())
+    }
+
+    pub fn max_deny_pending_claim(ctx: Context<MaxDenyPendingClaim>, submitter_address: Pubkey, error_code: u8) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        let claim = &mut ctx.accounts.claim;
+        let admin_processor = &mut ctx.accounts.admin_processor;
+
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //Claim must be in a pending state to use this Max Deny
+        require!(claim.status == Status::Pending as u8, InvalidOperationError::ClaimNotPending);
+
+        //Can't max deny claim if patient record was created
+        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't max deny claim if hospital record was created
+        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't max deny claim if insurance company record was created
+        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        processor_stats.max_denied_claim_count += 1;
+        submitter.max_denied_claim_count += 1;
+        patient.max_denied_claim_count += 1;
+        admin_processor.max_denied_claim_count += 1;
+        admin_processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+     
+        let claim_queue = &mut ctx.accounts.claim_queue; 
+        leave_queue(claim_queue)?;
+
+        //The submitter shouldn't pay for a claim the protocol got wrong, so a protocol error gets the fee back
+        if error_code == PROTOCOL_ERROR_CODE
+        {
+            //Call the helper function to refund the fee
+            refund_protocol_error_fee(&ctx.accounts.treasurer,
+                &ctx.accounts.treasurer_signer,
+                &ctx.accounts.treasurer_fee_ata,
+                &ctx.accounts.submitter_fee_ata,
+                &ctx.accounts.token_program,
+                claim)?;
+        }
+
+        msg!("New Max Pending Claim Denial");
+        msg!("Max Denied Claim Count: {}", processor_stats.max_denied_claim_count);
+        msg!("User Address: {}", submitter_address);
+        msg!("Error Code: {}", error_code);
+        
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::MaxDenyPendingClaim, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn max_deny_in_progress_claim(ctx: Context<MaxDenyInProgressClaim>, submitter_address: Pubkey, error_code: u8) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        let claim = &mut ctx.accounts.claim;
+        let admin_processor = &mut ctx.accounts.admin_processor;
+        let claim_processor = &mut ctx.accounts.claim_processor;
+
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        admin_processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //Claim must be assigned or in a processing state to use this Max Deny
+        require!(claim.status == Status::Processing as u8 || claim.status == Status::Assigned as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Can't max deny claim if patient record was created
+        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't max deny claim if hospital record was created
+        require!(claim.is_hospital_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't max deny claim if insurance company record was created
+        require!(claim.is_insurance_company_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        processor_stats.max_denied_claim_count += 1;
+        submitter.max_denied_claim_count += 1;
+        patient.max_denied_claim_count += 1;
+        admin_processor.max_denied_claim_count += 1;
+        admin_processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+     
+        let claim_queue = &mut ctx.accounts.claim_queue; 
+        leave_queue(claim_queue)?;
+
+        if claim.status == Status::Processing as u8 || claim.status == Status::Assigned as u8
+        {
+            claim_processor.is_processing_claim = false;
+            remove_claim_in_progress(claim_processor, &mut ctx.accounts.claim_processor_claim_index, submitter_address);
+
+            //Check if Signer was the processor on the claim, they can't exist in 2 processor variables in this function, so have to do an extra check
+            if claim.processor_address == ctx.accounts.signer.key()
+            {
+                admin_processor.is_processing_claim = false;
+                remove_claim_in_progress(admin_processor, &mut ctx.accounts.claim_processor_claim_index, submitter_address);
+                claim_processor.max_denied_claim_count += 1;
+            }
+        }
+
+        //The submitter shouldn't pay for a claim the protocol got wrong, so a protocol error gets the fee back
+        if error_code == PROTOCOL_ERROR_CODE
+        {
+            //Call the helper function to refund the fee
+            refund_protocol_error_fee(&ctx.accounts.treasurer,
+                &ctx.accounts.treasurer_signer,
+                &ctx.accounts.treasurer_fee_ata,
+                &ctx.accounts.submitter_fee_ata,
+                &ctx.accounts.token_program,
+                claim)?;
+        }
+
+        msg!("New Max In Progress Claim Denial");
+        msg!("Max Denied Claim Count: {}", processor_stats.max_denied_claim_count);
+        msg!("User Address: {}", submitter_address);
+        msg!("Error Code: {}", error_code);
+        
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::MaxDenyInProgressClaim, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn create_patient_record_and_deny_claim(ctx: Context<CreatePatientRecordAndDenyClaim>, _submitter_address: Pubkey, denial_reason: String, denial_reason_code: Option<u16>) -> Result<()> 
+    {
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must still be being processed, guards against a claim being approved or denied twice
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Call the helper function to create the processed claim, one that already exists at this processor count index gets ProcessedClaimAlreadyExists
+        let mut processed_claim = create_processed_claim(&ctx.accounts.processed_claim, &ctx.accounts.signer, &ctx.accounts.system_program, processor.processed_claim_count, ctx.bumps.processed_claim)?;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Call the helper function to count this claim against the processor's daily limit
+        record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+        let state = &mut ctx.accounts.state;
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        processor_stats.denied_claim_count += 1;
+        processor_stats.denied_claim_count_by_type[claim.hospital_type as usize] += 1;
+        state.denied_claim_count += 1;
+        processor_stats.processed_claim_count += 1;
+        processor_stats.created_patient_record_count += 1;
+
+        //Only create 1 patient record per claim
+        require!(claim.is_patient_record_created == false, InvalidOperationError::RecordAlreadyCreated);
+
+        //Reserved record slots get populated instead, creating here would orphan the reserved records
+        require!(claim.are_record_slots_reserved == false, InvalidOperationError::RecordSlotsAlreadyReserved);
+
+        //Call the helper function to fill in the denial reason from its template when only a reason code is given
+        let denial_reason = get_denial_reason(denial_reason, denial_reason_code, &ctx.accounts.denial_reason_template)?;
+
+        //Denial note string must not be longer than 140 characters
+        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let claim_queue = &mut ctx.accounts.claim_queue; 
+        leave_queue(claim_queue)?;
+
+        let patient = &mut ctx.accounts.patient;
+        let submitter = &mut ctx.accounts.submitter;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        let processed_claim = &mut processed_claim;
+        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
+        processed_claim.claim_id = claim.id;
+        processed_claim.processor_count_index = processor.processed_claim_count;
+        processed_claim.status = Status::Denied as u8;
+        processed_claim.denial_reason = denial_reason.clone();
+        processed_claim.is_patient_record_created = true;
+        processed_claim.patient_record_index = patient.record_count;
+        processed_claim.processor_address = ctx.accounts.signer.key();
+        processed_claim.record_creator_address = ctx.accounts.signer.key();
+        processed_claim.resolving_processor_address = ctx.accounts.signer.key();
+        processed_claim.submitter_address = claim.submitter_address;
+        processed_claim.patient_index = claim.patient_index;
+        processed_claim.country_index = claim.country_index;
+        processed_claim.state_index = claim.state_index;
+        processed_claim.hospital_index = claim.hospital_index;
+        processed_claim.hospital_type = claim.hospital_type;
+        processed_claim.hospital_name = claim.hospital_name.clone();
+        processed_claim.hospital_address = claim.hospital_address.clone();
+        processed_claim.hospital_city = claim.hospital_city.clone();
+        processed_claim.hospital_zip_code = claim.hospital_zip_code;
+        processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
+        processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+        processed_claim.note = claim.note.clone();
+        processed_claim.processor_notes = claim.processor_notes.clone();
+        processed_claim.processor_note_edit_count = claim.processor_note_edit_count;
+        processed_claim.claim_amount = claim.claim_amount;
+        processed_claim.copay_amount = claim.copay_amount;
+        processed_claim.deductible_amount = claim.deductible_amount;
+        processed_claim.currency_code = claim.currency_code;
+        processed_claim.fee_mint = claim.fee_mint;
+        processed_claim.fee_amount_paid = claim.fee_amount_paid;
+        processed_claim.ailment = claim.ailment.clone();
+        processed_claim.diagnosis_codes = claim.diagnosis_codes.clone();
+        processed_claim.is_flagged_for_review = claim.is_flagged_for_review;
+        processed_claim.flag_reason = claim.flag_reason.clone();
+        processed_claim.insurance_company_index = claim.insurance_company_index;
+        processed_claim.insurance_company_name = claim.insurance_company_name.clone();
+        processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+        processed_claim.submitted_time = claim.submitted_time;
+        processed_claim.processed_time = time_stamp;
+        record_sla_breach(processed_claim, processor_stats, claim.sla_deadline);
+        
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient.record_count = patient.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        patient_record.record_id = patient.record_count as u32;
+        patient_record.claim_id = claim.id as u32;
+        patient_record.status = Status::Denied as u8;
+        patient_record.patient_record_only = true;
+        patient_record.submitter_address = claim.submitter_address;
+        patient_record.processor_address = ctx.accounts.signer.key();
+        patient_record.processor_count_index = processor.processed_claim_count;
+        patient_record.denial_reason = denial_reason.clone();
+        patient_record.country_index = claim.country_index;
+        patient_record.state_index = claim.state_index;
+        patient_record.hospital_index = claim.hospital_index as u32;
+        patient_record.insurance_company_index = claim.insurance_company_index as u16;
+        patient_record.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+        patient_record.claim_amount = claim.claim_amount;
+        patient_record.copay_amount = claim.copay_amount;
+        patient_record.deductible_amount = claim.deductible_amount;
+        patient_record.ailment = claim.ailment.clone();
+        patient_record.diagnosis_codes = claim.diagnosis_codes.clone();
+        patient_record.note = claim.note.clone();
+        patient_record.submitted_time = claim.submitted_time;
+        patient_record.processed_time = time_stamp;
+        
+        submitter.denied_claim_count += 1;
+        patient.denied_claim_count += 1;
+
+        processor.created_patient_record_count += 1;
+        processor.denied_claim_count += 1;
+        processor.processed_claim_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        update_processor_denial_rate(processor);
+        processor.is_processing_claim = false;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+        
+        msg!("New Patient Record And Claim Denial");
+        msg!("Denied Claim Count: {}", processor_stats.denied_claim_count);
+        msg!("User Address: {}", claim.submitter_address);
+        msg!("Reason: {}", denial_reason.clone());
+
+        emit!(ClaimDenied
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        //The processed claim isn't an Anchor account, so it's written back by hand
+        store_processed_claim(&ctx.accounts.processed_claim, processed_claim)?;
+
+        Ok(())
+    }
+
+    pub fn deny_claim_with_all_records(ctx: Context<DenyClaimWithAllRecords>, _submitter_address: Pubkey, denial_reason: String, denial_reason_code: Option<u16>) -> Result<()> 
+    {
+        //Only logged in debug-compute builds so mainnet doesn't pay for it
+        #[cfg(feature = "debug-compute")]
+        sol_log_compute_units();
+
+        let claim = &mut ctx.accounts.claim;
+        let processor = &mut ctx.accounts.processor;
+        
+        //Claim must still be being processed, guards against a claim being approved or denied twice
+        require!(claim.status == Status::Processing as u8, InvalidOperationError::ClaimNotBeingProcessed);
+
+        //Call the helper function to create the processed claim, one that already exists at this processor count index gets ProcessedClaimAlreadyExists
+        let mut processed_claim = create_processed_claim(&ctx.accounts.processed_claim, &ctx.accounts.signer, &ctx.accounts.system_program, processor.processed_claim_count, ctx.bumps.processed_claim)?;
+
+        //Only an active Processor can call this function
+        require!(processor.is_active == true, AuthorizationError::NotActiveProcessor);
+
+        //Only the Processor can call this function
+        require_keys_eq!(processor.submitter_address_of_claim_being_processed.key(), claim.submitter_address.key(), AuthorizationError::NotTheProcessor);
+
+        //Call the helper function to count this claim against the processor's daily limit
+        record_daily_processing(processor, ctx.accounts.m4a_protocol.max_daily_processing)?;
+
+        //Can't deny claim if patient record wasn't created
+        require!(claim.is_patient_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't deny claim if hospital record wasn't created
+        require!(claim.is_hospital_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+
+        //Can't deny claim if insurance company record wasn't created
+        require!(claim.is_insurance_company_record_created == true, InvalidOperationError::RecordAlreadyCreated);
+
+        //Call the helper function to fill in the denial reason from its template when only a reason code is given
+        let denial_reason = get_denial_reason(denial_reason, denial_reason_code, &ctx.accounts.denial_reason_template)?;
+
+        //Denial note string must not be longer than 140 characters
+        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let claim_queue = &mut ctx.accounts.claim_queue; 
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        processor_stats.denied_claim_count += 1;
+        processor_stats.denied_claim_count_by_type[claim.hospital_type as usize] += 1;
+        processor_stats.processed_claim_count += 1;
+        leave_queue(claim_queue)?;
+        submitter.denied_claim_count += 1;
+        patient.denied_claim_count += 1;
+        state.denied_claim_count += 1;
+        hospital.denied_claim_count += 1;
+        insurance_company.denied_claim_count += 1;
+
+        let processed_claim = &mut processed_claim;
+        processed_claim.processed_claim_id = processor_stats.processed_claim_count;
+        processed_claim.claim_id = claim.id;
+        processed_claim.processor_count_index = processor.processed_claim_count;
+        processed_claim.status = Status::Denied as u8;
+        processed_claim.denial_reason = denial_reason.clone();
+        processed_claim.is_patient_record_created = true;
+        processed_claim.is_hospital_record_created = true;
+        processed_claim.is_insurance_company_record_created = true;
+        processed_claim.patient_record_index = claim.patient_record_index;
+        processed_claim.hospital_record_index = claim.hospital_record_index;
+        processed_claim.insurance_company_record_index = claim.insurance_company_record_index;
+        processed_claim.processor_address = ctx.accounts.signer.key();
+        processed_claim.record_creator_address = ctx.accounts.patient_record.processor_address;
+        processed_claim.resolving_processor_address = ctx.accounts.signer.key();
+        processed_claim.submitter_address = claim.submitter_address;
+        processed_claim.patient_index = claim.patient_index;
+        processed_claim.country_index = claim.country_index;
+        processed_claim.state_index = claim.state_index;
+        processed_claim.hospital_index = claim.hospital_index;
+        processed_claim.hospital_type = claim.hospital_type;
+        processed_claim.hospital_name = claim.hospital_name.clone();
+        processed_claim.hospital_address = claim.hospital_address.clone();
+        processed_claim.hospital_city = claim.hospital_city.clone();
+        processed_claim.hospital_zip_code = claim.hospital_zip_code;
+        processed_claim.hospital_phone_number = claim.hospital_phone_number.clone();
+        processed_claim.hospital_bill_invoice_number = claim.hospital_bill_invoice_number.clone();
+        processed_claim.note = claim.note.clone();
+        processed_claim.processor_notes = claim.processor_notes.clone();
+        processed_claim.processor_note_edit_count = claim.processor_note_edit_count;
+        processed_claim.claim_amount = claim.claim_amount;
+        processed_claim.copay_amount = claim.copay_amount;
+        processed_claim.deductible_amount = claim.deductible_amount;
+        processed_claim.currency_code = claim.currency_code;
+        processed_claim.fee_mint = claim.fee_mint;
+        processed_claim.fee_amount_paid = claim.fee_amount_paid;
+        processed_claim.ailment = claim.ailment.clone();
+        processed_claim.diagnosis_codes = claim.diagnosis_codes.clone();
+        processed_claim.is_flagged_for_review = claim.is_flagged_for_review;
+        processed_claim.flag_reason = claim.flag_reason.clone();
+        processed_claim.insurance_company_index = claim.insurance_company_index;
+        processed_claim.insurance_company_name = claim.insurance_company_name.clone();
+        processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+        processed_claim.submitted_time = claim.submitted_time;
+        processed_claim.processed_time = time_stamp;
+        record_sla_breach(processed_claim, processor_stats, claim.sla_deadline);
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Denied as u8;
+        patient_record.processor_count_index = processor.processed_claim_count;
+        patient_record.denial_reason = denial_reason.clone();
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.status = Status::Denied as u8;
+        hospital_record.processor_count_index = processor.processed_claim_count;
+        hospital_record.processed_claim_id = processor_stats.processed_claim_count;
+        hospital_record.denial_reason = denial_reason.clone();
+        hospital_record.processed_time = time_stamp;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.status = Status::Denied as u8;
+        insurance_company_record.processor_count_index = processor.processed_claim_count;
+        insurance_company_record.denial_reason = denial_reason.clone();
+        insurance_company_record.processed_time = time_stamp;
+
+        processor.denied_claim_count += 1;
+        processor.processed_claim_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        update_processor_denial_rate(processor);
+        processor.is_processing_claim = false;
+        remove_claim_in_progress(processor, &mut ctx.accounts.processor_claim_index, claim.submitter_address);
+        
+        msg!("New Claim Denial");
+        msg!("Denied Claim Count: {}", processor_stats.denied_claim_count);
+        msg!("User Address: {}", claim.submitter_address);
+        msg!("Reason: {}", denial_reason.clone());
+        
+        #[cfg(feature = "debug-compute")]
+        sol_log_compute_units();
+
+        emit!(ClaimDenied
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        //The processed claim isn't an Anchor account, so it's written back by hand
+        store_processed_claim(&ctx.accounts.processed_claim, processed_claim)?;
+
+        Ok(())
+    } 
+
+    pub fn appeal_denied_claim_with_only_patient_record(ctx: Context<AppealDeniedClaimWithOnlyPatientRecord>,
+        _processor_address: Pubkey,
+        _processor_count_index: u64,
+        _token_mint_address: Pubkey,
+        appeal_reason: String) -> Result<()> 
+    {
+        let fee_token_entry = load_fee_token_entry(&ctx.accounts.fee_token_entry)?;
+        check_fee_token_not_expired(&fee_token_entry)?;
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        //Only the person who submitted the claim can appeal it
+        require_keys_eq!(ctx.accounts.signer.key(), processed_claim.submitter_address, AuthorizationError::NotSubmitter);
+
+        //An approved or settled claim was already resolved in the submitter's favor, e.g. undenied after a stale client loaded it
+        require!(processed_claim.status != Status::Approved as u8 &&
+        processed_claim.status != Status::Settled as u8, InvalidOperationError::ClaimAlreadyResolved);
+
+        //Only denied claims can be appealed
+        require!(processed_claim.status == Status::Denied as u8, InvalidOperationError::ClaimNotDenied);
+        
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_hospital_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_insurance_company_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Appeal note string must not be longer than 140 characters
+        require!(appeal_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        //A claim can only be appealed so many times
+        require!(processed_claim.appeal_count < ctx.accounts.m4a_protocol.max_appeals_per_claim, InvalidOperationError::TooManyAppeals);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let patient_record = &mut ctx.accounts.patient_record;
+        let state = &mut ctx.accounts.state;
+
+        processor_stats.submitted_appeal_count += 1;
+        submitter.submitted_appeal_count += 1;
+        patient.submitted_appeal_count += 1;
+        state.submitted_appeal_count += 1;
+        patient_record.status = Status::Appealed as u8;
+        patient_record.appeal_reason = appeal_reason.clone();
+        processed_claim.status = Status::Appealed as u8;
+        processed_claim.appeal_reason = appeal_reason.clone();
+        processed_claim.appeal_count += 1;
+        
+        msg!("New Appeal For Denied Claim With Only Patient Record");
+        msg!("Appeal Reason {}", appeal_reason);
+        msg!("Submitted Appeals Count {}", processor_stats.submitted_appeal_count);
+
+        let accounts = &ctx.accounts;
+        let treasurer = ctx.accounts.treasurer.clone();
+
+        //Both fee token accounts have to be for the same mint, in case crafted accounts get past the constraints
+        require_keys_eq!(accounts.user_fee_ata.mint, accounts.treasurer_fee_ata.mint, InvalidOperationError::FeeMintMismatch);
+
+        //Call the helper function to transfer the fee
+        apply_fee(
+            accounts.user_fee_ata.to_account_info(),
+            accounts.treasurer_fee_ata.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            treasurer,
+            accounts.fee_config.patient_only_appeal_fee_cents as f64/100.00,
+            fee_token_entry.decimal_amount,
+            accounts.fee_exemption.as_ref(),
+            accounts.fee_config.fees_enabled,
+            accounts.signer.key()
+        )?;
+
+        Ok(())
+    }
+
+    pub fn deny_appealed_claim_with_only_patient_record(ctx: Context<DenyAppealedClaimWithOnlyPatientRecord>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String, denial_reason_code: Option<u16>) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        //Can't deny appeal of a claim that isn't in an appealed state
+        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+
+        //Can't deny appeal of a claim that isn't in an appealed state
+        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_hospital_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_insurance_company_record_created == false, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Call the helper function to fill in the denial reason from its template when only a reason code is given
+        let denial_reason = get_denial_reason(denial_reason, denial_reason_code, &ctx.accounts.denial_reason_template)?;
+
+        //Denital note string must not be longer than 140 characters
+        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let patient_record = &mut ctx.accounts.patient_record;
+        
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        processor_stats.denied_appeal_count += 1;
+        submitter.denied_appeal_count += 1;
+        patient.denied_appeal_count += 1;
+        processor.denied_appeal_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        state.denied_appeal_count += 1;
+        patient_record.status = Status::Denied as u8;
+        patient_record.denial_reason = denial_reason.clone();
+        patient_record.processed_time = time_stamp;
+        processed_claim.status = Status::Denied as u8;
+        processed_claim.denial_reason = denial_reason.clone();
+        processed_claim.processed_time = time_stamp;
+        
+        msg!("An Appeal With Only A Patient Record Has Been Denied");
+        msg!("Denital Reason {}", denial_reason);
+        msg!("Submitted Appeals Count {}", processor_stats.denied_appeal_count);
+
+        emit!(ClaimDenied
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        Ok(())
+    }
+
+    pub fn appeal_denied_claim_with_all_records(ctx: Context<AppealDeniedClaimWithAllRecords>,
+        _processor_address: Pubkey,
+        _processor_count_index: u64,
+        _token_mint_address: Pubkey,
+        appeal_reason: String) -> Result<()> 
+    {
+        let fee_token_entry = load_fee_token_entry(&ctx.accounts.fee_token_entry)?;
+        check_fee_token_not_expired(&fee_token_entry)?;
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        //Only the person who submitted the claim can appeal it
+        require_keys_eq!(ctx.accounts.signer.key(), processed_claim.submitter_address, AuthorizationError::NotSubmitter);
+
+        //An approved or settled claim was already resolved in the submitter's favor, e.g. undenied after a stale client loaded it
+        require!(processed_claim.status != Status::Approved as u8 &&
+        processed_claim.status != Status::Settled as u8, InvalidOperationError::ClaimAlreadyResolved);
+
+        //Only denied claims can be appealed
+        require!(processed_claim.status == Status::Denied as u8, InvalidOperationError::ClaimNotDenied);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_hospital_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_insurance_company_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Appeal note string must not be longer than 140 characters
+        require!(appeal_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        //A claim can only be appealed so many times
+        require!(processed_claim.appeal_count < ctx.accounts.m4a_protocol.max_appeals_per_claim, InvalidOperationError::TooManyAppeals);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let state = &mut ctx.accounts.state;
+        let patient = &mut ctx.accounts.patient;
+        let patient_record = &mut ctx.accounts.patient_record;
+        let hospital = &mut ctx.accounts.hospital;
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        
+        processor_stats.submitted_appeal_count += 1;
+        state.submitted_appeal_count += 1;
+        processed_claim.status = Status::Appealed as u8;
+        processed_claim.appeal_reason = appeal_reason.clone();
+        processed_claim.appeal_count += 1;
+        patient.submitted_appeal_count += 1;
+        patient_record.status = Status::Appealed as u8;
+        patient_record.appeal_reason = appeal_reason.clone();
+        hospital.submitted_appeal_count += 1;
+        hospital_record.status = Status::Appealed as u8;
+        hospital_record.appeal_reason = appeal_reason.clone();
+        insurance_company.submitted_appeal_count += 1;
+        insurance_company_record.status = Status::Appealed as u8;
+        insurance_company_record.appeal_reason = appeal_reason.clone();
+        
+        msg!("New Appeal For Denied Claim With All Records");
+        msg!("Appeal Reason {}", appeal_reason);
+        msg!("Submitted Appeals Count {}", processor_stats.submitted_appeal_count);
+
+        let accounts = &ctx.accounts;
+        let treasurer = ctx.accounts.treasurer.clone();
+
+        //Both fee token accounts have to be for the same mint, in case crafted accounts get past the constraints
+        require_keys_eq!(accounts.user_fee_ata.mint, accounts.treasurer_fee_ata.mint, InvalidOperationError::FeeMintMismatch);
+
+        //Call the helper function to transfer the fee
+        apply_fee(
+            accounts.user_fee_ata.to_account_info(),
+            accounts.treasurer_fee_ata.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            treasurer,
+            accounts.fee_config.full_appeal_fee_cents as f64/100.00,
+            fee_token_entry.decimal_amount,
+            accounts.fee_exemption.as_ref(),
+            accounts.fee_config.fees_enabled,
+            accounts.signer.key()
+        )?;
+
+        Ok(())
+    }
+
+    pub fn deny_appealed_claim_with_all_records(ctx: Context<DenyAppealedClaimWithAllRecords>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String, denial_reason_code: Option<u16>) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Can't deny appeal of a claim that isn't in an appealed state
+        require!(processed_claim.status == Status::Appealed as u8, InvalidOperationError::ClaimNotAppealed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_patient_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_hospital_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Prevent Rat Fuckery
+        require!(processed_claim.is_insurance_company_record_created == true, InvalidOperationError::NoRatFuckeryAllowed);
+
+        //Call the helper function to fill in the denial reason from its template when only a reason code is given
+        let denial_reason = get_denial_reason(denial_reason, denial_reason_code, &ctx.accounts.denial_reason_template)?;
+
+        //Denial note string must not be longer than 140 characters
+        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let patient_record = &mut ctx.accounts.patient_record;
+        let hospital = &mut ctx.accounts.hospital;
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        
+        processor_stats.denied_appeal_count += 1;
+        processor.denied_appeal_count += 1;
+        submitter.denied_appeal_count += 1;
+        patient.denied_appeal_count += 1;
+        processor.denied_appeal_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        state.denied_appeal_count += 1;
+        patient_record.status = Status::Denied as u8;
+        patient_record.denial_reason = denial_reason.clone();
+        patient_record.processed_time = time_stamp;
+        hospital.denied_appeal_count += 1;
+        hospital_record.status = Status::Denied as u8;
+        hospital_record.denial_reason = denial_reason.clone();
+        hospital_record.processed_time = time_stamp;
+        insurance_company.denied_appeal_count += 1;
+        insurance_company_record.status = Status::Denied as u8;
+        insurance_company_record.denial_reason = denial_reason.clone();
+        insurance_company_record.processed_time = time_stamp;
+        processed_claim.status = Status::Denied as u8;
+        processed_claim.denial_reason = denial_reason.clone();
+        processed_claim.processed_time = time_stamp;
+        
+        msg!("An Appeal With Only All Records Has Been Denied");
+        msg!("Denital Reason {}", denial_reason);
+        msg!("Submitted Appeals Count {}", processor_stats.denied_appeal_count);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::DenyAppealedClaimWithAllRecords, ctx.accounts.signer.key())?;
+
+        emit!(ClaimDenied
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        Ok(())
+    }
+
+    pub fn undeny_claim_and_create_hospital_and_insurance_company_records(ctx: Context<UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Only denied or appealed claims can be undenied
+        require!((processed_claim.status == Status::Denied as u8) || (processed_claim.status == Status::Appealed as u8), InvalidOperationError::ClaimNotDeniedOrAppealed);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        processor_stats.approved_claim_amount += processed_claim.claim_amount;
+        processor_stats.undenied_claim_count += 1;
+        processor_stats.approved_claim_count += 1;
+        processor_stats.denied_claim_count -= 1;
+        processor_stats.approved_claim_count_by_type[processed_claim.hospital_type as usize] += 1;
+        processor_stats.approved_amount_buckets[get_approved_amount_bucket(processed_claim.claim_amount)] += 1;
+        processor_stats.denied_claim_count_by_type[processed_claim.hospital_type as usize] -= 1;
+        processor_stats.created_hospital_and_insurance_company_records_count += 1;
+        submitter.undenied_claim_count += 1;
+        submitter.approved_claim_count += 1;
+        submitter.denied_claim_count -= 1;
+        submitter.approved_claim_amount += processed_claim.claim_amount;
+        patient.undenied_claim_count += 1;
+        patient.approved_claim_count += 1;
+        patient.denied_claim_count -= 1;
+        patient.approved_claim_amount += processed_claim.claim_amount;
+        processor.undenied_claim_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        processor.approved_claim_amount += processed_claim.claim_amount;
+        state.undenied_claim_count += 1;
+        state.approved_claim_count += 1;
+        state.denied_claim_count -= 1;
+        state.approved_claim_amount += processed_claim.claim_amount;
+        hospital.undenied_claim_count += 1;
+        hospital.approved_claim_count += 1;
+        hospital.approved_claim_amount += processed_claim.claim_amount;
+        insurance_company.undenied_claim_count += 1;
+        insurance_company.approved_claim_count += 1;
+        insurance_company.approved_claim_amount += processed_claim.claim_amount;
+
+        processed_claim.status = Status::Approved as u8;
+        processed_claim.insurance_covered_amount = get_insurance_covered_amount(processed_claim.claim_amount, processed_claim.copay_amount, processed_claim.deductible_amount);
+        processed_claim.hospital_record_index = hospital.record_count;
+        processed_claim.insurance_company_record_index = insurance_company.record_count;
+        processed_claim.is_hospital_record_created = true;
+        processed_claim.is_insurance_company_record_created = true;
+        processed_claim.processed_time = time_stamp;
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Approved as u8;
+        patient_record.patient_record_only = false;
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital.record_count = hospital.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        hospital_record.record_id = hospital.record_count;
+        hospital_record.claim_id = processed_claim.claim_id;
+        hospital_record.status = Status::Approved as u8;
+        hospital_record.submitter_address = processed_claim.submitter_address;
+        hospital_record.patient_index = processed_claim.patient_index;
+        hospital_record.processor_address = ctx.accounts.signer.key();
+        hospital_record.insurance_company_index = processed_claim.insurance_company_index as u16;
+        hospital_record.hospital_bill_invoice_number = processed_claim.hospital_bill_invoice_number.clone();
+        hospital_record.claim_amount = processed_claim.claim_amount;
+        hospital_record.copay_amount = processed_claim.copay_amount;
+        hospital_record.deductible_amount = processed_claim.deductible_amount;
+        hospital_record.ailment = processed_claim.ailment.clone();
+        hospital_record.diagnosis_codes = processed_claim.diagnosis_codes.clone();
+        hospital_record.note = processed_claim.note.clone();
+        hospital_record.submitted_time = processed_claim.submitted_time;
+        hospital_record.processed_time = time_stamp;
+        hospital_record.processed_claim_id = processed_claim.processed_claim_id;
+        
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company.record_count = insurance_company.record_count.checked_add(1).ok_or(InvalidOperationError::RecordCountOverflow)?;
+        insurance_company_record.record_id = insurance_company.record_count;
+        insurance_company_record.claim_id = processed_claim.claim_id;
+        insurance_company_record.status = Status::Approved as u8;
+        insurance_company_record.submitter_address = processed_claim.submitter_address;
+        insurance_company_record.patient_index = processed_claim.patient_index;
+        insurance_company_record.processor_address = ctx.accounts.signer.key();
+        insurance_company_record.country_index = processed_claim.country_index;
+        insurance_company_record.state_index = processed_claim.state_index;
+        insurance_company_record.hospital_index = processed_claim.hospital_index as u32;
+        insurance_company_record.hospital_bill_invoice_number = processed_claim.hospital_bill_invoice_number.clone();
+        insurance_company_record.claim_amount = processed_claim.claim_amount;
+        insurance_company_record.copay_amount = processed_claim.copay_amount;
+        insurance_company_record.deductible_amount = processed_claim.deductible_amount;
+        insurance_company_record.ailment = processed_claim.ailment.clone();
+        insurance_company_record.diagnosis_codes = processed_claim.diagnosis_codes.clone();
+        insurance_company_record.note = processed_claim.note.clone();
+        insurance_company_record.submitted_time = processed_claim.submitted_time;
+        insurance_company_record.processed_time = time_stamp;
+
+        msg!("New Undenied Claim");
+        msg!("New Hospital Record Created");
+        msg!("New Insurance Company Record Created");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords, ctx.accounts.signer.key())?;
+
+        emit!(ClaimApproved
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        Ok(())
+    }
+
+    pub fn undeny_claim_with_all_records(ctx: Context<UndenyClaimWithAllRecords>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Only denied or appealed claims can be undenied
+        require!((processed_claim.status == Status::Denied as u8) || (processed_claim.status == Status::Appealed as u8), InvalidOperationError::ClaimNotDeniedOrAppealed);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        
+        processor_stats.approved_claim_amount += processed_claim.claim_amount;
+        processor_stats.undenied_claim_count += 1;
+        processor_stats.approved_claim_count += 1;
+        processor_stats.denied_claim_count -= 1;
+        processor_stats.approved_claim_count_by_type[processed_claim.hospital_type as usize] += 1;
+        processor_stats.approved_amount_buckets[get_approved_amount_bucket(processed_claim.claim_amount)] += 1;
+        processor_stats.denied_claim_count_by_type[processed_claim.hospital_type as usize] -= 1;
+        submitter.undenied_claim_count += 1;
+        submitter.approved_claim_count += 1;
+        submitter.denied_claim_count -= 1;
+        submitter.approved_claim_amount += processed_claim.claim_amount;
+        patient.undenied_claim_count += 1;
+        patient.approved_claim_count += 1;
+        patient.denied_claim_count -= 1;
+        patient.approved_claim_amount += processed_claim.claim_amount;
+        processor.undenied_claim_count += 1;
+        processor.last_processed_time = Clock::get()?.unix_timestamp as u64;
+        processor.approved_claim_amount += processed_claim.claim_amount;
+        state.undenied_claim_count += 1;
+        state.approved_claim_count += 1;
+        state.denied_claim_count -= 1;
+        state.approved_claim_amount += processed_claim.claim_amount;
+        hospital.undenied_claim_count += 1;
+        hospital.approved_claim_count += 1;
+        hospital.denied_claim_count -= 1;
+        hospital.approved_claim_amount += processed_claim.claim_amount;
+        insurance_company.undenied_claim_count += 1;
+        insurance_company.approved_claim_count += 1;
+        insurance_company.denied_claim_count -= 1;
+        insurance_company.approved_claim_amount += processed_claim.claim_amount;
+
+        processed_claim.status = Status::Approved as u8;
+        processed_claim.insurance_covered_amount = get_insurance_covered_amount(processed_claim.claim_amount, processed_claim.copay_amount, processed_claim.deductible_amount);
+        processed_claim.processed_time = time_stamp;
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Approved as u8;
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.status = Status::Approved as u8;
+        hospital_record.processed_time = time_stamp;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.status = Status::Approved as u8;
+        insurance_company_record.processed_time = time_stamp;
+        
+        msg!("New Undenied Claim");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::UndenyClaimWithAllRecords, ctx.accounts.signer.key())?;
+
+        emit!(ClaimApproved
+        {
+            claim_id: processed_claim.claim_id,
+            submitter_address: processed_claim.submitter_address,
+            processor_address: processed_claim.processor_address,
+            processed_claim_id: processed_claim.processed_claim_id,
+            processor_count_index: processed_claim.processor_count_index
+        });
+
+        Ok(())
+    }
+
+    pub fn edit_processed_claim_and_patient_record(ctx: Context<EditProcessedClaimAndPatientRecord>, 
+        _processor_address: Pubkey,
+        _processor_count_index: u64,
+        hospital_index: u32,
+        insurance_company_index: u16,
+        hospital_bill_invoice_number: String,
+        claim_note: String,
+        claim_amount: u64,
+        copay_amount: u64,
+        deductible_amount: u64,
+        ailment: String) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Copay and deductible are part of the claim amount, so together they can't be more than it
+        require!(copay_amount as u128 + deductible_amount as u128 <= claim_amount as u128, InvalidOperationError::CopayAndDeductibleExceedClaimAmount);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let processed_claim = &mut ctx.accounts.processed_claim;
+        let patient = &mut ctx.accounts.patient;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        //An edit count is kept to help stream line the table listeners on the front end
+        patient.edited_record_count += 1;
+        processor_stats.edited_claim_or_processed_claim_count += 1;
+
+        //Update Processed Claim
+        processed_claim.hospital_index = hospital_index as i32;
+        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        processed_claim.note = claim_note.clone();
+        processed_claim.claim_amount = claim_amount;
+        processed_claim.copay_amount = copay_amount;
+        processed_claim.deductible_amount = deductible_amount;
+
+        //Only approved claims have an insurer covered amount to keep in step with the edit
+        if processed_claim.status == Status::Approved as u8
+        {
+            processed_claim.insurance_covered_amount = get_insurance_covered_amount(claim_amount, copay_amount, deductible_amount);
+        }
+
+        processed_claim.ailment = ailment.clone();
+        processed_claim.insurance_company_index = insurance_company_index as i16;
+        processed_claim.hospital_name = hospital.hospital_name.clone();
+        processed_claim.hospital_address = hospital.hospital_address.clone();
+        processed_claim.hospital_city = hospital.hospital_city.clone();
+        processed_claim.hospital_zip_code = hospital.hospital_zip_code;
+        processed_claim.hospital_phone_number = hospital.hospital_phone_number.clone();
+        processed_claim.insurance_company_name = insurance_company.insurance_company_name.clone();
+        processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+        processed_claim.processed_time = time_stamp;
+
+        //Update Records
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.hospital_index = hospital_index;
+        patient_record.insurance_company_index = insurance_company_index;
+        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        patient_record.claim_amount = claim_amount;
+        patient_record.copay_amount = copay_amount;
+        patient_record.deductible_amount = deductible_amount;
+        patient_record.ailment = ailment.clone();
+        patient_record.note = claim_note.clone();
+        patient_record.processed_time = time_stamp;
+        
+        msg!("Processed Claim And Patient Record Updated");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::EditProcessedClaimAndPatientRecord, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn edit_processed_claim_and_all_records(ctx: Context<EditProcessedClaimAndAllRecords>, 
+        _processor_address: Pubkey,
+        _processor_count_index: u64,
+        hospital_bill_invoice_number: String,
+        claim_note: String,
+        claim_amount: u64,
+        copay_amount: u64,
+        deductible_amount: u64,
+        ailment: String) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Copay and deductible are part of the claim amount, so together they can't be more than it
+        require!(copay_amount as u128 + deductible_amount as u128 <= claim_amount as u128, InvalidOperationError::CopayAndDeductibleExceedClaimAmount);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let processed_claim = &mut ctx.accounts.processed_claim;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        //An edit count is kept to help stream line the table listeners on the front end
+        patient.edited_record_count += 1;
+        hospital.edited_record_count += 1;
+        insurance_company.edited_record_count += 1;
+        processor_stats.edited_claim_or_processed_claim_count += 1;
+
+        //Update Previous Amounts If Amounts Were Already Approved
+        if processed_claim.status == Status::Approved as u8
+        {
+            processor_stats.approved_claim_amount -= processed_claim.claim_amount;
+            processor_stats.approved_claim_amount += claim_amount;
+            processor_stats.approved_amount_buckets[get_approved_amount_bucket(processed_claim.claim_amount)] -= 1;
+            processor_stats.approved_amount_buckets[get_approved_amount_bucket(claim_amount)] += 1;
+            submitter.approved_claim_amount -= processed_claim.claim_amount;
+            submitter.approved_claim_amount += claim_amount;
+            patient.approved_claim_amount -= processed_claim.claim_amount;
+            patient.approved_claim_amount += claim_amount;
+            processor.approved_claim_amount -= processed_claim.claim_amount;
+            processor.approved_claim_amount += claim_amount;
+            hospital.approved_claim_amount -= processed_claim.claim_amount;
+            hospital.approved_claim_amount += claim_amount;
+            state.approved_claim_amount -= processed_claim.claim_amount;
+            state.approved_claim_amount += claim_amount;
+            insurance_company.approved_claim_amount -= processed_claim.claim_amount;
+            insurance_company.approved_claim_amount += claim_amount;
+        }
+
+        //Update Processed Claim
+        processed_claim.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        processed_claim.note = claim_note.clone();
+        processed_claim.claim_amount = claim_amount;
+        processed_claim.copay_amount = copay_amount;
+        processed_claim.deductible_amount = deductible_amount;
+
+        //Only approved claims have an insurer covered amount to keep in step with the edit
+        if processed_claim.status == Status::Approved as u8
+        {
+            processed_claim.insurance_covered_amount = get_insurance_covered_amount(claim_amount, copay_amount, deductible_amount);
+        }
+
+        processed_claim.ailment = ailment.clone();
+        processed_claim.content_hash = get_processed_claim_content_hash(processed_claim);
+        processed_claim.processed_time = time_stamp;
+
+        //Update Records
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        patient_record.claim_amount = claim_amount;
+        patient_record.copay_amount = copay_amount;
+        patient_record.deductible_amount = deductible_amount;
+        patient_record.ailment = ailment.clone();
+        patient_record.note = claim_note.clone();
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        hospital_record.claim_amount = claim_amount;
+        hospital_record.copay_amount = copay_amount;
+        hospital_record.deductible_amount = deductible_amount;
+        hospital_record.ailment = ailment.clone();
+        hospital_record.note = claim_note.clone();
+        hospital_record.processed_time = time_stamp;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.hospital_bill_invoice_number = hospital_bill_invoice_number.clone();
+        insurance_company_record.claim_amount = claim_amount;
+        insurance_company_record.copay_amount = copay_amount;
+        insurance_company_record.deductible_amount = deductible_amount;
+        insurance_company_record.ailment = ailment;
+        insurance_company_record.note = claim_note;
+        insurance_company_record.processed_time = time_stamp;
+
+        msg!("Processed Claim And All Records Updated");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::EditProcessedClaimAndAllRecords, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn revoke_approval(ctx: Context<RevokeApproval>, _processor_address: Pubkey, _processor_count_index: u64, denial_reason: String) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Only approved claims can be revoked
+        require!(processed_claim.status == Status::Approved as u8, InvalidOperationError::ClaimNotApproved);
+
+        //Denial note string must not be longer than 140 characters
+        require!(denial_reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+        
+        processor_stats.approved_claim_amount -= processed_claim.claim_amount;
+        processor_stats.revoked_approval_count += 1;
+        processor_stats.approved_claim_count -= 1;
+        processor_stats.denied_claim_count += 1;
+        processor_stats.approved_claim_count_by_type[processed_claim.hospital_type as usize] -= 1;
+        processor_stats.approved_amount_buckets[get_approved_amount_bucket(processed_claim.claim_amount)] -= 1;
+        processor_stats.denied_claim_count_by_type[processed_claim.hospital_type as usize] += 1;
+        submitter.revoked_approval_count += 1;
+        submitter.approved_claim_count -= 1;
+        submitter.denied_claim_count += 1;
+        submitter.approved_claim_amount -= processed_claim.claim_amount;
+        patient.revoked_approval_count += 1;
+        patient.approved_claim_count -= 1;
+        patient.denied_claim_count += 1;
+        patient.approved_claim_amount -= processed_claim.claim_amount;
+        processor.revoked_approval_count += 1;
+        processor.approved_claim_amount -= processed_claim.claim_amount;
+        state.revoked_approval_count += 1;
+        state.approved_claim_count -= 1;
+        state.denied_claim_count += 1;
+        state.approved_claim_amount -= processed_claim.claim_amount;
+        hospital.revoked_approval_count += 1;
+        hospital.approved_claim_count -= 1;
+        hospital.denied_claim_count += 1;
+        hospital.approved_claim_amount -= processed_claim.claim_amount;
+        insurance_company.revoked_approval_count += 1;
+        insurance_company.approved_claim_count -= 1;
+        insurance_company.denied_claim_count += 1;
+        insurance_company.approved_claim_amount -= processed_claim.claim_amount;
+
+        //A revoked approval is a new denial, so the submitter gets to appeal it with appeal_denied_claim_with_all_records
+        processed_claim.status = Status::Denied as u8;
+        processed_claim.insurance_covered_amount = 0;
+        processed_claim.denial_reason = denial_reason.clone();
+        processed_claim.appeal_count = 0;
+        processed_claim.processed_time = time_stamp;
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Denied as u8;
+        patient_record.denial_reason = denial_reason.clone();
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.status = Status::Denied as u8;
+        hospital_record.denial_reason = denial_reason.clone();
+        hospital_record.processed_time = time_stamp;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.status = Status::Denied as u8;
+        insurance_company_record.denial_reason = denial_reason.clone();
+        insurance_company_record.processed_time = time_stamp;
+        
+        msg!("New Revoked Approval");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::RevokeApproval, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn void_processed_claim(ctx: Context<VoidProcessedClaim>, _processor_address: Pubkey, _processor_count_index: u64, reason: String) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        //Only approved or denied claims that have all their records can be voided
+        require!((processed_claim.status == Status::Approved as u8 || processed_claim.status == Status::Denied as u8) &&
+        processed_claim.is_hospital_record_created == true &&
+        processed_claim.is_insurance_company_record_created == true, InvalidOperationError::ClaimCannotBeVoided);
+
+        //Void reason string must not be longer than 140 characters
+        require!(reason.len() <= MAX_NOTE_LENGTH, InvalidLengthError::NoteTooLong);
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let submitter = &mut ctx.accounts.submitter;
+        let patient = &mut ctx.accounts.patient;
+        let processor = &mut ctx.accounts.processor;
+        let state = &mut ctx.accounts.state;
+        let hospital = &mut ctx.accounts.hospital;
+        let insurance_company = &mut ctx.accounts.insurance_company;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        //Take back everything the claim added to the stats when it was processed
+        if processed_claim.status == Status::Approved as u8
+        {
+            processor_stats.approved_claim_amount -= processed_claim.claim_amount;
+            processor_stats.approved_claim_count -= 1;
+            processor_stats.approved_claim_count_by_type[processed_claim.hospital_type as usize] -= 1;
+            processor_stats.approved_amount_buckets[get_approved_amount_bucket(processed_claim.claim_amount)] -= 1;
+            submitter.approved_claim_count -= 1;
+            submitter.approved_claim_amount -= processed_claim.claim_amount;
+            patient.approved_claim_count -= 1;
+            patient.approved_claim_amount -= processed_claim.claim_amount;
+            processor.approved_claim_count -= 1;
+            processor.approved_claim_amount -= processed_claim.claim_amount;
+            state.approved_claim_count -= 1;
+            state.approved_claim_amount -= processed_claim.claim_amount;
+            hospital.approved_claim_count -= 1;
+            hospital.approved_claim_amount -= processed_claim.claim_amount;
+            insurance_company.approved_claim_count -= 1;
+            insurance_company.approved_claim_amount -= processed_claim.claim_amount;
+        }
+        else
+        {
+            processor_stats.denied_claim_count -= 1;
+            processor_stats.denied_claim_count_by_type[processed_claim.hospital_type as usize] -= 1;
+            submitter.denied_claim_count -= 1;
+            patient.denied_claim_count -= 1;
+            processor.denied_claim_count -= 1;
+            state.denied_claim_count -= 1;
+            hospital.denied_claim_count -= 1;
+            insurance_company.denied_claim_count -= 1;
+            update_processor_denial_rate(processor);
+        }
+
+        processed_claim.status = Status::Voided as u8;
+        processed_claim.insurance_covered_amount = 0;
+        processed_claim.void_reason = reason;
+        processed_claim.processed_time = time_stamp;
+
+        let patient_record = &mut ctx.accounts.patient_record;
+        patient_record.status = Status::Voided as u8;
+        patient_record.processed_time = time_stamp;
+
+        let hospital_record = &mut ctx.accounts.hospital_record;
+        hospital_record.status = Status::Voided as u8;
+        hospital_record.processed_time = time_stamp;
+
+        let insurance_company_record = &mut ctx.accounts.insurance_company_record;
+        insurance_company_record.status = Status::Voided as u8;
+        insurance_company_record.processed_time = time_stamp;
+        
+        msg!("Processed Claim Voided");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        msg!("Void Reason: {}", processed_claim.void_reason);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::VoidProcessedClaim, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    //Audit and repair tool, every record the hospital has must be passed in so it only fits hospitals with a transaction's worth of records
+    pub fn reconcile_hospital_stats<'info>(ctx: Context<'_, '_, 'info, 'info, ReconcileHospitalStats<'info>>, country_index: u16, state_index: u32, hospital_index: u32) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        let hospital = &mut ctx.accounts.hospital;
+
+        //Every record the hospital has must be passed in, in record index order
+        require!(ctx.remaining_accounts.len() as u64 == hospital.record_count, InvalidOperationError::InvalidBatchSize);
+
+        let mut approved_claim_count: u64 = 0;
+        let mut approved_claim_amount: u64 = 0;
+        let mut denied_claim_count: u64 = 0;
+
+        for (record_index, record_account) in ctx.remaining_accounts.iter().enumerate()
+        {
+            require_batch_pda(record_account, &[b"hospitalRecord".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), hospital_index.to_le_bytes().as_ref(), (record_index as u64).to_le_bytes().as_ref()])?;
+
+            let hospital_record = Account::<HospitalRecord>::try_from(record_account)?;
+
+            //Appealed records were denied first and still count as denied until they're undenied
+            if hospital_record.status == Status::Approved as u8
+            {
+                approved_claim_count += 1;
+                approved_claim_amount += hospital_record.claim_amount;
+            }
+            else if hospital_record.status == Status::Denied as u8 || hospital_record.status == Status::Appealed as u8
+            {
+                denied_claim_count += 1;
+            }
+        }
+
+        msg!("Hospital Stats Reconciled");
+        msg!("Approved Claim Count: {} -> {}", hospital.approved_claim_count, approved_claim_count);
+        msg!("Approved Claim Amount: ${:.2} -> ${:.2}", hospital.approved_claim_amount as f64/100.00, approved_claim_amount as f64/100.00);
+        msg!("Denied Claim Count: {} -> {}", hospital.denied_claim_count, denied_claim_count);
+
+        hospital.approved_claim_count = approved_claim_count;
+        hospital.approved_claim_amount = approved_claim_amount;
+        hospital.denied_claim_count = denied_claim_count;
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::ReconcileHospitalStats, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn deactivate_state_hospitals<'info>(ctx: Context<'_, '_, 'info, 'info, DeactivateStateHospitals<'info>>, country_index: u16, state_index: u32) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        let hospital_stats = &mut ctx.accounts.hospital_stats;
+        let state = &mut ctx.accounts.state;
+
+        //Every hospital in the state must be passed in, in hospital index order
+        require!(ctx.remaining_accounts.len() as u32 == state.hospital_count, InvalidOperationError::InvalidBatchSize);
+
+        let mut deactivated_hospital_count: u32 = 0;
+
+        for (hospital_index, hospital_account) in ctx.remaining_accounts.iter().enumerate()
+        {
+            require_batch_pda(hospital_account, &[b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), (hospital_index as u32).to_le_bytes().as_ref()])?;
+
+            let mut hospital = Box::new(Account::<Hospital>::try_from(hospital_account)?);
+
+            //Hospitals that are already inactive were taken out of the type counts when they were deactivated
+            if hospital.is_active == false
+            {
+                continue;
+            }
+
+            hospital.is_active = false;
+
+            //Call the helper function to take the hospital out of its type count
+            deduct_hospital_type_count(hospital_stats, state, hospital.hospital_type);
+
+            hospital_stats.edited_hospital_count += 1;
+            state.edited_hospital_count += 1;
+            deactivated_hospital_count += 1;
+
+            hospital.exit(&ID)?;
+        }
+
+        msg!("State Hospitals Deactivated");
+        msg!("Country Index: {}", country_index);
+        msg!("State Index: {}", state_index);
+        msg!("Deactivated Hospital Count: {}", deactivated_hospital_count);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::DeactivateStateHospitals, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn take_stats_snapshot(ctx: Context<TakeStatsSnapshot>) -> Result<()> 
+    {
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let stats_snapshot = &mut ctx.accounts.stats_snapshot;
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        //Anyone can take a snapshot once the interval has passed, the CEO can take one at any time
+        require!(ctx.accounts.signer.key() == ctx.accounts.ceo.address.key() ||
+        processor_stats.stats_snapshot_count == 0 ||
+        now >= processor_stats.last_stats_snapshot_time + STATS_SNAPSHOT_INTERVAL_SECONDS, InvalidOperationError::StatsSnapshotTooSoon);
+
+        stats_snapshot.epoch = processor_stats.stats_snapshot_count;
+        stats_snapshot.timestamp = now;
+        stats_snapshot.taken_by = ctx.accounts.signer.key();
+        stats_snapshot.processor_account_total = processor_stats.processor_account_total;
+        stats_snapshot.processor_active_account_total = processor_stats.processor_active_account_total;
+        stats_snapshot.processed_claim_count = processor_stats.processed_claim_count;
+        stats_snapshot.approved_claim_amount = processor_stats.approved_claim_amount;
+        stats_snapshot.approved_claim_count = processor_stats.approved_claim_count;
+        stats_snapshot.denied_claim_count = processor_stats.denied_claim_count;
+        stats_snapshot.max_denied_claim_count = processor_stats.max_denied_claim_count;
+        stats_snapshot.submitted_appeal_count = processor_stats.submitted_appeal_count;
+        stats_snapshot.revoked_approval_count = processor_stats.revoked_approval_count;
+        stats_snapshot.flagged_claim_count = processor_stats.flagged_claim_count;
+
+        processor_stats.stats_snapshot_count += 1;
+        processor_stats.last_stats_snapshot_time = now;
+
+        msg!("Stats Snapshot Taken");
+        msg!("Epoch: {}", stats_snapshot.epoch);
+        msg!("Taken By: {}", stats_snapshot.taken_by);
+
+        Ok(())
+    }
+
+    pub fn mark_claim_settled(ctx: Context<MarkClaimSettled>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    {
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        //Only the submitter or the Treasurer can call this function
+        require!(ctx.accounts.signer.key() == processed_claim.submitter_address.key() ||
+        ctx.accounts.signer.key() == ctx.accounts.treasurer.address.key(), AuthorizationError::NotSubmitterOrTreasurer);
+
+        //Only an approved claim gets paid out, so only it can be settled
+        require!(processed_claim.status == Status::Approved as u8, InvalidOperationError::ClaimNotApproved);
+
+        processed_claim.status = Status::Settled as u8;
+        processed_claim.settled_time = Clock::get()?.unix_timestamp as u64;
+
+        let submitter = &mut ctx.accounts.submitter;
+        submitter.settled_claim_count += 1;
+
+        msg!("Processed Claim Settled");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+        msg!("Settled By: {}", ctx.accounts.signer.key());
+
+        Ok(())
+    }
+
+    pub fn archive_processed_claim(ctx: Context<ArchiveProcessedClaim>, _processor_address: Pubkey, _processor_count_index: u64) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+
+        let processed_claim = &mut ctx.accounts.processed_claim;
+
+        //Archiving only happens once, there's nothing left to clear the second time
+        require!(processed_claim.is_archived == false, InvalidOperationError::ProcessedClaimAlreadyArchived);
+
+        //Free text fields are blanked, IDs, indexes, amounts, status, and timestamps stay as the tombstone
+        processed_claim.is_archived = true;
+        processed_claim.hospital_name = String::new();
+        processed_claim.hospital_address = String::new();
+        processed_claim.hospital_city = String::new();
+        processed_claim.hospital_bill_invoice_number = String::new();
+        processed_claim.note = String::new();
+        processed_claim.processor_notes = String::new();
+        processed_claim.ailment = String::new();
+        processed_claim.diagnosis_codes = Vec::new();
+        processed_claim.flag_reason = String::new();
+        processed_claim.denial_reason = String::new();
+        processed_claim.appeal_reason = String::new();
+
+        msg!("Processed Claim Archived");
+        msg!("Processed Claim Number: {}", processed_claim.processed_claim_id);
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::ArchiveProcessedClaim, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+
+    pub fn drop_denial_hammer(ctx: Context<DropDenialHammer>) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        let processor = &ctx.accounts.processor;
+
+        //Only an Admin or the CEO can call this function
+        require!(ctx.accounts.signer.key() == ceo.address.key() ||
+        processor.is_super_admin == true, AuthorizationError::NotSuperAdminOrCEO);
+
+        //Keeps the closed claim id list in the event bounded
+        require!(ctx.remaining_accounts.len() <= MAX_DENIAL_HAMMER_CLAIM_COUNT, InvalidOperationError::InvalidBatchSize);
+
+        let mut closed_claim_ids: Vec<u64> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+        for claim_account in ctx.remaining_accounts.iter()
+        {
+            //Read the claim id before the account gets wiped so the audit trail knows which claims were hammered
+            {
+                let claim_data = claim_account.try_borrow_data()?;
+                let claim = Claim::try_deserialize(&mut &claim_data[..])?;
+
+                //Only the submitter's own claim PDA can be hammered, not some other program owned account
+                require_batch_pda(claim_account, &[b"claim".as_ref(), claim.submitter_address.as_ref()])?;
+
+                //A held claim is still on its processor's in progress list, closing it here would leave that list pointing at nothing
+                require!(claim.status != Status::Assigned as u8 &&
+                claim.status != Status::Processing as u8, InvalidOperationError::ClaimHeldByProcessor);
+
+                closed_claim_ids.push(claim.id);
+            }
+
+            //Transfer tokens from the account to the sol_destination.
+            let dest_starting_lamports = ctx.accounts.signer.lamports();
+            **ctx.accounts.signer.lamports.borrow_mut() = 
+                dest_starting_lamports.checked_add(claim_account.lamports()).unwrap();
+            **claim_account.lamports.borrow_mut() = 0;
+            
+            claim_account.assign(&system_program::ID);
+            let _ = claim_account.realloc(0, false);
+        }
+
+        let processor_stats = &mut ctx.accounts.processor_stats;
+        let claim_queue = &mut ctx.accounts.claim_queue;
+        let processor = &mut ctx.accounts.processor;
+
+        processor_stats.denial_hammer_dropped_count += 1;
+
+        for _ in 0..ctx.remaining_accounts.len()
+        {
+            leave_queue(claim_queue)?;
+        }
+
+        processor.denial_hammer_dropped_count += 1;
+        
+        msg!("Denial Hammer Dropped");
+        msg!("Denial Hammer Use Count: {}", processor_stats.denial_hammer_dropped_count);
+        msg!("Number of Accounts Hammered: {}", ctx.remaining_accounts.len());
+
+        emit!(DenialHammerDropped
+        {
+            closed_claim_ids,
+            actor: ctx.accounts.signer.key()
+        });
+
+        log_admin_action(&mut ctx.accounts.admin_action_log, AdminAction::DropDenialHammer, ctx.accounts.signer.key())?;
+
+        Ok(())
+    }
+}
+
+//Derived Accounts
+#[derive(Accounts)]
+pub struct InitializeAdminAccounts<'info> 
+{
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump,
+        space = size_of::<M4AProtocolCEO>() + 8)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump,
+        space = size_of::<M4AProtocolTreasurer>() + 8)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        space = size_of::<AdminActionLog>() + 8)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct PassOnM4AProtocolCEO<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        mut,
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct PassOnM4AProtocolTreasurer<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetFeesEnabledFlag<'info> 
+{
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        mut, 
+        seeds = [b"feeConfig".as_ref()], 
+        bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct AddFeeTokenEntry<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump, 
+        space = size_of::<FeeTokenEntry>() + 8)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct AddFeeTokenEntriesBatch<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct UpdateFeeTokenDecimals<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        mut,
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct SetFeeTokenValidUntil<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        mut,
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct RemoveFeeTokenEntry<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey)]
+pub struct GrantFeeExemption<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"feeExemption".as_ref(), submitter_address.key().as_ref()], 
+        bump, 
+        space = size_of::<FeeExemption>() + 8)]
+    pub fee_exemption: Account<'info, FeeExemption>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(code: u16)]
+pub struct AddDenialReasonTemplate<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"denialReasonTemplate".as_ref(), code.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<DenialReasonTemplate>() + DENIAL_REASON_TEMPLATE_EXTRA_SIZE + 8)]
+    pub denial_reason_template: Account<'info, DenialReasonTemplate>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(code: u16)]
+pub struct UpdateDenialReasonTemplate<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"denialReasonTemplate".as_ref(), code.to_le_bytes().as_ref()], 
+        bump)]
+    pub denial_reason_template: Account<'info, DenialReasonTemplate>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey)]
+pub struct RevokeFeeExemption<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"feeExemption".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub fee_exemption: Account<'info, FeeExemption>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeeConfig<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"feeConfig".as_ref()], 
+        bump, 
+        space = size_of::<FeeConfig>() + 8)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetAppealFees<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"feeConfig".as_ref()], 
+        bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct InitializeFieldLimits<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"fieldLimits".as_ref()], 
+        bump, 
+        space = size_of::<FieldLimits>() + 8)]
+    pub field_limits: Account<'info, FieldLimits>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetFieldLimits<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"fieldLimits".as_ref()], 
+        bump)]
+    pub field_limits: Account<'info, FieldLimits>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey, patient_index: u8)]
+pub struct CorrectPatientRecordCount<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"patient".as_ref(), submitter_address.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Box<Account<'info, PatientAccount>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct SetPreferredFeeMint<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"feeConfig".as_ref()], 
+        bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    //Preferred fee mint must be a supported fee token
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct GetFeeConfig<'info> 
+{
+    #[account(
+        seeds = [b"feeConfig".as_ref()], 
+        bump)]
+    pub fee_config: Account<'info, FeeConfig>
+}
+
+#[derive(Accounts)]
+#[instruction(who: Pubkey)]
+pub struct GetRoles<'info> 
+{
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    /// CHECK: Loaded in the instruction since most keys won't have a processor account
+    #[account(
+        seeds = [b"processor".as_ref(), who.key().as_ref()],
+        bump)]
+    pub processor: UncheckedAccount<'info>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey, patient_index: u8, fee_mint: Pubkey)]
+pub struct CanSubmit<'info> 
+{
+    #[account(
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    /// CHECK: Only checked for existence since the submitter may not have created their account yet
+    #[account(
+        seeds = [b"submitter".as_ref(), submitter_address.key().as_ref()],
+        bump)]
+    pub submitter: UncheckedAccount<'info>,
+
+    /// CHECK: Only checked for existence since most submitters won't have an open claim
+    #[account(
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()],
+        bump)]
+    pub claim: UncheckedAccount<'info>,
+
+    /// CHECK: Loaded in the instruction since the submitter may not have created the patient yet
+    #[account(
+        seeds = [b"patient".as_ref(), submitter_address.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: UncheckedAccount<'info>,
+
+    /// CHECK: Loaded in the instruction so a fee mint without an entry is reported instead of failing
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(), fee_mint.key().as_ref()],
+        bump)]
+    pub fee_token_entry: UncheckedAccount<'info>
+}
+
+#[derive(Accounts)]
+pub struct OpenClaimRentEstimate<'info> 
+{
+    #[account(
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct GetProcessedClaimSummaries<'info> 
+{
+    #[account(
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Box<Account<'info, ProcessorAccount>>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct GetProcessorCurrentClaim<'info> 
+{
+    #[account(
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Box<Account<'info, ProcessorAccount>>
+}
+
+#[derive(Accounts)]
+#[instruction(patient_index: u8)]
+pub struct BuildPatientManifest<'info> 
+{
+    #[account(
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Box<Account<'info, PatientAccount>>,
+
+    pub signer: Signer<'info>
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolStats<'info>
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"processorStats".as_ref()],
+        bump,
+        space = size_of::<ProcessorStats>() + 8)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"hospitalStats".as_ref()],
+        bump,
+        space = size_of::<HospitalStats>() + 8)]
+    pub hospital_stats: Account<'info, HospitalStats>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"insuranceCompanyStats".as_ref()],
+        bump,
+        space = size_of::<InsuranceCompanyStats>() + 8)]
+    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct InitializeM4AProtocolAndClaimQueue<'info> 
+{
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"m4aProtocol".as_ref()],
+        bump,
+        space = size_of::<M4AProtocol>() + 8)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    //Stats account must exist to initialize protocol
+    #[account(
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"claimQueue".as_ref()],
+        bump,
+        space = size_of::<ClaimQueue>() + 8)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetClaimQueueFlag<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetMaxAppealsPerClaim<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetMaxUpwardEditBps<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetSlaSeconds<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetAssignmentCooldownSeconds<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetMaxDailyProcessing<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetMinClaimAmountCents<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetAutoFlagAmountCents<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetDualSignoffAmountCents<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetMaxReassignments<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetAccountCreationFlag<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct EditClaimQueueSize<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        mut, 
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SetQueueSizeBounds<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+    
+    #[account(
+        mut, 
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateSubmitterAccount<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump,
+        space = size_of::<SubmitterAccount>() + 8)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CloseSubmitterAccount<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreatePatientAccount<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
+    #[account(
+        mut,
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), submitter.patient_count.to_le_bytes().as_ref()],
+        bump,
+        space = size_of::<PatientAccount>() + PATIENT_EXTRA_SIZE + 8)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey, patient_index: u8)]
+pub struct SetLifetimeBenefitCap<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    //The CEO doesn't need a processor account to set the cap
+    #[account(
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Option<Box<Account<'info, ProcessorAccount>>>,
+
+    #[account(
+        mut, 
+        seeds = [b"patient".as_ref(), submitter_address.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Box<Account<'info, PatientAccount>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(old_submitter_address: Pubkey, old_patient_index: u8, record_index: u32, new_submitter_address: Pubkey, new_patient_index: u8)]
+pub struct MigratePatientRecord<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"patientRecord".as_ref(), old_submitter_address.key().as_ref(), old_patient_index.to_le_bytes().as_ref(), record_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub old_patient_record: Box<Account<'info, PatientRecord>>,
+
+    #[account(
+        mut, 
+        seeds = [b"patient".as_ref(), new_submitter_address.key().as_ref(), new_patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub new_patient: Account<'info, PatientAccount>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"patientRecord".as_ref(), new_submitter_address.key().as_ref(), new_patient_index.to_le_bytes().as_ref(), new_patient.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<PatientRecord>() + PATIENT_RECORD_EXTRA_SIZE + 8)]
+    pub new_patient_record: Box<Account<'info, PatientRecord>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(patient_index: u8)]
+pub struct GivePatientConsent<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(patient_index: u8)]
+pub struct RenamePatient<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(patient_index: u8)]
+pub struct SetPatientFlag<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct CreateProcessorAccount<'info>
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump,
+        space = size_of::<ProcessorAccount>() + PROCESSOR_EXTRA_SIZE + 8)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"processorClaimIndex".as_ref(), processor_address.key().as_ref()],
+        bump,
+        space = size_of::<ProcessorClaimIndex>() + PROCESSOR_CLAIM_INDEX_EXTRA_SIZE + 8)]
+    pub processor_claim_index: Account<'info, ProcessorClaimIndex>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct SetProcessorName<'info>
+{
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct SetProcessorAccountActiveFlag<'info>
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey)]
+pub struct SetProcessorAccountPrivilege<'info>
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(patient_index: u8, token_mint_address: Pubkey)]
+pub struct SubmitClaimToQueue<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        seeds = [b"fieldLimits".as_ref()],
+        bump)]
+    pub field_limits: Box<Account<'info, FieldLimits>>,
+    
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"claim".as_ref(), signer.key().as_ref()], 
+        bump, 
+        space = size_of::<Claim>() + CLAIM_EXTRA_SIZE + 8)]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        seeds = [b"feeConfig".as_ref()],
+        bump)]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_address,
+        associated_token::authority = signer
+    )]
+    pub user_fee_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_address,
+        associated_token::authority = treasurer.address
+        //address = anchor_spl::associated_token::get_associated_token_address(&treasurer.address, &USDC_MINT)
+    )]
+    pub treasurer_fee_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Loaded in the instruction so a removed fee token entry returns FeeTokenNotSupported
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: UncheckedAccount<'info>,
+
+    //Only passed in for fee exempt submitters
+    #[account(
+        seeds = [b"feeExemption".as_ref(), signer.key().as_ref()], 
+        bump)]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    pub token_program: Program<'info, Token>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(delegate_address: Pubkey)]
+pub struct CreateSubmitterDelegate<'info> 
+{
+    #[account(
+        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"submitterDelegate".as_ref(), signer.key().as_ref(), delegate_address.key().as_ref()],
+        bump,
+        space = size_of::<SubmitterDelegate>() + 8)]
+    pub submitter_delegate: Account<'info, SubmitterDelegate>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(delegate_address: Pubkey)]
+pub struct SetSubmitterDelegateFlag<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"submitterDelegate".as_ref(), signer.key().as_ref(), delegate_address.key().as_ref()],
+        bump)]
+    pub submitter_delegate: Account<'info, SubmitterDelegate>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(submitter_address: Pubkey, patient_index: u8, token_mint_address: Pubkey)]
+pub struct SubmitClaimAsDelegate<'info> 
+{
+    #[account(
+        mut,
+        seeds = [b"submitter".as_ref(), submitter_address.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"patient".as_ref(), submitter_address.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        seeds = [b"fieldLimits".as_ref()],
+        bump)]
+    pub field_limits: Box<Account<'info, FieldLimits>>,
+    
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump, 
+        space = size_of::<Claim>() + CLAIM_EXTRA_SIZE + 8)]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [b"submitterDelegate".as_ref(), submitter_address.key().as_ref(), signer.key().as_ref()],
+        bump)]
+    pub submitter_delegate: Account<'info, SubmitterDelegate>,
+
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        seeds = [b"feeConfig".as_ref()],
+        bump)]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_address,
+        associated_token::authority = signer
+    )]
+    pub user_fee_ata: Account<'info, TokenAccount>,
 
-        Ok(())
-    }
-}
+    #[account(
+        mut,
+        associated_token::mint = token_mint_address,
+        associated_token::authority = treasurer.address
+        //address = anchor_spl::associated_token::get_associated_token_address(&treasurer.address, &USDC_MINT)
+    )]
+    pub treasurer_fee_ata: Account<'info, TokenAccount>,
 
-//Derived Accounts
-#[derive(Accounts)]
-pub struct InitializeAdminAccounts<'info> 
-{
+    /// CHECK: Loaded in the instruction so a removed fee token entry returns FeeTokenNotSupported
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"m4aProtocolCEO".as_ref()],
-        bump,
-        space = size_of::<M4AProtocolCEO>() + 8)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: UncheckedAccount<'info>,
 
+    //Only passed in for fee exempt submitters
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"m4aProtocolTreasurer".as_ref()],
-        bump,
-        space = size_of::<M4AProtocolTreasurer>() + 8)]
-    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+        seeds = [b"feeExemption".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 
+    pub token_program: Program<'info, Token>,
+    
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-pub struct PassOnM4AProtocolCEO<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct AssignClaimToProcessor<'info> 
 {
     #[account(
         mut,
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        seeds = [b"processorStats".as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub processor_stats: Account<'info, ProcessorStats>,
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
 
-#[derive(Accounts)]
-pub struct PassOnM4AProtocolTreasurer<'info> 
-{
     #[account(
         mut,
-        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2533,22 +7666,19 @@ pub struct PassOnM4AProtocolTreasurer<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct AddFeeTokenEntry<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct AcknowledgeClaim<'info> 
 {
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub claim: Account<'info, Claim>,
 
     #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FeeTokenEntry>() + 8)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2556,9 +7686,26 @@ pub struct AddFeeTokenEntry<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct RemoveFeeTokenEntry<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct ReassignClaimToNewProcessor<'info> 
 {
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        mut,
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -2566,11 +7713,33 @@ pub struct RemoveFeeTokenEntry<'info>
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub new_processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
+        bump)]
+    pub old_processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), claim.processor_address.key().as_ref()],
+        bump)]
+    pub old_processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub new_processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2578,85 +7747,100 @@ pub struct RemoveFeeTokenEntry<'info>
 }
 
 #[derive(Accounts)]
-pub struct InitializeProtocolStats<'info>
+#[instruction(submitter_address: Pubkey)]
+pub struct UnassignClaimFromProcessor<'info> 
 {
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
 
     #[account(
-        init, 
-        payer = signer,
+        mut,
         seeds = [b"processorStats".as_ref()],
-        bump,
-        space = size_of::<ProcessorStats>() + 8)]
+        bump)]
     pub processor_stats: Account<'info, ProcessorStats>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"hospitalStats".as_ref()],
-        bump,
-        space = size_of::<HospitalStats>() + 8)]
-    pub hospital_stats: Account<'info, HospitalStats>,
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"insuranceCompanyStats".as_ref()],
-        bump,
-        space = size_of::<InsuranceCompanyStats>() + 8)]
-    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub admin_processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
+        bump)]
+    pub old_processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), claim.processor_address.key().as_ref()],
+        bump)]
+    pub old_processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
+//In the event that the claim has already been denied some kind of way and the processor is stuck on a dead claim
 #[derive(Accounts)]
-pub struct InitializeM4AProtocolAndClaimQueue<'info> 
+#[instruction(processor_address: Pubkey)]
+pub struct SetProcessorToNotProcessingClaimState<'info> 
 {
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"m4aProtocol".as_ref()],
-        bump,
-        space = size_of::<M4AProtocol>() + 8)]
-    pub m4a_protocol: Account<'info, M4AProtocol>,
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
 
-    //Stats account must exist to initialize protocol
     #[account(
+        mut,
         seeds = [b"processorStats".as_ref()],
         bump)]
     pub processor_stats: Account<'info, ProcessorStats>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"claimQueue".as_ref()],
-        bump,
-        space = size_of::<ClaimQueue>() + 8)]
-    pub claim_queue: Account<'info, ClaimQueue>,
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub admin_processor: Account<'info, ProcessorAccount>,
 
-#[derive(Accounts)]
-pub struct SetClaimQueueFlag<'info> 
-{
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut, 
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(
-        mut,
-        seeds = [b"claimQueue".as_ref()],
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), processor_address.key().as_ref()],
         bump)]
-    pub claim_queue: Account<'info, ClaimQueue>,
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
+    /// CHECK: Loaded in the instruction since the claim the processor is stuck on has usually been closed
+    #[account(
+        seeds = [b"claim".as_ref(), processor.submitter_address_of_claim_being_processed.key().as_ref()], 
+        bump)]
+    pub claim: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2664,18 +7848,32 @@ pub struct SetClaimQueueFlag<'info>
 }
 
 #[derive(Accounts)]
-pub struct EditClaimQueueSize<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct ReturnClaimForCorrection<'info> 
 {
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"processorStats".as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
-    
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Account<'info, Claim>,
+
     #[account(
         mut, 
-        seeds = [b"claimQueue".as_ref()],
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub claim_queue: Account<'info, ClaimQueue>,
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2683,21 +7881,23 @@ pub struct EditClaimQueueSize<'info>
 }
 
 #[derive(Accounts)]
-pub struct CreateSubmitterAccount<'info> 
+pub struct ResubmitCorrectedClaim<'info> 
 {
     #[account(
-        mut,
         seeds = [b"m4aProtocol".as_ref()],
         bump)]
-    pub m4a_protocol: Account<'info, M4AProtocol>,
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
-        bump,
-        space = size_of::<SubmitterAccount>() + 8)]
-    pub submitter: Account<'info, SubmitterAccount>,
+        seeds = [b"fieldLimits".as_ref()],
+        bump)]
+    pub field_limits: Box<Account<'info, FieldLimits>>,
+
+    #[account(
+        mut,
+        seeds = [b"claim".as_ref(), signer.key().as_ref()], 
+        bump)]
+    pub claim: Account<'info, Claim>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2705,7 +7905,8 @@ pub struct CreateSubmitterAccount<'info>
 }
 
 #[derive(Accounts)]
-pub struct CreatePatientAccount<'info> 
+#[instruction(submitter_address: Pubkey, country_index: u16, state_index: u32)]
+pub struct CreateStateAccount<'info> 
 {
     #[account(
         mut,
@@ -2714,18 +7915,24 @@ pub struct CreatePatientAccount<'info>
     pub m4a_protocol: Account<'info, M4AProtocol>,
 
     #[account(
-        mut,
-        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub submitter: Account<'info, SubmitterAccount>,
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(
-        init,
+        init, 
         payer = signer,
-        seeds = [b"patient".as_ref(), signer.key().as_ref(), submitter.patient_count.to_le_bytes().as_ref()],
+        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
         bump,
-        space = size_of::<PatientAccount>() + PATIENT_EXTRA_SIZE + 8)]
-    pub patient: Account<'info, PatientAccount>,
+        space = size_of::<StateAccount>() + STATE_EXTRA_SIZE + 8)]
+    pub state: Account<'info, StateAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Account<'info, Claim>, 
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2733,20 +7940,25 @@ pub struct CreatePatientAccount<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(patient_index: u8)]
-pub struct SetPatientFlag<'info> 
+#[instruction(country_index: u16, state_index: u32)]
+pub struct SetStateMetadata<'info> 
 {
     #[account(
-        mut,
-        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
         bump)]
-    pub submitter: Account<'info, SubmitterAccount>,
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
 
     #[account(
         mut,
-        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
         bump)]
-    pub patient: Account<'info, PatientAccount>,
+    pub state: Account<'info, StateAccount>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2754,27 +7966,40 @@ pub struct SetPatientFlag<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(processor_address: Pubkey)]
-pub struct CreateProcessorAccount<'info>
+#[instruction(submitter_address: Pubkey, country_index: u16, state_index: u32)]
+pub struct CreateHospital<'info> 
 {
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"hospitalStats".as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub hospital_stats: Account<'info, HospitalStats>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(
         mut,
-        seeds = [b"processorStats".as_ref()],
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
+    pub claim: Account<'info, Claim>, 
+
+    #[account(
+        mut, 
+        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
+        bump)]
+    pub state: Account<'info, StateAccount>,
 
     #[account(
         init, 
         payer = signer,
-        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        seeds = [b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), state.hospital_count.to_le_bytes().as_ref()],
         bump,
-        space = size_of::<ProcessorAccount>() + 8)]
-    pub processor: Account<'info, ProcessorAccount>,
+        space = size_of::<Hospital>() + HOSPITAL_EXTRA_SIZE + 8)]
+    pub hospital: Account<'info, Hospital>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2782,9 +8007,15 @@ pub struct CreateProcessorAccount<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(processor_address: Pubkey)]
-pub struct SetProcessorAccountActiveFlag<'info>
+#[instruction(country_index: u16, state_index: u32, hospital_index: u32)]
+pub struct EditHospital<'info> 
 {
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -2792,15 +8023,21 @@ pub struct SetProcessorAccountActiveFlag<'info>
 
     #[account(
         mut,
-        seeds = [b"processorStats".as_ref()],
+        seeds = [b"hospitalStats".as_ref()],
         bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
+    pub hospital_stats: Account<'info, HospitalStats>,
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub state: Account<'info, StateAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), hospital_index.to_le_bytes().as_ref()],
+        bump)]
+    pub hospital: Account<'info, Hospital>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -2808,8 +8045,8 @@ pub struct SetProcessorAccountActiveFlag<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(processor_address: Pubkey)]
-pub struct SetProcessorAccountPrivilege<'info>
+#[instruction(submitter_address: Pubkey, insurance_company_index: u16)]
+pub struct CreateInsuranceCompany<'info> 
 {
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
@@ -2818,78 +8055,62 @@ pub struct SetProcessorAccountPrivilege<'info>
 
     #[account(
         mut,
-        seeds = [b"processorStats".as_ref()],
+        seeds = [b"insuranceCompanyStats".as_ref()],
         bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
+    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
     pub processor: Account<'info, ProcessorAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Account<'info, Claim>, 
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"insuranceCompany".as_ref(), insurance_company_index.to_le_bytes().as_ref()],
+        bump,
+        space = size_of::<InsuranceCompany>() + INSURANCE_COMPANY_EXTRA_SIZE + 8)]
+    pub insurance_company: Account<'info, InsuranceCompany>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-#[instruction(patient_index: u8, token_mint_address: Pubkey)]
-pub struct SubmitClaimToQueue<'info> 
+#[instruction(insurance_company_index: u16)]
+pub struct EditInsuranceCompany<'info> 
 {
     #[account(
-        mut,
-        seeds = [b"submitter".as_ref(), signer.key().as_ref()],
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
         bump)]
-    pub submitter: Account<'info, SubmitterAccount>,
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
 
     #[account(
-        seeds = [b"patient".as_ref(), signer.key().as_ref(), patient_index.to_le_bytes().as_ref()],
+        seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
-    pub patient: Account<'info, PatientAccount>,
+    pub ceo: Account<'info, M4AProtocolCEO>,
 
     #[account(
         mut,
-        seeds = [b"claimQueue".as_ref()],
-        bump)]
-    pub claim_queue: Account<'info, ClaimQueue>,
-    
-    #[account(
-        init, 
-        payer = signer,
-        seeds = [b"claim".as_ref(), signer.key().as_ref()], 
-        bump, 
-        space = size_of::<Claim>() + CLAIM_EXTRA_SIZE + 8)]
-    pub claim: Account<'info, Claim>,
-
-    #[account(
-        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        seeds = [b"insuranceCompanyStats".as_ref()],
         bump)]
-    pub treasurer: Account<'info, M4AProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_fee_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-        //address = anchor_spl::associated_token::get_associated_token_address(&treasurer.address, &USDC_MINT)
-    )]
-    pub treasurer_usdc_ata: Account<'info, TokenAccount>,
+    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
 
     #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
+        mut, 
+        seeds = [b"insuranceCompany".as_ref(), insurance_company_index.to_le_bytes().as_ref()],
         bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    pub insurance_company: Account<'info, InsuranceCompany>,
 
-    pub token_program: Program<'info, Token>,
-    
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
@@ -2897,10 +8118,10 @@ pub struct SubmitClaimToQueue<'info>
 
 #[derive(Accounts)]
 #[instruction(submitter_address: Pubkey)]
-pub struct AssignClaimToProcessor<'info> 
+pub struct UpdateClaim<'info> 
 {
     #[account(
-        mut,
+        mut, 
         seeds = [b"processorStats".as_ref()],
         bump)]
     pub processor_stats: Account<'info, ProcessorStats>,
@@ -2913,7 +8134,7 @@ pub struct AssignClaimToProcessor<'info>
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
         bump)]
     pub processor: Account<'info, ProcessorAccount>,
 
@@ -2924,37 +8145,14 @@ pub struct AssignClaimToProcessor<'info>
 
 #[derive(Accounts)]
 #[instruction(submitter_address: Pubkey)]
-pub struct ReassignClaimToNewProcessor<'info> 
+pub struct AddClaimComment<'info> 
 {
-    #[account(
-        mut,
-        seeds = [b"processorStats".as_ref()],
-        bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
-
-    #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
-
     #[account(
         mut,
         seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
     pub claim: Account<'info, Claim>,
 
-    #[account(
-        mut, 
-        seeds = [b"processor".as_ref(), signer.key().as_ref()],
-        bump)]
-    pub new_processor: Account<'info, ProcessorAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
-        bump)]
-    pub old_processor: Account<'info, ProcessorAccount>,
-
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
@@ -2962,104 +8160,104 @@ pub struct ReassignClaimToNewProcessor<'info>
 
 #[derive(Accounts)]
 #[instruction(submitter_address: Pubkey)]
-pub struct UnassignClaimFromProcessor<'info> 
+pub struct CreatePatientRecord<'info> 
 {
     #[account(
-        mut,
+        mut, 
         seeds = [b"processorStats".as_ref()],
         bump)]
     pub processor_stats: Account<'info, ProcessorStats>,
 
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(
         mut,
         seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub claim: Account<'info, Claim>,
+    pub claim: Account<'info, Claim>, 
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        seeds = [b"patient".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref()],
         bump)]
-    pub admin_processor: Account<'info, ProcessorAccount>,
+    pub patient: Account<'info, PatientAccount>,
 
     #[account(
-        mut, 
-        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
-        bump)]
-    pub old_processor: Account<'info, ProcessorAccount>,
+        init, 
+        payer = signer,
+        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), patient.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<PatientRecord>() + PATIENT_RECORD_EXTRA_SIZE + 8)]
+    pub patient_record: Account<'info, PatientRecord>,  
 
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
-//In the event that the claim has already been denied some kind of way and the processor is stuck on a dead claim
 #[derive(Accounts)]
-#[instruction(processor_address: Pubkey)]
-pub struct SetProcessorToNotProcessingClaimState<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct CreateHospitalAndInsuranceCompanyRecords<'info> 
 {
     #[account(
-        mut,
+        mut, 
         seeds = [b"processorStats".as_ref()],
         bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
-
-    #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub processor_stats: Box<Account<'info, ProcessorStats>>,
 
     #[account(
         mut, 
         seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub admin_processor: Account<'info, ProcessorAccount>,
+    pub processor: Account<'info, ProcessorAccount>,
 
     #[account(
-        mut, 
-        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        mut,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    pub claim: Box<Account<'info, Claim>>,
 
-#[derive(Accounts)]
-#[instruction(submitter_address: Pubkey, country_index: u16, state_index: u32)]
-pub struct CreateStateAccount<'info> 
-{
     #[account(
-        mut,
-        seeds = [b"m4aProtocol".as_ref()],
+        mut, 
+        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), claim.patient_record_index.to_le_bytes().as_ref()], 
         bump)]
-    pub m4a_protocol: Account<'info, M4AProtocol>,
+    pub patient_record: Account<'info, PatientRecord>,  
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        seeds = [b"hospital".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub hospital: Account<'info, Hospital>,
 
     #[account(
         init, 
         payer = signer,
-        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
-        bump,
-        space = size_of::<StateAccount>() + 8)]
-    pub state: Account<'info, StateAccount>,
+        seeds = [b"hospitalRecord".as_ref(),
+        claim.country_index.to_le_bytes().as_ref(),
+        claim.state_index.to_le_bytes().as_ref(),
+        claim.hospital_index.to_le_bytes().as_ref(),
+        hospital.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<HospitalRecord>() + HOSPITAL_RECORD_EXTRA_SIZE + 8)]
+    pub hospital_record: Account<'info, HospitalRecord>, 
 
     #[account(
-        mut,
-        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        mut, 
+        seeds = [b"insuranceCompany".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref()],
         bump)]
-    pub claim: Account<'info, Claim>, 
+    pub insurance_company: Box<Account<'info, InsuranceCompany>>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"insuranceCompanyRecord".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref(), insurance_company.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<InsuranceCompanyRecord>() + INSURANCE_COMPANY_RECORD_EXTRA_SIZE + 8)]
+    pub insurance_company_record: Account<'info, InsuranceCompanyRecord>,  
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -3067,72 +8265,65 @@ pub struct CreateStateAccount<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(submitter_address: Pubkey, country_index: u16, state_index: u32)]
-pub struct CreateHospital<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct ReserveRecordSlots<'info> 
 {
     #[account(
-        mut,
-        seeds = [b"hospitalStats".as_ref()],
-        bump)]
-    pub hospital_stats: Account<'info, HospitalStats>,
-
-    #[account(
-        mut, 
         seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub processor: Box<Account<'info, ProcessorAccount>>,
 
     #[account(
         mut,
         seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub claim: Account<'info, Claim>, 
+    pub claim: Box<Account<'info, Claim>>,
 
     #[account(
         mut, 
-        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
+        seeds = [b"patient".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref()],
         bump)]
-    pub state: Account<'info, StateAccount>,
+    pub patient: Box<Account<'info, PatientAccount>>,
 
     #[account(
         init, 
         payer = signer,
-        seeds = [b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), state.hospital_count.to_le_bytes().as_ref()],
-        bump,
-        space = size_of::<Hospital>() + HOSPITAL_EXTRA_SIZE + 8)]
-    pub hospital: Account<'info, Hospital>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), patient.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<PatientRecord>() + PATIENT_RECORD_EXTRA_SIZE + 8)]
+    pub patient_record: Box<Account<'info, PatientRecord>>,
 
-#[derive(Accounts)]
-#[instruction(country_index: u16, state_index: u32, hospital_index: u32)]
-pub struct EditHospital<'info> 
-{
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut, 
+        seeds = [b"hospital".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref()],
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub hospital: Box<Account<'info, Hospital>>,
 
     #[account(
-        mut,
-        seeds = [b"hospitalStats".as_ref()],
-        bump)]
-    pub hospital_stats: Account<'info, HospitalStats>,
+        init, 
+        payer = signer,
+        seeds = [b"hospitalRecord".as_ref(),
+        claim.country_index.to_le_bytes().as_ref(),
+        claim.state_index.to_le_bytes().as_ref(),
+        claim.hospital_index.to_le_bytes().as_ref(),
+        hospital.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<HospitalRecord>() + HOSPITAL_RECORD_EXTRA_SIZE + 8)]
+    pub hospital_record: Box<Account<'info, HospitalRecord>>, 
 
     #[account(
         mut, 
-        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
+        seeds = [b"insuranceCompany".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref()],
         bump)]
-    pub state: Account<'info, StateAccount>,
+    pub insurance_company: Box<Account<'info, InsuranceCompany>>,
 
     #[account(
-        mut,
-        seeds = [b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), hospital_index.to_le_bytes().as_ref()],
-        bump)]
-    pub hospital: Account<'info, Hospital>,
+        init, 
+        payer = signer,
+        seeds = [b"insuranceCompanyRecord".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref(), insurance_company.record_count.to_le_bytes().as_ref()], 
+        bump, 
+        space = size_of::<InsuranceCompanyRecord>() + INSURANCE_COMPANY_RECORD_EXTRA_SIZE + 8)]
+    pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,  
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -3140,60 +8331,54 @@ pub struct EditHospital<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(submitter_address: Pubkey, insurance_company_index: u16)]
-pub struct CreateInsuranceCompany<'info> 
+#[instruction(submitter_address: Pubkey)]
+pub struct PopulateReservedRecords<'info> 
 {
     #[account(
-        mut,
-        seeds = [b"insuranceCompanyStats".as_ref()],
+        mut, 
+        seeds = [b"processorStats".as_ref()],
         bump)]
-    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
+    pub processor_stats: Box<Account<'info, ProcessorStats>>,
 
     #[account(
         mut, 
         seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub processor: Box<Account<'info, ProcessorAccount>>,
 
     #[account(
         mut,
         seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
         bump)]
-    pub claim: Account<'info, Claim>, 
+    pub claim: Box<Account<'info, Claim>>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"insuranceCompany".as_ref(), insurance_company_index.to_le_bytes().as_ref()],
-        bump,
-        space = size_of::<InsuranceCompany>() + INSURANCE_COMPANY_EXTRA_SIZE + 8)]
-    pub insurance_company: Account<'info, InsuranceCompany>,
+        mut, 
+        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), claim.patient_record_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub patient_record: Box<Account<'info, PatientRecord>>,
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    #[account(
+        seeds = [b"hospital".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref()],
+        bump)]
+    pub hospital: Box<Account<'info, Hospital>>,
 
-#[derive(Accounts)]
-#[instruction(insurance_company_index: u16)]
-pub struct EditInsuranceCompany<'info> 
-{
     #[account(
-        seeds = [b"m4aProtocolCEO".as_ref()],
+        mut, 
+        seeds = [b"hospitalRecord".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref(), claim.hospital_record_index.to_le_bytes().as_ref()], 
         bump)]
-    pub ceo: Account<'info, M4AProtocolCEO>,
+    pub hospital_record: Box<Account<'info, HospitalRecord>>,
 
     #[account(
-        mut,
-        seeds = [b"insuranceCompanyStats".as_ref()],
+        seeds = [b"insuranceCompany".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref()],
         bump)]
-    pub insurance_company_stats: Account<'info, InsuranceCompanyStats>,
+    pub insurance_company: Box<Account<'info, InsuranceCompany>>,
 
     #[account(
         mut, 
-        seeds = [b"insuranceCompany".as_ref(), insurance_company_index.to_le_bytes().as_ref()],
+        seeds = [b"insuranceCompanyRecord".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref(), claim.insurance_company_record_index.to_le_bytes().as_ref()], 
         bump)]
-    pub insurance_company: Account<'info, InsuranceCompany>,
+    pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -3201,9 +8386,14 @@ pub struct EditInsuranceCompany<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(submitter_address: Pubkey)]
-pub struct UpdateClaim<'info> 
+#[instruction(_submitter_address: Pubkey)]
+pub struct ApproveClaim<'info> 
 {
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3212,131 +8402,155 @@ pub struct UpdateClaim<'info>
 
     #[account(
         mut,
-        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        seeds = [b"claimQueue".as_ref()],
         bump)]
-    pub claim: Account<'info, Claim>,
+    pub claim_queue: Account<'info, ClaimQueue>,
 
+    /// CHECK: Loaded in the instruction so a closed submitter account gets a clear error
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), claim.processor_address.key().as_ref()],
+        seeds = [b"submitter".as_ref(), claim.submitter_address.key().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    pub submitter: UncheckedAccount<'info>,
 
-#[derive(Accounts)]
-#[instruction(submitter_address: Pubkey)]
-pub struct CreatePatientRecord<'info> 
-{
     #[account(
         mut, 
-        seeds = [b"processorStats".as_ref()],
+        seeds = [b"patient".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref()],
         bump)]
-    pub processor_stats: Account<'info, ProcessorStats>,
+    pub patient: Account<'info, PatientAccount>,
 
     #[account(
         mut, 
         seeds = [b"processor".as_ref(), signer.key().as_ref()],
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub processor: Box<Account<'info, ProcessorAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
         bump)]
-    pub claim: Account<'info, Claim>, 
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
 
     #[account(
         mut, 
-        seeds = [b"patient".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref()],
+        seeds = [b"state".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref()],
         bump)]
-    pub patient: Account<'info, PatientAccount>,
+    pub state: Box<Account<'info, StateAccount>>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), patient.record_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PatientRecord>() + PATIENT_RECORD_EXTRA_SIZE + 8)]
+        mut, 
+        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), claim.patient_record_index.to_le_bytes().as_ref()], 
+        bump)]
     pub patient_record: Account<'info, PatientRecord>,  
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(submitter_address: Pubkey)]
-pub struct CreateHospitalAndInsuranceCompanyRecords<'info> 
-{
     #[account(
-        mut, 
-        seeds = [b"processorStats".as_ref()],
+        mut,
+        seeds = [b"hospital".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref()], 
         bump)]
-    pub processor_stats: Box<Account<'info, ProcessorStats>>,
+    pub hospital: Box<Account<'info, Hospital>>,  
 
     #[account(
         mut, 
-        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        seeds = [b"hospitalRecord".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref(), claim.hospital_record_index.to_le_bytes().as_ref()], 
         bump)]
-    pub processor: Account<'info, ProcessorAccount>,
+    pub hospital_record: Box<Account<'info, HospitalRecord>>,  
 
     #[account(
         mut,
-        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        seeds = [b"insuranceCompany".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref()], 
         bump)]
-    pub claim: Box<Account<'info, Claim>>,
+    pub insurance_company: Box<Account<'info, InsuranceCompany>>,  
 
     #[account(
         mut, 
-        seeds = [b"patientRecord".as_ref(), claim.submitter_address.key().as_ref(), claim.patient_index.to_le_bytes().as_ref(), claim.patient_record_index.to_le_bytes().as_ref()], 
+        seeds = [b"insuranceCompanyRecord".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref(), claim.insurance_company_record_index.to_le_bytes().as_ref()], 
         bump)]
-    pub patient_record: Account<'info, PatientRecord>,  
-
+    pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,  
+    
+    /// CHECK: Created in the instruction with the same steps as init, so a processed claim that already exists at this processor count index gets ProcessedClaimAlreadyExists instead of a raw system program error
     #[account(
         mut, 
-        seeds = [b"hospital".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref(), claim.hospital_index.to_le_bytes().as_ref()],
+        seeds = [b"processedClaim".as_ref(), signer.key().as_ref(), processor.processed_claim_count.to_le_bytes().as_ref()], 
         bump)]
-    pub hospital: Account<'info, Hospital>,
+    pub processed_claim: UncheckedAccount<'info>,  
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 
+    //The submitter paid the claim's rent, so it goes back to them when the claim closes
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"hospitalRecord".as_ref(),
-        claim.country_index.to_le_bytes().as_ref(),
-        claim.state_index.to_le_bytes().as_ref(),
-        claim.hospital_index.to_le_bytes().as_ref(),
-        hospital.record_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<HospitalRecord>() + HOSPITAL_RECORD_EXTRA_SIZE + 8)]
-    pub hospital_record: Account<'info, HospitalRecord>, 
+        mut,
+        address = _submitter_address)]
+    pub submitter_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = submitter_wallet,
+        seeds = [b"claim".as_ref(), _submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Box<Account<'info, Claim>>, 
+}
+
+#[derive(Accounts)]
+#[instruction(_submitter_address: Pubkey)]
+pub struct FirstApproveClaim<'info> 
+{
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Box<Account<'info, ProcessorAccount>>,
 
     #[account(
         mut, 
-        seeds = [b"insuranceCompany".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref()],
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
         bump)]
-    pub insurance_company: Box<Account<'info, InsuranceCompany>>,
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
 
     #[account(
-        init, 
-        payer = signer,
-        seeds = [b"insuranceCompanyRecord".as_ref(), claim.insurance_company_index.to_le_bytes().as_ref(), insurance_company.record_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<InsuranceCompanyRecord>() + INSURANCE_COMPANY_RECORD_EXTRA_SIZE + 8)]
-    pub insurance_company_record: Account<'info, InsuranceCompanyRecord>,  
+        mut,
+        seeds = [b"claim".as_ref(), _submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Box<Account<'info, Claim>>, 
+}
+
+#[derive(Accounts)]
+#[instruction(_submitter_address: Pubkey)]
+pub struct RejectFirstApproval<'info> 
+{
+    #[account(
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Box<Account<'info, ProcessorAccount>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
+
+    #[account(
+        mut,
+        seeds = [b"claim".as_ref(), _submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Box<Account<'info, Claim>>, 
 }
 
 #[derive(Accounts)]
 #[instruction(_submitter_address: Pubkey)]
-pub struct ApproveClaim<'info> 
+pub struct SecondApproveClaim<'info> 
 {
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3349,11 +8563,12 @@ pub struct ApproveClaim<'info>
         bump)]
     pub claim_queue: Account<'info, ClaimQueue>,
 
+    /// CHECK: Loaded in the instruction so a closed submitter account gets a clear error
     #[account(
         mut, 
         seeds = [b"submitter".as_ref(), claim.submitter_address.key().as_ref()],
         bump)]
-    pub submitter: Box<Account<'info, SubmitterAccount>>,
+    pub submitter: UncheckedAccount<'info>,
 
     #[account(
         mut, 
@@ -3403,30 +8618,83 @@ pub struct ApproveClaim<'info>
         bump)]
     pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,  
     
+    /// CHECK: Created in the instruction with the same steps as init, so a processed claim that already exists at this processor count index gets ProcessedClaimAlreadyExists instead of a raw system program error
     #[account(
-        init, 
-        payer = signer,
+        mut, 
         seeds = [b"processedClaim".as_ref(), signer.key().as_ref(), processor.processed_claim_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<ProcessedClaim>() + PROCESSED_CLAIM_EXTRA_SIZE + 8)]
-    pub processed_claim: Account<'info, ProcessedClaim>,  
+        bump)]
+    pub processed_claim: UncheckedAccount<'info>,  
 
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>,
 
+    //The submitter paid the claim's rent, so it goes back to them when the claim closes
     #[account(
         mut,
-        close = signer,
+        address = _submitter_address)]
+    pub submitter_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = submitter_wallet,
         seeds = [b"claim".as_ref(), _submitter_address.key().as_ref()], 
         bump)]
     pub claim: Box<Account<'info, Claim>>, 
 }
 
+#[derive(Accounts)]
+pub struct ApproveClaimsBatch<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Account<'info, ProcessorStats>,
+
+    #[account(
+        mut,
+        seeds = [b"claimQueue".as_ref()],
+        bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor: Box<Account<'info, ProcessorAccount>>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
 #[derive(Accounts)]
 #[instruction(submitter_address: Pubkey)]
 pub struct ApproveClaimWithEdits<'info> 
 {
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3457,6 +8725,12 @@ pub struct ApproveClaimWithEdits<'info>
         bump)]
     pub processor: Box<Account<'info, ProcessorAccount>>,
 
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
     #[account(
         mut, 
         seeds = [b"state".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref()],
@@ -3500,13 +8774,16 @@ pub struct ApproveClaimWithEdits<'info>
         bump)]
     pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,
     
+    /// CHECK: Created in the instruction with the same steps as init, so a processed claim that already exists at this processor count index gets ProcessedClaimAlreadyExists instead of a raw system program error
     #[account(
-        init, 
-        payer = signer,
+        mut, 
         seeds = [b"processedClaim".as_ref(), signer.key().as_ref(), processor.processed_claim_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<ProcessedClaim>() + PROCESSED_CLAIM_EXTRA_SIZE + 8)]
-    pub processed_claim: Account<'info, ProcessedClaim>,
+        bump)]
+    pub processed_claim: UncheckedAccount<'info>,
+
+    //Only needed when the claim amount is edited up past the max upward edit bound
+    pub super_admin_co_signer: Option<Signer<'info>>,
+    pub super_admin_co_signer_processor: Option<Box<Account<'info, ProcessorAccount>>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -3517,6 +8794,11 @@ pub struct ApproveClaimWithEdits<'info>
 #[instruction(submitter_address: Pubkey)]
 pub struct CreatePatientRecordAndDenyClaim<'info> 
 {
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3547,6 +8829,12 @@ pub struct CreatePatientRecordAndDenyClaim<'info>
         bump)]
     pub processor: Box<Account<'info, ProcessorAccount>>,
 
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
     #[account(
         mut, 
         seeds = [b"state".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref()],
@@ -3561,13 +8849,15 @@ pub struct CreatePatientRecordAndDenyClaim<'info>
         space = size_of::<PatientRecord>() + PATIENT_RECORD_EXTRA_SIZE + 8)]
     pub patient_record: Account<'info, PatientRecord>,  
     
+    /// CHECK: Created in the instruction with the same steps as init, so a processed claim that already exists at this processor count index gets ProcessedClaimAlreadyExists instead of a raw system program error
     #[account(
-        init, 
-        payer = signer,
+        mut, 
         seeds = [b"processedClaim".as_ref(), signer.key().as_ref(), processor.processed_claim_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<ProcessedClaim>() + PROCESSED_CLAIM_EXTRA_SIZE + 8)]
-    pub processed_claim: Account<'info, ProcessedClaim>,  
+        bump)]
+    pub processed_claim: UncheckedAccount<'info>,  
+
+    //Only passed in when denying with a reason code and no denial reason text
+    pub denial_reason_template: Option<Box<Account<'info, DenialReasonTemplate>>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -3585,6 +8875,12 @@ pub struct CreatePatientRecordAndDenyClaim<'info>
 #[instruction(submitter_address: Pubkey)]
 pub struct MaxDenyPendingClaim<'info> 
 {
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -3627,6 +8923,22 @@ pub struct MaxDenyPendingClaim<'info>
         bump)]
     pub claim: Account<'info, Claim>, 
 
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    //The refund accounts are only passed in when the claim is max denied for a protocol error
+    pub treasurer_signer: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub treasurer_fee_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub submitter_fee_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
@@ -3636,6 +8948,12 @@ pub struct MaxDenyPendingClaim<'info>
 #[instruction(submitter_address: Pubkey)]
 pub struct MaxDenyInProgressClaim<'info> 
 {
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -3677,6 +8995,12 @@ pub struct MaxDenyInProgressClaim<'info>
         bump)]
     pub claim_processor: Account<'info, ProcessorAccount>,
 
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), claim.processor_address.key().as_ref()],
+        bump)]
+    pub claim_processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
     #[account(
         mut,
         close = signer,
@@ -3684,6 +9008,22 @@ pub struct MaxDenyInProgressClaim<'info>
         bump)]
     pub claim: Account<'info, Claim>, 
 
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    //The refund accounts are only passed in when the claim is max denied for a protocol error
+    pub treasurer_signer: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub treasurer_fee_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub submitter_fee_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
@@ -3693,6 +9033,11 @@ pub struct MaxDenyInProgressClaim<'info>
 #[instruction(submitter_address: Pubkey)]
 pub struct DenyClaimWithAllRecords<'info> 
 {
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump)]
+    pub m4a_protocol: Box<Account<'info, M4AProtocol>>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3723,6 +9068,12 @@ pub struct DenyClaimWithAllRecords<'info>
         bump)]
     pub processor: Box<Account<'info, ProcessorAccount>>,
 
+    #[account(
+        mut, 
+        seeds = [b"processorClaimIndex".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub processor_claim_index: Box<Account<'info, ProcessorClaimIndex>>,
+
     #[account(
         mut, 
         seeds = [b"state".as_ref(), claim.country_index.to_le_bytes().as_ref(), claim.state_index.to_le_bytes().as_ref()],
@@ -3759,30 +9110,150 @@ pub struct DenyClaimWithAllRecords<'info>
         bump)]
     pub insurance_company_record: Box<Account<'info, InsuranceCompanyRecord>>,
     
+    /// CHECK: Created in the instruction with the same steps as init, so a processed claim that already exists at this processor count index gets ProcessedClaimAlreadyExists instead of a raw system program error
     #[account(
-        init, 
-        payer = signer,
+        mut, 
         seeds = [b"processedClaim".as_ref(), signer.key().as_ref(), processor.processed_claim_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<ProcessedClaim>() + PROCESSED_CLAIM_EXTRA_SIZE + 8)]
-    pub processed_claim: Account<'info, ProcessedClaim>,  
+        bump)]
+    pub processed_claim: UncheckedAccount<'info>,  
+
+    //Only passed in when denying with a reason code and no denial reason text
+    pub denial_reason_template: Option<Box<Account<'info, DenialReasonTemplate>>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>,
 
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        mut,
+        close = signer,
+        seeds = [b"claim".as_ref(), submitter_address.key().as_ref()], 
+        bump)]
+    pub claim: Box<Account<'info, Claim>>, 
+}
+
+#[derive(Accounts)]
+#[instruction(country_index: u16, state_index: u32, hospital_index: u32)]
+pub struct ReconcileHospitalStats<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"hospital".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref(), hospital_index.to_le_bytes().as_ref()],
+        bump)]
+    pub hospital: Box<Account<'info, Hospital>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct TakeStatsSnapshot<'info> 
+{
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut,
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Box<Account<'info, ProcessorStats>>,
+
+    #[account(
+        init, 
+        payer = signer,
+        seeds = [b"statsSnapshot".as_ref(), processor_stats.stats_snapshot_count.to_le_bytes().as_ref()],
+        bump,
+        space = size_of::<StatsSnapshot>() + 8)]
+    pub stats_snapshot: Box<Account<'info, StatsSnapshot>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(country_index: u16, state_index: u32)]
+pub struct DeactivateStateHospitals<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"hospitalStats".as_ref()],
+        bump)]
+    pub hospital_stats: Account<'info, HospitalStats>,
+
+    #[account(
+        mut, 
+        seeds = [b"state".as_ref(), country_index.to_le_bytes().as_ref(), state_index.to_le_bytes().as_ref()],
+        bump)]
+    pub state: Account<'info, StateAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey, processor_count_index: u64)]
+pub struct MarkClaimSettled<'info> 
+{
+    #[account(
+        seeds = [b"m4aProtocolTreasurer".as_ref()],
+        bump)]
+    pub treasurer: Account<'info, M4AProtocolTreasurer>,
+
+    #[account(
+        mut, 
+        seeds = [b"processedClaim".as_ref(), processor_address.key().as_ref(), processor_count_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub processed_claim: Box<Account<'info, ProcessedClaim>>,
+
+    #[account(
+        mut, 
+        seeds = [b"submitter".as_ref(), processed_claim.submitter_address.key().as_ref()],
         bump)]
-    pub claim: Box<Account<'info, Claim>>, 
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 #[instruction(processor_address: Pubkey, processor_count_index: u64, token_mint_address: Pubkey)]
 pub struct AppealDeniedClaimWithOnlyPatientRecord<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3824,26 +9295,38 @@ pub struct AppealDeniedClaimWithOnlyPatientRecord<'info>
         bump)]
     pub treasurer: Account<'info, M4AProtocolTreasurer>,
 
+    #[account(
+        seeds = [b"feeConfig".as_ref()],
+        bump)]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
     #[account(
         mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::mint = token_mint_address,
         associated_token::authority = signer
     )]
     pub user_fee_ata: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::mint = token_mint_address,
         associated_token::authority = treasurer.address
         //address = anchor_spl::associated_token::get_associated_token_address(&treasurer.address, &USDC_MINT)
     )]
-    pub treasurer_usdc_ata: Account<'info, TokenAccount>,
+    pub treasurer_fee_ata: Account<'info, TokenAccount>,
 
+    /// CHECK: Loaded in the instruction so a removed fee token entry returns FeeTokenNotSupported
     #[account(
         seeds = [b"feeTokenEntry".as_ref(),
         token_mint_address.key().as_ref()], 
         bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    pub fee_token_entry: UncheckedAccount<'info>,
+
+    //Only passed in for fee exempt submitters
+    #[account(
+        seeds = [b"feeExemption".as_ref(), signer.key().as_ref()], 
+        bump)]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 
     pub token_program: Program<'info, Token>,
     
@@ -3856,9 +9339,11 @@ pub struct AppealDeniedClaimWithOnlyPatientRecord<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct DenyAppealedClaimWithOnlyPatientRecord<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
-        bump)]
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
     pub ceo: Account<'info, M4AProtocolCEO>,
 
     #[account(
@@ -3903,6 +9388,9 @@ pub struct DenyAppealedClaimWithOnlyPatientRecord<'info>
         bump)]
     pub processed_claim: Account<'info, ProcessedClaim>,
 
+    //Only passed in when denying with a reason code and no denial reason text
+    pub denial_reason_template: Option<Box<Account<'info, DenialReasonTemplate>>>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -3912,6 +9400,13 @@ pub struct DenyAppealedClaimWithOnlyPatientRecord<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64, token_mint_address: Pubkey)]
 pub struct AppealDeniedClaimWithAllRecords<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        seeds = [b"m4aProtocol".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub m4a_protocol: Account<'info, M4AProtocol>,
+
     #[account(
         mut, 
         seeds = [b"processorStats".as_ref()],
@@ -3977,26 +9472,38 @@ pub struct AppealDeniedClaimWithAllRecords<'info>
         bump)]
     pub treasurer: Account<'info, M4AProtocolTreasurer>,
 
+    #[account(
+        seeds = [b"feeConfig".as_ref()],
+        bump)]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
     #[account(
         mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::mint = token_mint_address,
         associated_token::authority = signer
     )]
     pub user_fee_ata: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::mint = token_mint_address,
         associated_token::authority = treasurer.address
         //address = anchor_spl::associated_token::get_associated_token_address(&treasurer.address, &USDC_MINT)
     )]
-    pub treasurer_usdc_ata: Account<'info, TokenAccount>,
+    pub treasurer_fee_ata: Account<'info, TokenAccount>,
 
+    /// CHECK: Loaded in the instruction so a removed fee token entry returns FeeTokenNotSupported
     #[account(
         seeds = [b"feeTokenEntry".as_ref(),
         token_mint_address.key().as_ref()], 
         bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    pub fee_token_entry: UncheckedAccount<'info>,
+
+    //Only passed in for fee exempt submitters
+    #[account(
+        seeds = [b"feeExemption".as_ref(), signer.key().as_ref()], 
+        bump)]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 
     pub token_program: Program<'info, Token>,
     
@@ -4009,6 +9516,14 @@ pub struct AppealDeniedClaimWithAllRecords<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct DenyAppealedClaimWithAllRecords<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4080,6 +9595,9 @@ pub struct DenyAppealedClaimWithAllRecords<'info>
         bump)]
     pub processed_claim: Box<Account<'info, ProcessedClaim>>,
 
+    //Only passed in when denying with a reason code and no denial reason text
+    pub denial_reason_template: Option<Box<Account<'info, DenialReasonTemplate>>>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -4089,6 +9607,14 @@ pub struct DenyAppealedClaimWithAllRecords<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4177,6 +9703,14 @@ pub struct UndenyClaimAndCreateHospitalAndInsuranceCompanyRecords<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct UndenyClaimWithAllRecords<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4257,6 +9791,14 @@ pub struct UndenyClaimWithAllRecords<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64, hospital_index: u32, insurance_company_index: u16)]
 pub struct EditProcessedClaimAndPatientRecord<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4307,6 +9849,14 @@ pub struct EditProcessedClaimAndPatientRecord<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct EditProcessedClaimAndAllRecords<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4387,6 +9937,14 @@ pub struct EditProcessedClaimAndAllRecords<'info>
 #[instruction(processor_address: Pubkey, processor_count_index: u64)]
 pub struct RevokeApproval<'info> 
 {
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4463,9 +10021,131 @@ pub struct RevokeApproval<'info>
     pub system_program: Program<'info, System>
 }
 
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey, processor_count_index: u64)]
+pub struct VoidProcessedClaim<'info> 
+{
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"processorStats".as_ref()],
+        bump)]
+    pub processor_stats: Box<Account<'info, ProcessorStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"submitter".as_ref(), processed_claim.submitter_address.key().as_ref()],
+        bump)]
+    pub submitter: Account<'info, SubmitterAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"processor".as_ref(), processor_address.key().as_ref()],
+        bump)]
+    pub processor: Account<'info, ProcessorAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"patient".as_ref(), processed_claim.submitter_address.key().as_ref(), processed_claim.patient_index.to_le_bytes().as_ref()],
+        bump)]
+    pub patient: Account<'info, PatientAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"state".as_ref(), processed_claim.country_index.to_le_bytes().as_ref(), processed_claim.state_index.to_le_bytes().as_ref()],
+        bump)]
+    pub state: Account<'info, StateAccount>,
+
+    #[account(
+        mut, 
+        seeds = [b"patientRecord".as_ref(), processed_claim.submitter_address.key().as_ref(), processed_claim.patient_index.to_le_bytes().as_ref(), processed_claim.patient_record_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub patient_record: Account<'info, PatientRecord>,
+
+    #[account(
+        mut, 
+        seeds = [b"hospital".as_ref(), processed_claim.country_index.to_le_bytes().as_ref(), processed_claim.state_index.to_le_bytes().as_ref(), processed_claim.hospital_index.to_le_bytes().as_ref()],
+        bump)]
+    pub hospital: Account<'info, Hospital>,
+
+    #[account(
+        mut, 
+        seeds = [b"hospitalRecord".as_ref(), processed_claim.country_index.to_le_bytes().as_ref(), processed_claim.state_index.to_le_bytes().as_ref(), processed_claim.hospital_index.to_le_bytes().as_ref(), processed_claim.hospital_record_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub hospital_record: Account<'info, HospitalRecord>,  
+
+    #[account(
+        mut,
+        seeds = [b"insuranceCompany".as_ref(), processed_claim.insurance_company_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub insurance_company: Box<Account<'info, InsuranceCompany>>,  
+
+    #[account(
+        mut, 
+        seeds = [b"insuranceCompanyRecord".as_ref(), processed_claim.insurance_company_index.to_le_bytes().as_ref(), processed_claim.insurance_company_record_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub insurance_company_record: Account<'info, InsuranceCompanyRecord>,
+
+    #[account(
+        mut, 
+        seeds = [b"processedClaim".as_ref(), processor_address.key().as_ref(), processor_count_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub processed_claim: Box<Account<'info, ProcessedClaim>>,  
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(processor_address: Pubkey, processor_count_index: u64)]
+pub struct ArchiveProcessedClaim<'info> 
+{
+    //Processed claim index is checked on the first account so a mismatch gets a friendly error before the processed claim seeds are checked
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump,
+        constraint = processed_claim.processor_count_index == processor_count_index @ InvalidOperationError::ProcessedClaimIndexMismatch)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
+    #[account(
+        seeds = [b"m4aProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, M4AProtocolCEO>,
+
+    #[account(
+        mut, 
+        seeds = [b"processedClaim".as_ref(), processor_address.key().as_ref(), processor_count_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub processed_claim: Box<Account<'info, ProcessedClaim>>,  
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
 #[derive(Accounts)]
 pub struct DropDenialHammer<'info> 
 {
+    #[account(
+        mut, 
+        seeds = [b"adminActionLog".as_ref()],
+        bump)]
+    pub admin_action_log: Box<Account<'info, AdminActionLog>>,
+
     #[account(
         seeds = [b"m4aProtocolCEO".as_ref()],
         bump)]
@@ -4509,18 +10189,136 @@ pub struct M4AProtocolTreasurer
 
 #[account]
 pub struct FeeTokenEntry
+{
+    pub token_mint_address: Pubkey,
+    pub decimal_amount: u8,
+    pub valid_until: i64 //0 means the fee token is accepted forever
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeeTokenEntryInput
 {
     pub token_mint_address: Pubkey,
     pub decimal_amount: u8
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ProcessorCurrentClaim
+{
+    pub submitter_address: Pubkey,
+    pub is_processing_claim: bool
+}
+
+//Anchor can't put tuples in the IDL, so can_submit returns this instead of a (bool, u8)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CanSubmitResult
+{
+    pub can_submit: bool,
+    pub reason_code: u8
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ProcessedClaimSummary
+{
+    pub processed_claim_id: u64,
+    pub status: u8,
+    pub claim_amount: u64,
+    pub processed_time: u64
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimComment
+{
+    pub author: Pubkey,
+    pub text: String,
+    pub time: u64
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct AdminActionEntry
+{
+    pub action_code: u8,
+    pub actor: Pubkey,
+    pub timestamp: u64
+}
+
+#[account]
+pub struct AdminActionLog
+{
+    pub total_action_count: u64,
+    pub entries: [AdminActionEntry; ADMIN_ACTION_LOG_SIZE]
+}
+
+#[account]
+pub struct FeeConfig
+{
+    pub preferred_fee_mint: Pubkey,
+    pub patient_only_appeal_fee_cents: u64,
+    pub full_appeal_fee_cents: u64,
+    pub fees_enabled: bool
+}
+
+#[account]
+pub struct FieldLimits
+{
+    pub hospital_name_length: u32,
+    pub hospital_address_length: u32,
+    pub hospital_city_length: u32,
+    pub hospital_bill_invoice_number_length: u32,
+    pub note_length: u32,
+    pub ailment_length: u32,
+    pub diagnosis_code_count: u32,
+    pub diagnosis_code_length: u32,
+    pub insurance_company_name_length: u32
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FieldLimitsInput
+{
+    pub hospital_name_length: u32,
+    pub hospital_address_length: u32,
+    pub hospital_city_length: u32,
+    pub hospital_bill_invoice_number_length: u32,
+    pub note_length: u32,
+    pub ailment_length: u32,
+    pub diagnosis_code_count: u32,
+    pub diagnosis_code_length: u32,
+    pub insurance_company_name_length: u32
+}
+
+#[account]
+pub struct FeeExemption
+{
+    pub submitter_address: Pubkey
+}
+
+#[account]
+pub struct DenialReasonTemplate
+{
+    pub code: u16,
+    pub text: String
+}
+
 #[account]
 pub struct M4AProtocol
 {
     pub m4a_protocol_initiator_address: Pubkey,
     pub submitter_account_total: u64,
     pub patient_account_total: u64,
-    pub state_account_total: u32
+    pub state_account_total: u32,
+    pub max_appeals_per_claim: u8,
+    pub max_upward_edit_bps: u16,
+    pub account_creation_enabled: bool,
+    pub sla_seconds: u64,
+    pub min_claim_amount_cents: u64,
+    pub assignment_cooldown_seconds: u64,
+    pub max_daily_processing: u32,
+    pub min_queue_size: u32,
+    pub max_queue_size: u32,
+    pub auto_flag_amount_cents: u64, //0 means claims aren't auto flagged
+    pub dual_signoff_amount_cents: u64, //0 means no claim needs a second approval
+    pub max_reassignments: u16, //0 means claims aren't auto flagged for being reassigned
+    pub submitter_id_counter: u64 //Only ever goes up, unlike submitter account total which drops when a submitter closes their account
 }
 
 #[account]
@@ -4551,7 +10349,32 @@ pub struct ProcessorStats
     pub submitted_appeal_count: u64,
     pub denied_appeal_count: u64,
     pub revoked_approval_count: u64,
-    pub denial_hammer_dropped_count: u64
+    pub denial_hammer_dropped_count: u64,
+    pub approved_claim_count_by_type: [u64; 5],
+    pub denied_claim_count_by_type: [u64; 5],
+    pub sla_breach_count: u64,
+    pub flagged_claim_count: u64,
+    pub stats_snapshot_count: u64,
+    pub last_stats_snapshot_time: u64,
+    pub approved_amount_buckets: [u64; 6] //Approvals by claim amount: <$100, <$1k, <$10k, <$100k, <$1M, >=$1M
+}
+
+#[account]
+pub struct StatsSnapshot
+{
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub taken_by: Pubkey,
+    pub processor_account_total: u64,
+    pub processor_active_account_total: u64,
+    pub processed_claim_count: u64,
+    pub approved_claim_amount: u64,
+    pub approved_claim_count: u64,
+    pub denied_claim_count: u64,
+    pub max_denied_claim_count: u64,
+    pub submitted_appeal_count: u64,
+    pub revoked_approval_count: u64,
+    pub flagged_claim_count: u64
 }
 
 #[account]
@@ -4562,7 +10385,8 @@ pub struct HospitalStats
     pub dental_hospital_count: u32,
     pub vision_hospital_count: u32,
     pub mental_hospital_count: u32,
-    pub edited_hospital_count: u32
+    pub edited_hospital_count: u32,
+    pub hospital_count_by_type: [u32; 5]
 }
 
 #[account]
@@ -4588,7 +10412,16 @@ pub struct SubmitterAccount
     pub undenied_claim_count: u32,
     pub submitted_appeal_count: u32,
     pub denied_appeal_count: u32,
-    pub revoked_approval_count: u32
+    pub revoked_approval_count: u32,
+    pub settled_claim_count: u32
+}
+
+#[account]
+pub struct SubmitterDelegate
+{
+    pub submitter_address: Pubkey,
+    pub delegate_address: Pubkey,
+    pub is_active: bool
 }
 
 #[account]
@@ -4609,7 +10442,10 @@ pub struct PatientAccount
     pub undenied_claim_count: u32,
     pub submitted_appeal_count: u32,
     pub denied_appeal_count: u32,
-    pub revoked_approval_count: u32 
+    pub revoked_approval_count: u32,
+    pub lifetime_benefit_cap_cents: u64, //0 means unlimited
+    pub consent_given: bool,
+    pub consent_time: u64
 }
 
 #[account]
@@ -4634,9 +10470,26 @@ pub struct ProcessorAccount
     pub undenied_claim_count: u64,
     pub denied_appeal_count: u64,
     pub revoked_approval_count: u64,
-    pub denial_hammer_dropped_count: u64
+    pub denial_hammer_dropped_count: u64,
+    pub denial_rate_bps: u16,
+    pub onboarded_time: u64,
+    pub deactivated_time: u64,
+    pub claims_in_progress: Vec<Pubkey>, //Submitter addresses of the claims the processor is holding
+    pub last_unassigned_time: u64,
+    pub processed_today_count: u32,
+    pub day_window_start: u64,
+    pub name: String,
+    pub last_processed_time: u64 //Last time the processor approved, denied, or undenied a claim
 }    
 
+//Reverse lookup from a processor to the claims assigned to them, since claims are keyed by submitter
+#[account]
+pub struct ProcessorClaimIndex
+{
+    pub processor_address: Pubkey,
+    pub submitter_addresses: Vec<Pubkey> //Submitter addresses of the claims assigned to the processor, bounded by MAX_CLAIMS_IN_PROGRESS
+}
+
 #[account]
 pub struct Claim
 {
@@ -4662,11 +10515,29 @@ pub struct Claim
     pub hospital_phone_number: u128,
     pub hospital_bill_invoice_number: String,
     pub note: String,
+    pub processor_notes: String,
+    pub processor_note_edit_count: u32,
     pub claim_amount: u64,
+    pub copay_amount: u64,
+    pub deductible_amount: u64,
+    pub currency_code: [u8; 3],
     pub ailment: String,
     pub submitted_time: u64,
+    pub sla_deadline: u64,
     pub insurance_company_index: i16,
-    pub insurance_company_name: String
+    pub insurance_company_name: String,
+    pub correction_reason: String,
+    pub are_record_slots_reserved: bool,
+    pub diagnosis_codes: Vec<String>,
+    pub is_flagged_for_review: bool,
+    pub flag_reason: String,
+    pub fee_mint: Pubkey, //Token the fee was paid in, a protocol error refund goes back in this token
+    pub fee_amount_paid: u64, //Zero when the fee was waived, a protocol error refund sends back exactly this much
+    pub comments: Vec<ClaimComment>,
+    pub content_hash: [u8; 32],
+    pub auto_flagged: bool,
+    pub first_approver_address: Pubkey, //Set while the claim is waiting on a second approval
+    pub reassignment_count: u16
 }
 
 #[account]
@@ -4676,8 +10547,10 @@ pub struct ProcessedClaim
     pub claim_id: u64,
     pub processor_count_index: u64,
     pub status: u8,
+    pub is_archived: bool,
     pub denial_reason: String,
     pub appeal_reason: String,
+    pub appeal_count: u8,
     pub is_patient_record_created: bool,
     pub is_hospital_record_created: bool,
     pub is_insurance_company_record_created: bool,
@@ -4685,6 +10558,8 @@ pub struct ProcessedClaim
     pub hospital_record_index: u64,
     pub insurance_company_record_index: u64,
     pub processor_address: Pubkey,
+    pub record_creator_address: Pubkey,
+    pub resolving_processor_address: Pubkey,
     pub submitter_address: Pubkey,
     pub patient_index: u8,
     pub country_index: u16,
@@ -4698,12 +10573,29 @@ pub struct ProcessedClaim
     pub hospital_phone_number: u128,
     pub hospital_bill_invoice_number: String,
     pub note: String,
+    pub processor_notes: String,
+    pub processor_note_edit_count: u32,
     pub claim_amount: u64,
+    pub copay_amount: u64,
+    pub deductible_amount: u64,
+    pub currency_code: [u8; 3],
     pub ailment: String,
     pub submitted_time: u64,
     pub processed_time: u64,
+    pub sla_breached: bool,
     pub insurance_company_index: i16,
-    pub insurance_company_name: String
+    pub insurance_company_name: String,
+    pub diagnosis_codes: Vec<String>,
+    pub settled_time: u64,
+    pub is_flagged_for_review: bool,
+    pub flag_reason: String,
+    pub eob_hash: [u8; 32],
+    pub insurance_covered_amount: u64, //Only set while the claim is approved
+    pub fee_mint: Pubkey,
+    pub fee_amount_paid: u64,
+    pub void_reason: String,
+    pub content_hash: [u8; 32],
+    pub first_approver_address: Pubkey //Only set for claims that needed a second approval
 }
 
 #[account]
@@ -4723,7 +10615,10 @@ pub struct StateAccount
     pub dental_hospital_count: u32,
     pub vision_hospital_count: u32,
     pub mental_hospital_count: u32,
-    pub edited_hospital_count: u32
+    pub edited_hospital_count: u32,
+    pub state_name: String,
+    pub state_abbreviation: [u8; 2],
+    pub hospital_count_by_type: [u32; 5]
 }
 
 #[account]
@@ -4744,10 +10639,13 @@ pub struct PatientRecord
     pub insurance_company_index: u16,
     pub hospital_bill_invoice_number: String,
     pub claim_amount: u64,
+    pub copay_amount: u64,
+    pub deductible_amount: u64,
     pub ailment: String,
     pub note: String,
     pub submitted_time: u64,
-    pub processed_time: u64
+    pub processed_time: u64,
+    pub diagnosis_codes: Vec<String>
 }
 
 #[account]
@@ -4776,6 +10674,15 @@ pub struct Hospital
     pub submitted_appeal_count: u64,
     pub denied_appeal_count: u64,
     pub revoked_approval_count: u64,
+    pub created_by: Pubkey,
+    pub note_history: Vec<HospitalNoteEntry> //Oldest first, the current note stays in note
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HospitalNoteEntry
+{
+    pub note: String,
+    pub time: u64 //When the note was replaced
 }
 
 #[account]
@@ -4795,10 +10702,14 @@ pub struct HospitalRecord
     pub insurance_company_index: u16,
     pub hospital_bill_invoice_number: String,
     pub claim_amount: u64,
+    pub copay_amount: u64,
+    pub deductible_amount: u64,
     pub ailment: String,
     pub note: String,
     pub submitted_time: u64,
     pub processed_time: u64,
+    pub diagnosis_codes: Vec<String>,
+    pub processed_claim_id: u64 //Links back to the processed claim once the claim is resolved
 }
 
 #[account]
@@ -4817,7 +10728,8 @@ pub struct InsuranceCompany
     pub undenied_claim_count: u64,
     pub submitted_appeal_count: u64,
     pub denied_appeal_count: u64,
-    pub revoked_approval_count: u64, 
+    pub revoked_approval_count: u64,
+    pub created_by: Pubkey
 }
 
 #[account]
@@ -4837,8 +10749,12 @@ pub struct InsuranceCompanyRecord
     pub hospital_index: u32,
     pub hospital_bill_invoice_number: String,
     pub claim_amount: u64,
+    pub copay_amount: u64,
+    pub deductible_amount: u64,
     pub ailment: String,
     pub note: String,
     pub submitted_time: u64,
-    pub processed_time: u64
+    pub processed_time: u64,
+    pub diagnosis_codes: Vec<String>,
+    pub eob_hash: [u8; 32]
 }
\ No newline at end of file
@@ -3,6 +3,8 @@ import { Program } from "@coral-xyz/anchor"
 import { M4AProtocol } from "../target/types/m_4_a_protocol"
 import { utf8 } from "@coral-xyz/anchor/dist/cjs/utils/bytes"
 import { assert } from "chai"
+import { createMint, getAccount, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token"
+import { createHash } from "crypto"
 
 describe("M4A_Protocol", () => {
   // Configure the client to use the local cluster.
@@ -26,16 +28,25 @@ describe("M4A_Protocol", () => {
   const hospitalAddress = "Lorem ipsum dolor sit amet, consectetuer adipiscing elit. Aenean commodo ligula eget dolor. Aenean m"
   const hospitalCity= "Lorem ipsum dolor sit amet, consectetuer"
   const hospitalZipCode = 77777
-  const hospitalPhoneNumber = new anchor.BN(9007199254740991)//3.4028236692093846346337460743177e+38//    
+  const hospitalPhoneNumber = new anchor.BN(999999999999999)//Max 15 digits (E.164)
   const hospitalBillInvoiceNumber = "Lorem ipsum dolor si"  
   const note144Characters = "Lorem ipsum dolor sit amet, consectetuer adipiscing elit. Aenean commodo ligula eget dolor. Aenean massa. Cum sociis natoque penatibus et magnis"
   const claimAmount = new anchor.BN(10000)
+  const copayAmount = new anchor.BN(2000)
+  const deductibleAmount = new anchor.BN(500)
+  const currencyCode = Array.from(Buffer.from("USD"))
   const ailment = "Lorem ipsum dolor sit amet, consectetuer adip"
   const insuranceCompanyIndex = 0
   const insuranceCompanyName = "😂LMAO I don't have insurance😂"
+  const diagnosisCodes = ["J45.909"]
 
   let firstCustomerWallet = anchor.web3.Keypair.generate()
 
+  const treasurerAddress = new anchor.web3.PublicKey("9BRgCdmwyP5wGVTvKAUDjSwucpqGncurVa35DjaWqSsC")
+  const payer = ((program.provider as anchor.AnchorProvider).wallet as anchor.Wallet).payer
+  const feeTokenDecimals = 6
+  let feeTokenMint: anchor.web3.PublicKey
+
   it("Initializes M4A Protocol CEO Account", async () => 
   {
     await program.methods.initializeM4AProtocolCeoAccount().rpc()
@@ -62,10 +73,14 @@ describe("M4A_Protocol", () => {
       signature: token_airdrop
     })
 
-    await program.methods.initializeM4AProtocolAndClaimQueue()
+    const initialQueueSize = 150
+    await program.methods.initializeM4AProtocolAndClaimQueue(initialQueueSize)
     .accounts({signer: firstCustomerWallet.publicKey})
     .signers([firstCustomerWallet])
     .rpc()
+
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.queueSizeLimit == initialQueueSize)
   })
 
   it("Passes on the M4A Protocol CEO Account", async () => 
@@ -103,6 +118,24 @@ describe("M4A_Protocol", () => {
     assert(claimQueue.enabled == true)
   }) 
 
+  //Submitting reads the fees enabled flag, so the fee config has to exist before the first claim
+  it("Initializes Fee Config", async () => 
+  {
+    await program.methods.initializeFeeConfig().rpc()
+
+    const feeConfig = await program.account.feeConfig.fetch(getFeeConfigPDA())
+    assert(feeConfig.feesEnabled == true)
+  })
+
+  //Submitting reads the field limits, so they have to exist before the first claim too
+  it("Initializes Field Limits", async () => 
+  {
+    await program.methods.initializeFieldLimits().rpc()
+
+    const fieldLimits = await program.account.fieldLimits.fetch(getFieldLimitsPDA())
+    assert(fieldLimits.noteLength == note144Characters.length)
+  })
+
   it("Creates Submitter Account", async () => 
   {
     await program.methods.createSubmitterAccount()
@@ -118,6 +151,11 @@ describe("M4A_Protocol", () => {
     .signers([firstCustomerWallet])
     .rpc()
 
+    await program.methods.givePatientConsent(patientIndex)
+    .accounts({signer: firstCustomerWallet.publicKey})
+    .signers([firstCustomerWallet])
+    .rpc()
+
     var patient = await program.account.patientAccount.fetch(getPatientPDA(firstCustomerWallet.publicKey, patientIndex))
 
     assert(patient.patientFirstName == patientFirstName)
@@ -182,9 +220,13 @@ describe("M4A_Protocol", () => {
       hospitalBillInvoiceNumber,
       note144Characters,
       claimAmount,
+      copayAmount,
+      deductibleAmount,
+      currencyCode,
       ailment,
       insuranceCompanyIndex,
-      insuranceCompanyName)
+      insuranceCompanyName,
+      diagnosisCodes)
     .accounts({signer: firstCustomerWallet.publicKey})
     .signers([firstCustomerWallet])
     .rpc()
@@ -193,6 +235,7 @@ describe("M4A_Protocol", () => {
   it("Marks Claim For Processing", async () => 
   {
     await program.methods.assignClaimToProcessor(firstCustomerWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(firstCustomerWallet.publicKey).rpc()
   })
   
   it("Creates State Account", async () => 
@@ -216,11 +259,17 @@ describe("M4A_Protocol", () => {
         hospitalZipCode,
         hospitalPhoneNumber,
         note144Characters).rpc()
+
+      const hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))
+      assert(hospital.createdBy.equals(program.provider.publicKey))
     })
 
   it("Creates Insurance Company", async () => 
   {
     await program.methods.createInsuranceCompany(firstCustomerWallet.publicKey, insuranceCompanyIndex, insuranceCompanyName, note144Characters).rpc()
+
+    const insuranceCompany = await program.account.insuranceCompany.fetch(getInsuranceCompanyPDA(insuranceCompanyIndex))
+    assert(insuranceCompany.createdBy.equals(program.provider.publicKey))
   })
 
   it("Creates Patient Record", async () => 
@@ -239,7 +288,9 @@ describe("M4A_Protocol", () => {
     console.log("Processed Claim Count: ", processorStats.processedClaimCount)
     console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
 
-    await program.methods.approveClaim(firstCustomerWallet.publicKey).rpc()
+    await program.methods.approveClaim(firstCustomerWallet.publicKey, null)
+    .accounts({submitterWallet: firstCustomerWallet.publicKey})
+    .rpc()
 
     processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
     console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -278,6 +329,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -293,6 +349,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -306,7 +365,7 @@ describe("M4A_Protocol", () => {
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("MaxDeniedClaim Count: ", processorStats.maxDeniedClaimCount)
 
-      await program.methods.maxDenyPendingClaim(newWallet.publicKey).rpc()
+      await program.methods.maxDenyPendingClaim(newWallet.publicKey, 0).rpc()
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
 
       //const derp = await program.account.processedClaim.all()
@@ -348,6 +407,11 @@ describe("M4A_Protocol", () => {
         .accounts({signer: newWallet.publicKey})
         .signers([newWallet])
         .rpc()
+
+        await program.methods.givePatientConsent(patientIndex)
+        .accounts({signer: newWallet.publicKey})
+        .signers([newWallet])
+        .rpc()
   
         await program.methods.submitClaimToQueue
         (
@@ -364,6 +428,9 @@ describe("M4A_Protocol", () => {
           hospitalBillInvoiceNumber,
           note144Characters,
           claimAmount,
+          copayAmount,
+          deductibleAmount,
+          currencyCode,
           ailment,
           insuranceCompanyIndex,
           insuranceCompanyName
@@ -373,13 +440,14 @@ describe("M4A_Protocol", () => {
         .rpc()
 
         await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+        await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
   
         var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
         
         console.log("Processed Claim Count: ", processorStats.processedClaimCount)
         console.log("MaxDeniedClaim Count: ", processorStats.maxDeniedClaimCount)
   
-        await program.methods.maxDenyInProgressClaim(newWallet.publicKey).rpc()
+        await program.methods.maxDenyInProgressClaim(newWallet.publicKey, 0).rpc()
         var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
   
         //const derp = await program.account.processedClaim.all()
@@ -422,6 +490,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -437,6 +510,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -446,6 +522,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
       
       await program.methods.createPatientRecord(newWallet.publicKey).rpc()
       await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
@@ -456,7 +533,7 @@ describe("M4A_Protocol", () => {
       console.log("DeniedClaim Count: ", processorStats.deniedClaimCount)
 
       const denialReason = "Testing"
-      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason).rpc()
+      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason, null).rpc()
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
 
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -496,6 +573,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -511,6 +593,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -520,6 +605,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       const denialReason = "Testing"
 
@@ -527,7 +613,7 @@ describe("M4A_Protocol", () => {
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("DeniedClaim Count: ", processorStats.deniedClaimCount)
 
-      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason).rpc()
+      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -567,6 +653,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       const wrongHospitalIndex = 11
       const wrongInsuranceIndex = 11
 
@@ -585,6 +676,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         wrongInsuranceIndex,
         insuranceCompanyName
@@ -594,6 +688,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       await program.methods.updateClaimHospitalIndex(newWallet.publicKey, hospitalIndex).rpc()
       await program.methods.updateClaimInsuranceCompanyIndex(newWallet.publicKey, insuranceCompanyIndex).rpc()
@@ -602,7 +697,9 @@ describe("M4A_Protocol", () => {
 
       await program.methods.createPatientRecord(newWallet.publicKey).rpc()
       await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
-      await program.methods.approveClaim(newWallet.publicKey).rpc()
+      await program.methods.approveClaim(newWallet.publicKey, null)
+      .accounts({submitterWallet: newWallet.publicKey})
+      .rpc()
     }
   })
 
@@ -638,6 +735,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -653,6 +755,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -729,6 +834,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -744,6 +854,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -753,6 +866,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -765,7 +879,9 @@ describe("M4A_Protocol", () => {
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
 
-      await program.methods.approveClaim(newWallet.publicKey).rpc()
+      await program.methods.approveClaim(newWallet.publicKey, null)
+      .accounts({submitterWallet: newWallet.publicKey})
+      .rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -790,14 +906,19 @@ describe("M4A_Protocol", () => {
       hospitalBillInvoiceNumber,
       note144Characters,
       claimAmount,
+      copayAmount,
+      deductibleAmount,
+      currencyCode,
       ailment,
       insuranceCompanyIndex,
-      insuranceCompanyName)
+      insuranceCompanyName,
+      diagnosisCodes)
     .accounts({signer: firstCustomerWallet.publicKey})
     .signers([firstCustomerWallet])
     .rpc()
 
     await program.methods.assignClaimToProcessor(firstCustomerWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(firstCustomerWallet.publicKey).rpc()
 
     const hospitalLongitudeEdited = 1.111
     const hospitalLatitudeEdited = 8.88
@@ -828,8 +949,11 @@ describe("M4A_Protocol", () => {
       hospitalBillInvoiceNumberEdited,
       claimNoteEdited,
       claimAmountEdited,
+      copayAmount,
+      deductibleAmount,
       ailmentEdited,
       insuranceCompanyName,
+      null
     ).rpc()
   })
 
@@ -865,6 +989,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -880,6 +1009,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -889,13 +1021,14 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
 
       const denialReason = "Testing"
-      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason).rpc()
+      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -955,6 +1088,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -970,6 +1108,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -979,6 +1120,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -992,7 +1134,7 @@ describe("M4A_Protocol", () => {
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
       
       const denialReason = "Testing"
-      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason).rpc()
+      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1052,6 +1194,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -1067,6 +1214,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -1076,13 +1226,14 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Denied Claim Count: ", processorStats.deniedClaimCount)
 
       const denialReason = "Testing"
-      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason).rpc()
+      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1101,7 +1252,7 @@ describe("M4A_Protocol", () => {
       console.log("Denied Appeal Count: ", processorStats.deniedAppealCount)
 
       const denyAppealReason = "Testing Denying Appeal"
-      await program.methods.denyAppealedClaimWithOnlyPatientRecord(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1)), denyAppealReason).rpc()
+      await program.methods.denyAppealedClaimWithOnlyPatientRecord(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1)), denyAppealReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1141,6 +1292,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -1156,6 +1312,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -1165,6 +1324,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
       
       await program.methods.createPatientRecord(newWallet.publicKey).rpc()
       await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
@@ -1174,7 +1334,7 @@ describe("M4A_Protocol", () => {
       console.log("Denied Claim Count: ", processorStats.deniedClaimCount)
       
       const denialReason = "Testing"
-      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason).rpc()
+      await program.methods.denyClaimWithAllRecords(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1192,7 +1352,7 @@ describe("M4A_Protocol", () => {
       console.log("Denied Appeal Count: ", processorStats.deniedAppealCount)
 
       const denyAppealReason = "Testing Denying Appeal"
-      await program.methods.denyAppealedClaimWithAllRecords(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1)), denyAppealReason).rpc()
+      await program.methods.denyAppealedClaimWithAllRecords(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1)), denyAppealReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Denied Appeal Count: ", processorStats.deniedAppealCount)
@@ -1231,6 +1391,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -1246,6 +1411,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -1255,13 +1423,14 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
 
       const denialReason = "Testing"
-      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason).rpc()
+      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, denialReason, null).rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1282,6 +1451,8 @@ describe("M4A_Protocol", () => {
         newHospitalBillInvoiceNumber,
         newClaimNote,
         newClaimAmount,
+        copayAmount,
+        deductibleAmount,
         newAilment).rpc()
     }
   })
@@ -1318,6 +1489,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -1333,6 +1509,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -1342,6 +1521,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1354,7 +1534,9 @@ describe("M4A_Protocol", () => {
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
 
-      await program.methods.approveClaim(newWallet.publicKey).rpc()
+      await program.methods.approveClaim(newWallet.publicKey, null)
+      .accounts({submitterWallet: newWallet.publicKey})
+      .rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1373,6 +1555,8 @@ describe("M4A_Protocol", () => {
         newHospitalBillInvoiceNumber,
         newClaimNote,
         newClaimAmount,
+        copayAmount,
+        deductibleAmount,
         newAilment)
       .rpc()
     }
@@ -1410,6 +1594,11 @@ describe("M4A_Protocol", () => {
       .signers([newWallet])
       .rpc()
 
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+
       await program.methods.submitClaimToQueue
       (
         patientIndex,
@@ -1425,6 +1614,9 @@ describe("M4A_Protocol", () => {
         hospitalBillInvoiceNumber,
         note144Characters,
         claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
         ailment,
         insuranceCompanyIndex,
         insuranceCompanyName
@@ -1434,6 +1626,7 @@ describe("M4A_Protocol", () => {
       .rpc()
 
       await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
 
       var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1446,7 +1639,9 @@ describe("M4A_Protocol", () => {
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
       console.log("Approved Claim Count: ", processorStats.approvedClaimCount)
       
-      await program.methods.approveClaim(newWallet.publicKey).rpc()
+      await program.methods.approveClaim(newWallet.publicKey, null)
+      .accounts({submitterWallet: newWallet.publicKey})
+      .rpc()
 
       processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
       console.log("Processed Claim Count: ", processorStats.processedClaimCount)
@@ -1471,147 +1666,4563 @@ describe("M4A_Protocol", () => {
     }*/
   })
 
-  const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms))
-  var counter = 0
-  async function sleepFunction() {
-    console.log('Start sleep: ', counter)
-    await sleep(5000) // Sleep for 5 seconds
-    console.log('End sleep: ', counter)
-    counter += 1
-  }
-
-  function getM4AProtocolCEOAccountPDA()
+  it("Creates Fee Token Mint And Adds Fee Token Entry", async () => 
   {
-    const [m4aProtocolCEOPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("m4aProtocolCEO")
-      ],
-      program.programId
-    )
-    return m4aProtocolCEOPDA
-  }
+    feeTokenMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+    await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, treasurerAddress)
 
-  function getM4AProtocolPDA()
-  {
-    const [m4aProtocolPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("m4aProtocol")
-      ],
-      program.programId
-    )
-    return m4aProtocolPDA
-  }
+    await program.methods.addFeeTokenEntry(feeTokenMint, feeTokenDecimals).rpc()
 
-  function getprocessorStatsPDA()
-  {
-    const [processorStatsPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("processorStats")
-      ],
-      program.programId
-    )
-    return processorStatsPDA
-  }
+    var feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(feeTokenMint))
+    assert(feeTokenEntry.decimalAmount == feeTokenDecimals)
+  })
 
-  function getPatientPDA(submitterAddress: anchor.web3.PublicKey, patientIndex: number)
+  it("Emits Queue Full Event When Submitting To A Full Claim Queue", async () => 
   {
-    const [patientPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("patient"),
-        submitterAddress.toBuffer(),
-        new anchor.BN(patientIndex).toBuffer('le', 1)
-      ],
-      program.programId
-    )
-    return patientPDA
-  }
+    let newWallet = await createFundedSubmitter()
 
-  function getProcessorPDA(processorAddress: anchor.web3.PublicKey)
-  {
-    const [processorPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("processor"),
-        processorAddress.toBuffer()
-      ],
-      program.programId
-    )
-    return processorPDA
-  }
+    //Shrink the queue down to whatever is currently in it so the next claim doesn't fit
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const originalQueueSizeLimit = claimQueue.queueSizeLimit
+    await program.methods.editClaimQueueSize(claimQueue.currentClaimQueueCount).rpc()
 
-  function getClaimPDA(submitterAddress: anchor.web3.PublicKey )
+    try
+    {
+      await submitClaim(newWallet)
+      assert(false, "Submitting to a full queue should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "TooManyClaimsInQueue")
+
+      const events = [...new anchor.EventParser(program.programId, program.coder).parseLogs(error.logs)]
+      const queueFullEvent = events.find(event => event.name == "queueFull")
+
+      assert(queueFullEvent != undefined)
+      assert(queueFullEvent.data.current == claimQueue.currentClaimQueueCount)
+      assert(queueFullEvent.data.limit == claimQueue.currentClaimQueueCount)
+    }
+
+    await program.methods.editClaimQueueSize(originalQueueSizeLimit).rpc()
+  })
+
+  it("Rejects A Claim Whose Copay And Deductible Add Up To More Than The Claim Amount", async () => 
   {
-    const [claimPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        new TextEncoder().encode("claim"),
-        submitterAddress.toBuffer()
-      ],
-      program.programId
-    )
-    return claimPDA
-  }
+    let newWallet = await createFundedSubmitter()
 
-  function getClaimQueuePDA()
+    try
+    {
+      await submitClaim(newWallet, claimAmount, claimAmount, new anchor.BN(1))
+      assert(false, "Copay plus deductible over the claim amount should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "CopayAndDeductibleExceedClaimAmount")
+    }
+
+    await submitClaim(newWallet, claimAmount, claimAmount.sub(deductibleAmount), deductibleAmount)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(newWallet.publicKey))
+    assert(claim.copayAmount.eq(claimAmount.sub(deductibleAmount)))
+    assert(claim.deductibleAmount.eq(deductibleAmount))
+  })
+
+  it("Updates Fee Token Decimals And Charges The Next Fee With The New Decimals", async () => 
   {
-    const [claimQueuePDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        utf8.encode("claimQueue"),
-      ],
-      program.programId
-    )
-    return claimQueuePDA
-  }
+    let newWallet = await createFundedSubmitter()
+    const newFeeTokenDecimals = feeTokenDecimals - 2
 
-  function getClaimHistoryPDA()
+    await program.methods.updateFeeTokenDecimals(feeTokenMint, newFeeTokenDecimals).rpc()
+
+    var feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(feeTokenMint))
+    assert(feeTokenEntry.decimalAmount == newFeeTokenDecimals)
+
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, newWallet.publicKey)
+    const balanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+
+    await submitClaim(newWallet)
+
+    //4 cents with 4 decimals is 400 base units
+    const balanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    assert(balanceBefore - balanceAfter == BigInt(400))
+
+    await program.methods.updateFeeTokenDecimals(feeTokenMint, feeTokenDecimals).rpc()
+  })
+
+  it("Removes A Fee Token Entry And Rejects Claims Paying With It", async () => 
   {
-    const [claimHistoryPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        utf8.encode("claimHistory"),
-      ],
-      program.programId
-    )
-    return claimHistoryPDA
-  }
+    let newWallet = await createFundedSubmitter()
 
-  function getClaimHistoryChunkPDA(chunkId: number)
+    const removedFeeTokenMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+    await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, removedFeeTokenMint, treasurerAddress)
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, removedFeeTokenMint, newWallet.publicKey)
+    await mintTo(program.provider.connection, payer, removedFeeTokenMint, userFeeAta.address, payer, 1000 * 10 ** feeTokenDecimals)
+
+    await program.methods.addFeeTokenEntry(removedFeeTokenMint, feeTokenDecimals).rpc()
+    await program.methods.removeFeeTokenEntry(removedFeeTokenMint).rpc()
+
+    var feeTokenEntry = await program.account.feeTokenEntry.fetchNullable(getFeeTokenEntryPDA(removedFeeTokenMint))
+    assert(feeTokenEntry == null)
+
+    try
+    {
+      await submitClaim(newWallet, claimAmount, copayAmount, deductibleAmount, removedFeeTokenMint)
+      assert(false, "Submitting with a removed fee token should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FeeTokenNotSupported")
+    }
+  })
+
+  it("Updates Processor Notes On An Assigned Claim", async () => 
   {
-    const [claimHistoryChunkPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        utf8.encode("claimHistoryChunk"),
-        new anchor.BN(chunkId).toBuffer('le', 4)
-      ],
-      program.programId
-    )
-    return claimHistoryChunkPDA
-  }
+    let newWallet = await createFundedSubmitter()
+    let notTheProcessorWallet = anchor.web3.Keypair.generate()
 
-  function getProcessedClaimPDA(processedClaimIndex: number)
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.appendProcessorNote(newWallet.publicKey, note144Characters + "!").rpc()
+      assert(false, "Processor note over the max length should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NoteTooLong")
+    }
+
+    try
+    {
+      await program.methods.appendProcessorNote(newWallet.publicKey, "Not my claim")
+      .accounts({signer: notTheProcessorWallet.publicKey})
+      .signers([notTheProcessorWallet])
+      .rpc()
+      assert(false, "Only the assigned processor should be able to update processor notes")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotTheProcessor")
+    }
+
+    await program.methods.appendProcessorNote(newWallet.publicKey, "Waiting on itemized bill").rpc()
+    await program.methods.appendProcessorNote(newWallet.publicKey, "Itemized bill received").rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(newWallet.publicKey))
+    assert(claim.processorNotes == "Itemized bill received")
+    assert(claim.processorNoteEditCount == 2)
+
+    await program.methods.unassignClaimFromProcessor(newWallet.publicKey).rpc()
+  })
+
+  it("Sets The Preferred Fee Mint", async () => 
   {
-    const [claimHistoryChunkPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
-        utf8.encode("processedClaim"),
-        new anchor.web3.PublicKey("93AWVuZKVhNQ4Uky3oFFwNewTgMs6T4aenZ4efom4gUw").toBuffer(),
-        new anchor.BN(processedClaimIndex).toBuffer('le', 4)
-      ],
-      program.programId
-    )
-    return claimHistoryChunkPDA
-  }
+    await program.methods.setPreferredFeeMint(feeTokenMint).rpc()
 
-  function getInsuranceCompanyPDA(index: number)
+    const preferredFeeMint = await program.methods.getPreferredFeeMint().view()
+    assert(preferredFeeMint.equals(feeTokenMint))
+  })
+
+  it("Rejects Denying A Claim That Isn't Being Processed", async () => 
   {
-    const [insuranceCompanyPDA] = anchor.web3.PublicKey.findProgramAddressSync
-    (
-      [
+    let newWallet = await createFundedSubmitter()
+
+    //Claim is left pending, as if another transaction already moved it out of processing
+    await submitClaim(newWallet)
+
+    try
+    {
+      await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, "Denying a pending claim", null).rpc()
+      assert(false, "Denying a claim that isn't being processed should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimNotBeingProcessed")
+    }
+  })
+
+  it("Validates Hospital Coordinates At The Boundaries And Out Of Range", async () => 
+  {
+    const editHospitalCoordinates = async (longitude: number, latitude: number) => 
+    {
+      await program.methods.editHospital
+      (
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        true,
+        hospitalType,
+        longitude,
+        latitude,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        note144Characters).rpc()
+    }
+
+    await editHospitalCoordinates(180, 90)
+    await editHospitalCoordinates(-180, -90)
+
+    for(const [longitude, latitude] of [[0, 90.000001], [0, -90.000001], [180.000001, 0], [-180.000001, 0], [-1000, 1000]])
+    {
+      try
+      {
+        await editHospitalCoordinates(longitude, latitude)
+        assert(false, "Out of range hospital coordinates should have failed")
+      }
+      catch(error)
+      {
+        assert(error.error.errorCode.code == "InvalidCoordinates")
+      }
+    }
+
+    await editHospitalCoordinates(hospitalLongitude, hospitalLatitude)
+  })
+
+  it("Blocks A Second Appeal When Max Appeals Per Claim Is 1", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocol.maxAppealsPerClaim == 1)
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(newWallet.publicKey, "Testing Appeal Limit", null).rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    await program.methods.appealDeniedClaimWithOnlyPatientRecord(program.provider.publicKey, processorCountIndex, feeTokenMint, "First Appeal")
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    await program.methods.denyAppealedClaimWithOnlyPatientRecord(program.provider.publicKey, processorCountIndex, "Denying First Appeal", null).rpc()
+
+    try
+    {
+      await program.methods.appealDeniedClaimWithOnlyPatientRecord(program.provider.publicKey, processorCountIndex, feeTokenMint, "Second Appeal")
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+      assert(false, "A second appeal should have failed when the max is 1")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "TooManyAppeals")
+    }
+  })
+
+  it("Recomputes The Processor Denial Rate After Approving And Denying Claims", async () => 
+  {
+    let approvedWallet = await createFundedSubmitter()
+    let deniedWallet = await createFundedSubmitter()
+
+    await submitClaim(approvedWallet)
+    await program.methods.assignClaimToProcessor(approvedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(approvedWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(approvedWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(approvedWallet.publicKey).rpc()
+    await program.methods.approveClaim(approvedWallet.publicKey, null)
+    .accounts({submitterWallet: approvedWallet.publicKey})
+    .rpc()
+
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.denialRateBps == processor.deniedClaimCount.muln(10000).div(processor.processedClaimCount).toNumber())
+
+    await submitClaim(deniedWallet)
+    await program.methods.assignClaimToProcessor(deniedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(deniedWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(deniedWallet.publicKey, "Testing Denial Rate", null).rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.denialRateBps == processor.deniedClaimCount.muln(10000).div(processor.processedClaimCount).toNumber())
+    assert(processor.denialRateBps > 0)
+  })
+
+  it("Approves A Claim, Revokes The Approval, And Then Appeals The Revocation", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    const revokeReason = "Testing Appeal After Revoke"
+    await program.methods.revokeApproval(program.provider.publicKey, processorCountIndex, revokeReason).rpc()
+
+    await program.methods.appealDeniedClaimWithAllRecords(program.provider.publicKey, processorCountIndex, feeTokenMint, "Appealing Revoked Approval")
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex))
+    assert(processedClaim.status == 4) //Appealed
+    assert(processedClaim.denialReason == revokeReason)
+    assert(processedClaim.appealReason == "Appealing Revoked Approval")
+  })
+
+  it("Logs The Most Recent Admin Actions In Order", async () => 
+  {
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+
+    await program.methods.setClaimQueueFlag(false).rpc()
+    await program.methods.setClaimQueueFlag(true).rpc()
+    await program.methods.editClaimQueueSize(claimQueue.queueSizeLimit).rpc()
+
+    const adminActionLog = await program.account.adminActionLog.fetch(getAdminActionLogPDA())
+    const totalActionCount = adminActionLog.totalActionCount.toNumber()
+    const logSize = adminActionLog.entries.length
+
+    //Most recent entries are right behind the total action count in the ring buffer
+    const recentEntries = [3, 2, 1].map(offset => adminActionLog.entries[(totalActionCount - offset) % logSize])
+    const setClaimQueueFlagActionCode = 7
+    const editClaimQueueSizeActionCode = 8
+
+    assert(logSize == 32)
+    assert(recentEntries[0].actionCode == setClaimQueueFlagActionCode)
+    assert(recentEntries[1].actionCode == setClaimQueueFlagActionCode)
+    assert(recentEntries[2].actionCode == editClaimQueueSizeActionCode)
+    assert(recentEntries.every(entry => entry.actor.equals(program.provider.publicKey)))
+    assert(recentEntries[0].timestamp.lte(recentEntries[2].timestamp))
+  })
+
+  it("Submits A Claim As An Authorized Delegate And Rejects Unauthorized Signers", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+    let delegateWallet = await createFundedSubmitter()
+
+    await program.methods.createSubmitterDelegate(delegateWallet.publicKey)
+    .accounts({signer: submitterWallet.publicKey})
+    .signers([submitterWallet])
+    .rpc()
+
+    await submitClaimAsDelegate(delegateWallet, submitterWallet.publicKey)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.submitterAddress.equals(submitterWallet.publicKey))
+
+    //A signer that was never made a delegate has no delegate account
+    let otherSubmitterWallet = await createFundedSubmitter()
+
+    try
+    {
+      await submitClaimAsDelegate(delegateWallet, otherSubmitterWallet.publicKey)
+      assert(false, "Submitting as a delegate without being granted should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "AccountNotInitialized")
+    }
+
+    //A delegate that has been deactivated can't submit anymore
+    await program.methods.createSubmitterDelegate(delegateWallet.publicKey)
+    .accounts({signer: otherSubmitterWallet.publicKey})
+    .signers([otherSubmitterWallet])
+    .rpc()
+
+    await program.methods.setSubmitterDelegateFlag(delegateWallet.publicKey, false)
+    .accounts({signer: otherSubmitterWallet.publicKey})
+    .signers([otherSubmitterWallet])
+    .rpc()
+
+    try
+    {
+      await submitClaimAsDelegate(delegateWallet, otherSubmitterWallet.publicKey)
+      assert(false, "Submitting as a deactivated delegate should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotSubmitterDelegate")
+    }
+  })
+
+  it("Rejects Revoking An Approval With A Processor Count Index That Doesn't Match The Processed Claim", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const wrongProcessorCountIndex = processorCountIndex.add(new anchor.BN(1))
+
+    try
+    {
+      await program.methods.revokeApproval(program.provider.publicKey, wrongProcessorCountIndex, "Wrong Index")
+      .accountsPartial({processedClaim: getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex)})
+      .rpc()
+      assert(false, "Revoking with a mismatched processor count index should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ProcessedClaimIndexMismatch")
+    }
+  })
+
+  it("Approves Two Claims In One Batch And Updates Both Sets Of Stats", async () => 
+  {
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, true).rpc()
+
+    var processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    var superProcessorBefore = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    var submitterWallets = []
+    var processorWallets = []
+    var batchAccounts = []
+
+    for(var i=0; i<2; i++)
+    {
+      let submitterWallet = await createFundedSubmitter()
+      let processorWallet = await createFundedSubmitter()
+      await program.methods.createProcessorAccount(processorWallet.publicKey).rpc()
+
+      await submitClaim(submitterWallet)
+
+      for(const method of ["assignClaimToProcessor", "acknowledgeClaim", "createPatientRecord", "createHospitalAndInsuranceCompanyRecords"])
+      {
+        await program.methods[method](submitterWallet.publicKey)
+        .accounts({signer: processorWallet.publicKey})
+        .signers([processorWallet])
+        .rpc()
+      }
+
+      const claimPDA = getClaimPDA(submitterWallet.publicKey)
+      var claim = await program.account.claim.fetch(claimPDA)
+      const processorCountIndex = superProcessorBefore.processedClaimCount.add(new anchor.BN(i))
+
+      batchAccounts.push(...getBatchApprovalAccounts(claimPDA, claim, processorCountIndex))
+      submitterWallets.push(submitterWallet)
+      processorWallets.push(processorWallet)
+    }
+
+    await program.methods.approveClaimsBatch()
+    .remainingAccounts(batchAccounts)
+    .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({units: 1400000})])
+    .rpc()
+
+    var processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    var superProcessorAfter = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+
+    assert(processorStatsAfter.approvedClaimCount.eq(processorStatsBefore.approvedClaimCount.add(new anchor.BN(2))))
+    assert(processorStatsAfter.approvedClaimAmount.eq(processorStatsBefore.approvedClaimAmount.add(claimAmount.mul(new anchor.BN(2)))))
+    assert(superProcessorAfter.approvedClaimCount.eq(superProcessorBefore.approvedClaimCount.add(new anchor.BN(2))))
+
+    for(var i=0; i<2; i++)
+    {
+      var submitter = await program.account.submitterAccount.fetch(getSubmitterPDA(submitterWallets[i].publicKey))
+      var patient = await program.account.patientAccount.fetch(getPatientPDA(submitterWallets[i].publicKey, patientIndex))
+      var claimProcessor = await program.account.processorAccount.fetch(getProcessorPDA(processorWallets[i].publicKey))
+      var claimProcessorClaimIndex = await program.account.processorClaimIndex.fetch(getProcessorClaimIndexPDA(processorWallets[i].publicKey))
+      var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, superProcessorBefore.processedClaimCount.add(new anchor.BN(i))))
+
+      assert(submitter.approvedClaimCount == 1)
+      assert(submitter.approvedClaimAmount.eq(claimAmount))
+      assert(patient.approvedClaimCount == 1)
+      assert(claimProcessor.isProcessingClaim == false)
+      assert(claimProcessorClaimIndex.submitterAddresses.length == 0)
+      assert(processedClaim.status == 2) //Approved
+      assert(processedClaim.submitterAddress.equals(submitterWallets[i].publicKey))
+      assert(await program.account.claim.fetchNullable(getClaimPDA(submitterWallets[i].publicKey)) == null)
+    }
+
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, false).rpc()
+  })
+
+  it("Rejects Attaching Records To An Inactive Hospital Or Insurance Company", async () => 
+  {
+    const setHospitalActiveFlag = async (isActive: boolean) => 
+    {
+      await program.methods.editHospital
+      (
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        isActive,
+        hospitalType,
+        hospitalLongitude,
+        hospitalLatitude,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        note144Characters).rpc()
+    }
+
+    const setInsuranceCompanyActiveFlag = async (isActive: boolean) => 
+    {
+      await program.methods.editInsuranceCompany(insuranceCompanyIndex, isActive, insuranceCompanyName, note144Characters).rpc()
+    }
+
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+
+    await setHospitalActiveFlag(false)
+
+    try
+    {
+      await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+      assert(false, "Attaching a record to an inactive hospital should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "HospitalInactive")
+    }
+
+    await setHospitalActiveFlag(true)
+    await setInsuranceCompanyActiveFlag(false)
+
+    try
+    {
+      await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+      assert(false, "Attaching a record to an inactive insurance company should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "InsuranceCompanyInactive")
+    }
+
+    await setInsuranceCompanyActiveFlag(true)
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(newWallet.publicKey))
+    assert(claim.isHospitalRecordCreated == true)
+    assert(claim.isInsuranceCompanyRecordCreated == true)
+
+    await program.methods.unassignClaimFromProcessor(newWallet.publicKey).rpc()
+  })
+
+  it("Archives A Processed Claim By Blanking Its Free Text But Keeping Amounts And Status", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const processedClaimPDA = getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex)
+
+    var processedClaimBefore = await program.account.processedClaim.fetch(processedClaimPDA)
+    await program.methods.archiveProcessedClaim(program.provider.publicKey, processorCountIndex).rpc()
+    var processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+
+    assert(processedClaim.isArchived == true)
+    assert(processedClaim.hospitalAddress == "")
+    assert(processedClaim.hospitalName == "")
+    assert(processedClaim.claimAmount.eq(claimAmount))
+    assert(processedClaim.status == 2) //Approved
+    assert(processedClaim.claimId.eq(processedClaimBefore.claimId))
+    assert(processedClaim.processedTime.eq(processedClaimBefore.processedTime))
+
+    try
+    {
+      await program.methods.archiveProcessedClaim(program.provider.publicKey, processorCountIndex).rpc()
+      assert(false, "Archiving the same processed claim twice should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ProcessedClaimAlreadyArchived")
+    }
+  })
+
+  it("Bounds Upward Claim Amount Edits By Max Upward Edit BPS", async () => 
+  {
+    const maxUpwardEditBps = 1000 //10%
+    await program.methods.setMaxUpwardEditBps(maxUpwardEditBps).rpc()
+
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+
+    const approveWithClaimAmount = async (claimAmountEdited: anchor.BN) => 
+    {
+      await program.methods.approveClaimWithEdits
+      (
+        newWallet.publicKey, 
+        hospitalType,
+        hospitalLongitude,
+        hospitalLatitude,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        hospitalBillInvoiceNumber,
+        note144Characters,
+        claimAmountEdited,
+        copayAmount,
+        deductibleAmount,
+        ailment,
+        insuranceCompanyName,
+        null
+      ).rpc()
+    }
+
+    //Just over 10% more than the submitted 10000
+    try
+    {
+      await approveWithClaimAmount(new anchor.BN(11001))
+      assert(false, "Editing the claim amount up past the bound should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "AmountEditTooLarge")
+    }
+
+    //Just under 10% more than the submitted 10000
+    await approveWithClaimAmount(new anchor.BN(10999))
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.claimAmount.eq(new anchor.BN(10999)))
+
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocol.maxUpwardEditBps == maxUpwardEditBps)
+
+    await program.methods.setMaxUpwardEditBps(0).rpc()
+  })
+
+  it("Sets The Processor Onboarded Time On Creation And The Deactivated Time On Deactivation", async () => 
+  {
+    const processorAddress = anchor.web3.Keypair.generate().publicKey
+
+    await program.methods.createProcessorAccount(processorAddress).rpc()
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(processorAddress))
+
+    assert(processor.onboardedTime.gt(new anchor.BN(0)))
+    assert(processor.deactivatedTime.eq(new anchor.BN(0)))
+
+    await program.methods.setProcessorAccountActiveFlag(processorAddress, false).rpc()
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(processorAddress))
+
+    assert(processor.deactivatedTime.gte(processor.onboardedTime))
+
+    await program.methods.setProcessorAccountActiveFlag(processorAddress, true).rpc()
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(processorAddress))
+
+    assert(processor.deactivatedTime.eq(new anchor.BN(0)))
+  })
+
+  it("Disables Account Creation While Still Letting Claims Be Approved", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+
+    await program.methods.setAccountCreationFlag(false).rpc()
+
+    let unregisteredWallet = anchor.web3.Keypair.generate()
+    let airdrop = await program.provider.connection.requestAirdrop(unregisteredWallet.publicKey, 1000 * 10002240)
+    const latestBlockHash = await program.provider.connection.getLatestBlockhash()
+    await program.provider.connection.confirmTransaction
+    ({
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature: airdrop,
+    })
+
+    try
+    {
+      await program.methods.createSubmitterAccount()
+      .accounts({signer: unregisteredWallet.publicKey})
+      .signers([unregisteredWallet])
+      .rpc()
+      assert(false, "Creating a submitter account while account creation is disabled should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "AccountCreationDisabled")
+    }
+
+    try
+    {
+      await program.methods.createPatientAccount("Jane", "Doe")
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+      assert(false, "Creating a patient account while account creation is disabled should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "AccountCreationDisabled")
+    }
+
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    var submitter = await program.account.submitterAccount.fetch(getSubmitterPDA(newWallet.publicKey))
+    assert(submitter.approvedClaimCount == 1)
+
+    await program.methods.setAccountCreationFlag(true).rpc()
+  })
+
+  it("Accepts A 15 Digit Hospital Phone Number And Rejects A 16 Digit One", async () => 
+  {
+    const editHospitalPhoneNumber = async (phoneNumber: anchor.BN) => 
+    {
+      await program.methods.editHospital
+      (
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        true,
+        hospitalType,
+        hospitalLongitude,
+        hospitalLatitude,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        phoneNumber,
+        note144Characters).rpc()
+    }
+
+    await editHospitalPhoneNumber(new anchor.BN("999999999999999"))
+
+    var hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))
+    assert(hospital.hospitalPhoneNumber.eq(new anchor.BN("999999999999999")))
+
+    try
+    {
+      await editHospitalPhoneNumber(new anchor.BN("1000000000000000"))
+      assert(false, "A 16 digit hospital phone number should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "HospitalPhoneNumberTooLong")
+    }
+
+    await editHospitalPhoneNumber(hospitalPhoneNumber)
+  })
+
+  it("Rejects Setting The Claim Queue Flag To Its Current State", async () => 
+  {
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.enabled == true)
+
+    try
+    {
+      await program.methods.setClaimQueueFlag(true).rpc()
+      assert(false, "Setting the claim queue flag to its current state should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FlagSameState")
+    }
+  })
+
+  it("Emits Last Super Admin Demoted Event When Demoting The Last Super Admin", async () => 
+  {
+    const processorAddress = anchor.web3.Keypair.generate().publicKey
+    await program.methods.createProcessorAccount(processorAddress).rpc()
+
+    var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    assert(processorStats.processorSuperAdminAccountTotal == 0)
+
+    await program.methods.setProcessorAccountPrivilege(processorAddress, true).rpc()
+    const signature = await program.methods.setProcessorAccountPrivilege(processorAddress, false).rpc({commitment: "confirmed"})
+
+    const transaction = await program.provider.connection.getTransaction(signature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+    const events = [...new anchor.EventParser(program.programId, program.coder).parseLogs(transaction.meta.logMessages)]
+    const lastSuperAdminDemotedEvent = events.find(event => event.name == "lastSuperAdminDemoted")
+
+    assert(lastSuperAdminDemotedEvent != undefined)
+    assert(lastSuperAdminDemotedEvent.data.processorAddress.equals(processorAddress))
+
+    processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    assert(processorStats.processorSuperAdminAccountTotal == 0)
+  })
+
+  it("Waives The Fee For An Exempt Submitter And Charges A Non Exempt One Normally", async () => 
+  {
+    let exemptWallet = await createFundedSubmitter()
+    let nonExemptWallet = await createFundedSubmitter()
+    const feeExemptionPDA = getFeeExemptionPDA(exemptWallet.publicKey)
+
+    await program.methods.grantFeeExemption(exemptWallet.publicKey).rpc()
+
+    var feeExemption = await program.account.feeExemption.fetch(feeExemptionPDA)
+    assert(feeExemption.submitterAddress.equals(exemptWallet.publicKey))
+
+    const exemptFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, exemptWallet.publicKey)
+    const nonExemptFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, nonExemptWallet.publicKey)
+    const exemptBalanceBefore = (await getAccount(program.provider.connection, exemptFeeAta.address)).amount
+    const nonExemptBalanceBefore = (await getAccount(program.provider.connection, nonExemptFeeAta.address)).amount
+
+    await submitClaim(exemptWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, feeExemptionPDA)
+    await submitClaim(nonExemptWallet)
+
+    const exemptBalanceAfter = (await getAccount(program.provider.connection, exemptFeeAta.address)).amount
+    const nonExemptBalanceAfter = (await getAccount(program.provider.connection, nonExemptFeeAta.address)).amount
+
+    //4 cents with 6 decimals is 40000 base units
+    assert(exemptBalanceBefore == exemptBalanceAfter)
+    assert(nonExemptBalanceBefore - nonExemptBalanceAfter == BigInt(40000))
+
+    //A submitter can't borrow someone else's exemption
+    let borrowingWallet = await createFundedSubmitter()
+
+    try
+    {
+      await submitClaim(borrowingWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, feeExemptionPDA)
+      assert(false, "Submitting with another submitter's fee exemption should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ConstraintSeeds")
+    }
+
+    await program.methods.revokeFeeExemption(exemptWallet.publicKey).rpc()
+    assert(await program.account.feeExemption.fetchNullable(feeExemptionPDA) == null)
+  })
+
+  it("Submits A Claim In Euros And Carries The Currency Code Through To The Processed Claim", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+    const euroCurrencyCode = Array.from(Buffer.from("EUR"))
+
+    await submitClaim(newWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, euroCurrencyCode)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(newWallet.publicKey))
+    assert(Buffer.from(claim.currencyCode).toString() == "EUR")
+
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(Buffer.from(processedClaim.currencyCode).toString() == "EUR")
+
+    //Leaving the currency code blank defaults it to USD
+    let blankCurrencyWallet = await createFundedSubmitter()
+    await submitClaim(blankCurrencyWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, [0, 0, 0])
+
+    claim = await program.account.claim.fetch(getClaimPDA(blankCurrencyWallet.publicKey))
+    assert(Buffer.from(claim.currencyCode).toString() == "USD")
+  })
+
+  it("Gets The Roles Of A CEO Key, A Processor Key, And An Unknown Key", async () => 
+  {
+    const ceoRole = 1
+    const treasurerRole = 2
+    const processorRole = 4
+
+    //The CEO wallet is also the main processor in these tests
+    const ceoRoles = await program.methods.getRoles(program.provider.publicKey).view()
+    assert(ceoRoles == (ceoRole | processorRole))
+
+    const processorAddress = anchor.web3.Keypair.generate().publicKey
+    await program.methods.createProcessorAccount(processorAddress).rpc()
+
+    const processorRoles = await program.methods.getRoles(processorAddress).view()
+    assert(processorRoles == processorRole)
+
+    const treasurerRoles = await program.methods.getRoles(treasurerAddress).view()
+    assert(treasurerRoles == treasurerRole)
+
+    const unknownRoles = await program.methods.getRoles(anchor.web3.Keypair.generate().publicKey).view()
+    assert(unknownRoles == 0)
+  })
+
+  it("Approves A Claim Whose Processed Claim PDA Was Pre-Created With Lamports", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+
+    //Anyone can send lamports to the next processed claim PDA ahead of time, that must not block the approval
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaimPDA = getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount)
+
+    const preCreateTransaction = new anchor.web3.Transaction().add(anchor.web3.SystemProgram.transfer
+    ({
+      fromPubkey: program.provider.publicKey,
+      toPubkey: processedClaimPDA,
+      lamports: 1000000
+    }))
+    await (program.provider as anchor.AnchorProvider).sendAndConfirm(preCreateTransaction)
+
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    var processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+    assert(processedClaim.status == 2) //Approved
+    assert(processedClaim.submitterAddress.equals(newWallet.publicKey))
+  })
+
+  it("Tracks Approved And Denied Claim Counts By Hospital Type", async () => 
+  {
+    const dentalType = 1
+    const visionType = 2
+    const denialReason = "Testing"
+
+    var processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    let dentalWallet = await createFundedSubmitter()
+    await submitClaim(dentalWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, dentalType)
+    await program.methods.assignClaimToProcessor(dentalWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(dentalWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(dentalWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(dentalWallet.publicKey).rpc()
+    await program.methods.approveClaim(dentalWallet.publicKey, null)
+    .accounts({submitterWallet: dentalWallet.publicKey})
+    .rpc()
+
+    let visionWallet = await createFundedSubmitter()
+    await submitClaim(visionWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, visionType)
+    await program.methods.assignClaimToProcessor(visionWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(visionWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(visionWallet.publicKey, denialReason, null).rpc()
+
+    var processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    for(let i = 0; i < 4; i++)
+    {
+      const approvedDelta = processorStatsAfter.approvedClaimCountByType[i].sub(processorStatsBefore.approvedClaimCountByType[i]).toNumber()
+      const deniedDelta = processorStatsAfter.deniedClaimCountByType[i].sub(processorStatsBefore.deniedClaimCountByType[i]).toNumber()
+
+      assert(approvedDelta == (i == dentalType ? 1 : 0))
+      assert(deniedDelta == (i == visionType ? 1 : 0))
+    }
+
+    //Undenying takes the denial back out of the vision bucket and counts it as a vision approval
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const visionProcessorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const visionProcessedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, visionProcessorCountIndex))
+    assert(visionProcessedClaim.hospitalType == visionType)
+
+    await program.methods.undenyClaimAndCreateHospitalAndInsuranceCompanyRecords(program.provider.publicKey, visionProcessorCountIndex).rpc()
+
+    processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    for(let i = 0; i < 4; i++)
+    {
+      const approvedDelta = processorStatsAfter.approvedClaimCountByType[i].sub(processorStatsBefore.approvedClaimCountByType[i]).toNumber()
+      const deniedDelta = processorStatsAfter.deniedClaimCountByType[i].sub(processorStatsBefore.deniedClaimCountByType[i]).toNumber()
+
+      assert(approvedDelta == (i == dentalType || i == visionType ? 1 : 0))
+      assert(deniedDelta == 0)
+    }
+  })
+
+  it("Flags A Claim Processed After Its SLA Deadline As Breached", async () => 
+  {
+    //Shrink the SLA to 1 second so the wait below crosses the deadline
+    await program.methods.setSlaSeconds(new anchor.BN(1)).rpc()
+
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocol.slaSeconds.eq(new anchor.BN(1)))
+
+    var processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    let newWallet = await createFundedSubmitter()
+    await submitClaim(newWallet)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(newWallet.publicKey))
+    assert(claim.slaDeadline.eq(claim.submittedTime.add(new anchor.BN(1))))
+
+    await sleep(3000)
+
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.slaBreached == true)
+
+    var processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    assert(processorStatsAfter.slaBreachCount.eq(processorStatsBefore.slaBreachCount.add(new anchor.BN(1))))
+
+    //Put the SLA back to 30 days so later claims aren't flagged
+    await program.methods.setSlaSeconds(new anchor.BN(30 * 24 * 60 * 60)).rpc()
+  })
+
+  it("Sets And Reads State Metadata", async () => 
+  {
+    const stateName = "California"
+    const stateAbbreviation = Array.from(Buffer.from("CA"))
+
+    await program.methods.setStateMetadata(countryIndex, stateIndex, stateName, stateAbbreviation).rpc()
+
+    var state = await program.account.stateAccount.fetch(getStatePDA(countryIndex, stateIndex))
+    assert(state.stateName == stateName)
+    assert(Buffer.from(state.stateAbbreviation).toString() == "CA")
+
+    try
+    {
+      await program.methods.setStateMetadata(countryIndex, stateIndex, "A".repeat(33), stateAbbreviation).rpc()
+      assert(false, "A state name longer than 32 characters should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "StateNameTooLong")
+    }
+
+    try
+    {
+      await program.methods.setStateMetadata(countryIndex, stateIndex, stateName, Array.from(Buffer.from("ca"))).rpc()
+      assert(false, "A lowercase state abbreviation should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "InvalidStateAbbreviation")
+    }
+  })
+
+  it("Captures Both The Record Creator And The Resolving Processor Across A Reassignment", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+    let newProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(newProcessorWallet.publicKey).rpc()
+    await program.methods.setProcessorAccountPrivilege(newProcessorWallet.publicKey, true).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    //The new super admin takes the claim over after the records were created by the original processor
+    await program.methods.reassignClaimToNewProcessor(submitterWallet.publicKey, null)
+    .accounts({signer: newProcessorWallet.publicKey})
+    .signers([newProcessorWallet])
+    .rpc()
+
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({signer: newProcessorWallet.publicKey, submitterWallet: submitterWallet.publicKey})
+    .signers([newProcessorWallet])
+    .rpc()
+
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(newProcessorWallet.publicKey, new anchor.BN(0)))
+    assert(processedClaim.recordCreatorAddress.equals(program.provider.publicKey))
+    assert(processedClaim.resolvingProcessorAddress.equals(newProcessorWallet.publicKey))
+
+    await program.methods.setProcessorAccountPrivilege(newProcessorWallet.publicKey, false).rpc()
+  })
+
+  it("Estimates The Rent Tied Up In Open Claims", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+    await submitClaim(newWallet)
+
+    const claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const rentEstimate = await program.methods.openClaimRentEstimate().view()
+
+    assert(claimQueue.currentClaimQueueCount > 0)
+    assert(rentEstimate.gt(new anchor.BN(0)))
+
+    //Every open claim has the same allocated space, so the estimate splits evenly across the queue
+    assert(rentEstimate.mod(new anchor.BN(claimQueue.currentClaimQueueCount)).eq(new anchor.BN(0)))
+  })
+
+  it("Rejects Claims Below The Min Claim Amount And Accepts Claims At It", async () => 
+  {
+    const minClaimAmount = new anchor.BN(5000)
+    await program.methods.setMinClaimAmountCents(minClaimAmount).rpc()
+
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocol.minClaimAmountCents.eq(minClaimAmount))
+
+    let belowMinWallet = await createFundedSubmitter()
+
+    try
+    {
+      await submitClaim(belowMinWallet, minClaimAmount.sub(new anchor.BN(1)), new anchor.BN(0), new anchor.BN(0))
+      assert(false, "Submitting a claim below the min claim amount should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimAmountTooSmall")
+    }
+
+    let atMinWallet = await createFundedSubmitter()
+    await submitClaim(atMinWallet, minClaimAmount, new anchor.BN(0), new anchor.BN(0))
+
+    var claim = await program.account.claim.fetch(getClaimPDA(atMinWallet.publicKey))
+    assert(claim.claimAmount.eq(minClaimAmount))
+
+    //Put the min back to 0 so later claims of any amount go through
+    await program.methods.setMinClaimAmountCents(new anchor.BN(0)).rpc()
+  })
+
+  it("Emits The Ids Of Every Claim Closed By The Denial Hammer", async () => 
+  {
+    var claimPDAs = []
+    var claimIds = []
+
+    for(var i=0; i<2; i++)
+    {
+      let newWallet = await createFundedSubmitter()
+      await submitClaim(newWallet)
+
+      const claimPDA = getClaimPDA(newWallet.publicKey)
+      var claim = await program.account.claim.fetch(claimPDA)
+      claimPDAs.push(claimPDA)
+      claimIds.push(claim.id)
+    }
+
+    const signature = await program.methods.dropDenialHammer()
+    .remainingAccounts(claimPDAs.map(claimPDA => ({pubkey: claimPDA, isSigner: false, isWritable: true})))
+    .rpc({commitment: "confirmed"})
+
+    const transaction = await program.provider.connection.getTransaction(signature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+    const events = [...new anchor.EventParser(program.programId, program.coder).parseLogs(transaction.meta.logMessages)]
+    const denialHammerDroppedEvent = events.find(event => event.name == "denialHammerDropped")
+
+    assert(denialHammerDroppedEvent != undefined)
+    assert(denialHammerDroppedEvent.data.actor.equals(program.provider.publicKey))
+    assert(denialHammerDroppedEvent.data.closedClaimIds.length == 2)
+
+    for(var i=0; i<2; i++)
+    {
+      assert(denialHammerDroppedEvent.data.closedClaimIds.some((closedClaimId: anchor.BN) => closedClaimId.eq(claimIds[i])))
+      assert(await program.account.claim.fetchNullable(claimPDAs[i]) == null)
+    }
+  })
+
+  it("Deactivates A Submitter's Patients Down To Zero Without Underflowing", async () => 
+  {
+    let newWallet = await createFundedSubmitter()
+
+    await program.methods.setPatientFlag(patientIndex, false)
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    var submitter = await program.account.submitterAccount.fetch(getSubmitterPDA(newWallet.publicKey))
+    assert(submitter.activePatientCount == 0)
+    assert(submitter.patientCount == 1)
+
+    //Deactivating past zero must give a clean error instead of wrapping the counter
+    try
+    {
+      await program.methods.setPatientFlag(patientIndex, false)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+      assert(false, "Deactivating an already deactivated patient should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FlagSameState")
+    }
+
+    submitter = await program.account.submitterAccount.fetch(getSubmitterPDA(newWallet.publicKey))
+    assert(submitter.activePatientCount == 0)
+
+    await program.methods.setPatientFlag(patientIndex, true)
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    submitter = await program.account.submitterAccount.fetch(getSubmitterPDA(newWallet.publicKey))
+    assert(submitter.activePatientCount == 1)
+  })
+
+  //Only runs against a program built with the debug-compute feature, e.g. DEBUG_COMPUTE=1 anchor test -- --features debug-compute
+  it("Approves With Edits And Denies With All Records With Compute Unit Logging On", async function() 
+  {
+    if(process.env.DEBUG_COMPUTE == undefined)
+    {
+      this.skip()
+    }
+
+    const denialReason = "Testing"
+    let approveWallet = await createFundedSubmitter()
+    let denyWallet = await createFundedSubmitter()
+
+    for(const wallet of [approveWallet, denyWallet])
+    {
+      await submitClaim(wallet)
+      await program.methods.assignClaimToProcessor(wallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(wallet.publicKey).rpc()
+      await program.methods.createPatientRecord(wallet.publicKey).rpc()
+      await program.methods.createHospitalAndInsuranceCompanyRecords(wallet.publicKey).rpc()
+
+      if(wallet == approveWallet)
+      {
+        var signature = await program.methods.approveClaimWithEdits
+        (
+          wallet.publicKey, 
+          hospitalType,
+          hospitalLongitude,
+          hospitalLatitude,
+          hospitalName,
+          hospitalAddress,
+          hospitalCity,
+          hospitalZipCode,
+          hospitalPhoneNumber,
+          hospitalBillInvoiceNumber,
+          note144Characters,
+          claimAmount,
+          copayAmount,
+          deductibleAmount,
+          ailment,
+          insuranceCompanyName,
+          null
+        ).rpc({commitment: "confirmed"})
+      }
+      else
+      {
+        var signature = await program.methods.denyClaimWithAllRecords(wallet.publicKey, denialReason, null).rpc({commitment: "confirmed"})
+      }
+
+      const transaction = await program.provider.connection.getTransaction(signature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+      const computeUnitLogs = transaction.meta.logMessages.filter(log => log.includes("consumption:"))
+
+      //One log at the start of the instruction and one at the end
+      assert(transaction.meta.err == null)
+      assert(computeUnitLogs.length == 2)
+    }
+  })
+
+  it("Returns A Claim For Correction And Approves It After The Submitter Resubmits It", async () => 
+  {
+    const correctionReason = "Bill invoice number is missing a digit"
+    const correctedClaimAmount = new anchor.BN(12000)
+    let newWallet = await createFundedSubmitter()
+    const claimPDA = getClaimPDA(newWallet.publicKey)
+
+    await submitClaim(newWallet)
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.returnClaimForCorrection(newWallet.publicKey, correctionReason).rpc()
+
+    var claim = await program.account.claim.fetch(claimPDA)
+    assert(claim.status == 6) //Returned For Correction
+    assert(claim.correctionReason == correctionReason)
+    assert(claim.processorAddress.equals(anchor.web3.SystemProgram.programId))
+
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.isProcessingClaim == false)
+
+    //Processors can't pick the claim back up until the submitter fixes it
+    try
+    {
+      await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+      assert(false, "Assigning a claim returned for correction should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimReturnedForCorrection")
+    }
+
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, newWallet.publicKey)
+    const balanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+
+    await program.methods.resubmitCorrectedClaim
+    (
+      hospitalType,
+      hospitalName,
+      hospitalAddress,
+      hospitalCity,
+      hospitalZipCode,
+      hospitalPhoneNumber,
+      hospitalBillInvoiceNumber,
+      note144Characters,
+      correctedClaimAmount,
+      copayAmount,
+      deductibleAmount,
+      ailment,
+      insuranceCompanyName,
+      diagnosisCodes
+    )
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    //Resubmitting doesn't charge a new fee
+    const balanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    assert(balanceBefore == balanceAfter)
+
+    claim = await program.account.claim.fetch(claimPDA)
+    assert(claim.status == 0) //Pending
+    assert(claim.correctionReason == "")
+    assert(claim.claimAmount.eq(correctedClaimAmount))
+
+    //A pending claim can't be resubmitted again
+    try
+    {
+      await program.methods.resubmitCorrectedClaim
+      (
+        hospitalType,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        hospitalBillInvoiceNumber,
+        note144Characters,
+        correctedClaimAmount,
+        copayAmount,
+        deductibleAmount,
+        ailment,
+        insuranceCompanyName,
+        diagnosisCodes
+      )
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+      assert(false, "Resubmitting a claim that wasn't returned for correction should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimNotReturnedForCorrection")
+    }
+
+    await program.methods.assignClaimToProcessor(newWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(newWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(newWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(newWallet.publicKey).rpc()
+    await program.methods.approveClaim(newWallet.publicKey, null)
+    .accounts({submitterWallet: newWallet.publicKey})
+    .rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    var processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.status == 2) //Approved
+    assert(processedClaim.claimAmount.eq(correctedClaimAmount))
+  })
+
+  it("Charges The Patient Only And Full Appeal Fees On Their Respective Appeal Paths", async () => 
+  {
+    var feeConfig = await program.account.feeConfig.fetch(getFeeConfigPDA())
+    assert(feeConfig.patientOnlyAppealFeeCents.eq(new anchor.BN(4)))
+    assert(feeConfig.fullAppealFeeCents.eq(new anchor.BN(4)))
+
+    await program.methods.setAppealFees(new anchor.BN(5), new anchor.BN(9)).rpc()
+
+    let patientOnlyWallet = await createFundedSubmitter()
+    let fullWallet = await createFundedSubmitter()
+
+    await submitClaim(patientOnlyWallet)
+    await program.methods.assignClaimToProcessor(patientOnlyWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(patientOnlyWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(patientOnlyWallet.publicKey, "Testing Patient Only Appeal Fee", null).rpc()
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const patientOnlyProcessorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    await submitClaim(fullWallet)
+    await program.methods.assignClaimToProcessor(fullWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(fullWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(fullWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(fullWallet.publicKey).rpc()
+    await program.methods.denyClaimWithAllRecords(fullWallet.publicKey, "Testing Full Appeal Fee", null).rpc()
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const fullProcessorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    const patientOnlyFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, patientOnlyWallet.publicKey)
+    const fullFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, fullWallet.publicKey)
+    const patientOnlyBalanceBefore = (await getAccount(program.provider.connection, patientOnlyFeeAta.address)).amount
+    const fullBalanceBefore = (await getAccount(program.provider.connection, fullFeeAta.address)).amount
+
+    await program.methods.appealDeniedClaimWithOnlyPatientRecord(program.provider.publicKey, patientOnlyProcessorCountIndex, feeTokenMint, "Patient Only Appeal")
+    .accountsPartial({signer: patientOnlyWallet.publicKey, feeExemption: null})
+    .signers([patientOnlyWallet])
+    .rpc()
+
+    await program.methods.appealDeniedClaimWithAllRecords(program.provider.publicKey, fullProcessorCountIndex, feeTokenMint, "Full Appeal")
+    .accountsPartial({signer: fullWallet.publicKey, feeExemption: null})
+    .signers([fullWallet])
+    .rpc()
+
+    const patientOnlyBalanceAfter = (await getAccount(program.provider.connection, patientOnlyFeeAta.address)).amount
+    const fullBalanceAfter = (await getAccount(program.provider.connection, fullFeeAta.address)).amount
+
+    //5 and 9 cents with 6 decimals are 50000 and 90000 base units
+    assert(patientOnlyBalanceBefore - patientOnlyBalanceAfter == BigInt(50000))
+    assert(fullBalanceBefore - fullBalanceAfter == BigInt(90000))
+
+    await program.methods.setAppealFees(new anchor.BN(4), new anchor.BN(4)).rpc()
+  })
+
+  it("Tracks The Claims A Processor Holds Through Assign, Unassign, And Approve", async () => 
+  {
+    let firstWallet = await createFundedSubmitter()
+    let secondWallet = await createFundedSubmitter()
+
+    await submitClaim(firstWallet)
+    await submitClaim(secondWallet)
+
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.claimsInProgress.length == 0)
+
+    await program.methods.assignClaimToProcessor(firstWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(firstWallet.publicKey).rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.claimsInProgress.length == 1)
+    assert(processor.claimsInProgress[0].equals(firstWallet.publicKey))
+
+    await program.methods.unassignClaimFromProcessor(firstWallet.publicKey).rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.claimsInProgress.length == 0)
+
+    await program.methods.assignClaimToProcessor(secondWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(secondWallet.publicKey).rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.claimsInProgress.length == 1)
+    assert(processor.claimsInProgress[0].equals(secondWallet.publicKey))
+
+    await program.methods.createPatientRecord(secondWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(secondWallet.publicKey).rpc()
+    await program.methods.approveClaim(secondWallet.publicKey, null)
+    .accounts({submitterWallet: secondWallet.publicKey})
+    .rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.claimsInProgress.length == 0)
+  })
+
+  it("Rejects Creating A State Account From An Active Processor The Claim Isn't Assigned To", async () => 
+  {
+    const unusedStateIndex = 7
+    let submitterWallet = await createFundedSubmitter()
+    let otherProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(otherProcessorWallet.publicKey).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.createStateAccount(submitterWallet.publicKey, countryIndex, unusedStateIndex)
+      .accounts({signer: otherProcessorWallet.publicKey})
+      .signers([otherProcessorWallet])
+      .rpc()
+      assert(false, "A processor the claim isn't assigned to shouldn't be able to create a state account for it")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotTheProcessor")
+    }
+
+    assert(await program.account.stateAccount.fetchNullable(getStatePDA(countryIndex, unusedStateIndex)) == null)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Reserves Record Slots And Lets A Retried Populate Succeed", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.reserveRecordSlots(submitterWallet.publicKey).rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.areRecordSlotsReserved == true)
+    assert(claim.isPatientRecordCreated == false)
+
+    await program.methods.populateReservedRecords(submitterWallet.publicKey).rpc()
+
+    //Resending the populate must not error or double count
+    await program.methods.populateReservedRecords(submitterWallet.publicKey).rpc()
+
+    claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.isPatientRecordCreated == true)
+    assert(claim.isHospitalRecordCreated == true)
+    assert(claim.isInsuranceCompanyRecordCreated == true)
+
+    try
+    {
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      assert(false, "Records for a claim with reserved slots shouldn't be created twice")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "RecordAlreadyCreated")
+    }
+
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+  })
+
+  it("Carries Multiple Diagnosis Codes Through To The Processed Claim", async () => 
+  {
+    const threeDiagnosisCodes = ["J45.909", "E11.9", "I10"]
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, hospitalType, threeDiagnosisCodes)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.ailment == ailment)
+    assert(claim.diagnosisCodes.length == 3)
+
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.diagnosisCodes.length == 3)
+    for (let i = 0; i < threeDiagnosisCodes.length; i++)
+    {
+      assert(processedClaim.diagnosisCodes[i] == threeDiagnosisCodes[i])
+    }
+
+    let secondSubmitterWallet = await createFundedSubmitter()
+
+    try
+    {
+      await submitClaim(secondSubmitterWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, hospitalType, ["A00", "A01", "A02", "A03", "A04", "A05"])
+      assert(false, "Submitting more than 5 diagnosis codes should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "TooManyDiagnosisCodes")
+    }
+
+    try
+    {
+      await submitClaim(secondSubmitterWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, hospitalType, ["A".repeat(46)])
+      assert(false, "Submitting a diagnosis code longer than 45 characters should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "DiagnosisCodeTooLong")
+    }
+  })
+
+  it("Rejects Setting The Fees Enabled Flag From A Non Treasurer", async () => 
+  {
+    try
+    {
+      await program.methods.setFeesEnabledFlag(false).rpc()
+      assert(false, "Only the treasurer should be able to turn fee collection off")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotTreasurer")
+    }
+
+    const feeConfig = await program.account.feeConfig.fetch(getFeeConfigPDA())
+    assert(feeConfig.feesEnabled == true)
+  })
+
+  //Only runs when the provider wallet is the treasurer, e.g. against a build with INITIAL_TREASURER_ADDRESS pointed at it
+  it("Submits A Claim Without Transferring A Fee While Fees Are Disabled", async function() 
+  {
+    if(program.provider.publicKey.equals(treasurerAddress) == false)
+    {
+      this.skip()
+    }
+
+    let submitterWallet = await createFundedSubmitter()
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, submitterWallet.publicKey)
+    const treasurerFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, treasurerAddress)
+    const userBalanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    const treasurerBalanceBefore = (await getAccount(program.provider.connection, treasurerFeeAta.address)).amount
+
+    await program.methods.setFeesEnabledFlag(false).rpc()
+    await submitClaim(submitterWallet)
+    await program.methods.setFeesEnabledFlag(true).rpc()
+
+    const userBalanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    const treasurerBalanceAfter = (await getAccount(program.provider.connection, treasurerFeeAta.address)).amount
+    assert(userBalanceBefore == userBalanceAfter)
+    assert(treasurerBalanceBefore == treasurerBalanceAfter)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.submitterAddress.equals(submitterWallet.publicKey))
+  })
+
+  //Only runs when the provider wallet is the treasurer, e.g. against a build with INITIAL_TREASURER_ADDRESS pointed at it
+  it("Reports The Submitter In The Fee Waived Event When A Delegate Submits While Fees Are Disabled", async function() 
+  {
+    if(program.provider.publicKey.equals(treasurerAddress) == false)
+    {
+      this.skip()
+    }
+
+    let submitterWallet = await createFundedSubmitter()
+    let delegateWallet = await createFundedSubmitter()
+
+    await program.methods.createSubmitterDelegate(delegateWallet.publicKey)
+    .accounts({signer: submitterWallet.publicKey})
+    .signers([submitterWallet])
+    .rpc()
+
+    await program.methods.setFeesEnabledFlag(false).rpc()
+    const signature = await submitClaimAsDelegate(delegateWallet, submitterWallet.publicKey)
+    await program.methods.setFeesEnabledFlag(true).rpc()
+
+    const transaction = await program.provider.connection.getTransaction(signature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+    const events = [...new anchor.EventParser(program.programId, program.coder).parseLogs(transaction.meta.logMessages)]
+    const feeWaivedEvent = events.find(event => event.name == "feeWaived")
+
+    assert(feeWaivedEvent != undefined)
+    assert(feeWaivedEvent.data.submitterAddress.equals(submitterWallet.publicKey))
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+  })
+
+  it("Rejects Creating A Patient Record When The Record Count Would Overflow", async () => 
+  {
+    const maxPatientRecordCount = 4294967295 //u32 max
+    let submitterWallet = await createFundedSubmitter()
+
+    await program.methods.correctPatientRecordCount(submitterWallet.publicKey, patientIndex, maxPatientRecordCount).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      assert(false, "Creating a patient record past the max record count should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "RecordCountOverflow")
+    }
+
+    const patient = await program.account.patientAccount.fetch(getPatientPDA(submitterWallet.publicKey, patientIndex))
+    assert(patient.recordCount == maxPatientRecordCount)
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.isPatientRecordCreated == false)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Lists The Claims Assigned To A Processor And Clears Them On Approval", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    var processorClaimIndex = await program.account.processorClaimIndex.fetch(getProcessorClaimIndexPDA(program.provider.publicKey))
+    assert(processorClaimIndex.processorAddress.equals(program.provider.publicKey))
+    assert(processorClaimIndex.submitterAddresses.length == 1)
+    assert(processorClaimIndex.submitterAddresses[0].equals(submitterWallet.publicKey))
+
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    processorClaimIndex = await program.account.processorClaimIndex.fetch(getProcessorClaimIndexPDA(program.provider.publicKey))
+    assert(processorClaimIndex.submitterAddresses.length == 0)
+  })
+
+  //There's no patient transfer yet, so the closest case is a submitter pointing at another submitter's patient
+  it("Rejects Submitting A Claim For A Patient That Belongs To Another Submitter", async () => 
+  {
+    let patientOwnerWallet = await createFundedSubmitter()
+    let otherSubmitterWallet = await createFundedSubmitter()
+
+    try
+    {
+      await program.methods.submitClaimToQueue
+      (
+        patientIndex,
+        feeTokenMint,
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        hospitalType,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        hospitalBillInvoiceNumber,
+        note144Characters,
+        claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
+        ailment,
+        insuranceCompanyIndex,
+        insuranceCompanyName,
+        diagnosisCodes
+      )
+      .accountsPartial({signer: otherSubmitterWallet.publicKey, patient: getPatientPDA(patientOwnerWallet.publicKey, patientIndex), feeExemption: null})
+      .signers([otherSubmitterWallet])
+      .rpc()
+      assert(false, "Submitting a claim for another submitter's patient should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ConstraintSeeds" || error.error.errorCode.code == "NotSubmitter")
+    }
+
+    assert(await program.account.claim.fetchNullable(getClaimPDA(otherSubmitterWallet.publicKey)) == null)
+  })
+
+  it("Rejects Reassigning A Claim To A Processor Still In Their Assignment Cooldown", async () => 
+  {
+    const assignmentCooldownSeconds = new anchor.BN(60)
+    let submitterWallet = await createFundedSubmitter()
+
+    await program.methods.setAssignmentCooldownSeconds(assignmentCooldownSeconds).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.lastUnassignedTime.gt(new anchor.BN(0)))
+
+    try
+    {
+      await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+      assert(false, "Picking a claim right back up during the cooldown should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "AssignmentCooldownActive")
+    }
+
+    //Turning the cooldown back off lets the processor pick the claim up again
+    await program.methods.setAssignmentCooldownSeconds(new anchor.BN(0)).rpc()
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Builds A Deterministic Patient Manifest From The Patient's Records", async () => 
+  {
+    const deniedStatus = 3
+    const approvedStatus = 2
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(submitterWallet.publicKey, "Testing", null).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const patientIndexBytes = new anchor.BN(patientIndex).toArrayLike(Buffer, 'le', 1)
+    const patientRecordAccounts = [0, 1].map(recordIndex => 
+    ({
+      pubkey: anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("patientRecord"), submitterWallet.publicKey.toBuffer(), patientIndexBytes, new anchor.BN(recordIndex).toArrayLike(Buffer, 'le', 4)], program.programId)[0],
+      isSigner: false,
+      isWritable: false
+    }))
+
+    const buildManifest = async () => Buffer.from(await program.methods.buildPatientManifest(patientIndex)
+    .accounts({signer: submitterWallet.publicKey})
+    .remainingAccounts(patientRecordAccounts)
+    .signers([submitterWallet])
+    .view())
+
+    //Record IDs start at 1, each step hashes the running manifest, the record ID, then the status
+    var expectedManifest = Buffer.alloc(32)
+    for (const [recordId, status] of [[1, deniedStatus], [2, approvedStatus]])
+    {
+      expectedManifest = createHash("sha256").update(Buffer.concat([expectedManifest, new anchor.BN(recordId).toArrayLike(Buffer, 'le', 4), Buffer.from([status])])).digest()
+    }
+
+    const firstManifest = await buildManifest()
+    const secondManifest = await buildManifest()
+    assert(firstManifest.equals(secondManifest))
+    assert(firstManifest.equals(expectedManifest))
+
+    try
+    {
+      await program.methods.buildPatientManifest(patientIndex)
+      .accounts({signer: submitterWallet.publicKey})
+      .remainingAccounts(patientRecordAccounts.slice(0, 1))
+      .signers([submitterWallet])
+      .view()
+      assert(false, "Building a manifest without every record should have failed")
+    }
+    catch(error)
+    {
+      assert(error.message.includes("InvalidBatchSize"))
+    }
+  })
+
+  //The queue count can reach the limit but never pass it, the close paths rely on that when they decrement it
+  it("Keeps The Claim Queue Count At Or Below The Queue Size Limit", async () => 
+  {
+    let lastFittingWallet = await createFundedSubmitter()
+    let overflowWallet = await createFundedSubmitter()
+
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const originalQueueSizeLimit = claimQueue.queueSizeLimit
+    await program.methods.editClaimQueueSize(claimQueue.currentClaimQueueCount + 1).rpc()
+
+    await submitClaim(lastFittingWallet)
+
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.currentClaimQueueCount == claimQueue.queueSizeLimit)
+
+    try
+    {
+      await submitClaim(overflowWallet)
+      assert(false, "Submitting past the queue size limit should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "TooManyClaimsInQueue")
+    }
+
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.currentClaimQueueCount <= claimQueue.queueSizeLimit)
+
+    await program.methods.editClaimQueueSize(originalQueueSizeLimit).rpc()
+  })
+
+  it("Marks An Approved Claim As Settled And Rejects Settling A Denied Claim", async () => 
+  {
+    const settledStatus = 7
+    let approvedWallet = await createFundedSubmitter()
+    let deniedWallet = await createFundedSubmitter()
+
+    await submitClaim(approvedWallet)
+    await program.methods.assignClaimToProcessor(approvedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(approvedWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(approvedWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(approvedWallet.publicKey).rpc()
+    await program.methods.approveClaim(approvedWallet.publicKey, null)
+    .accounts({submitterWallet: approvedWallet.publicKey})
+    .rpc()
+
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const approvedProcessorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    await program.methods.markClaimSettled(program.provider.publicKey, approvedProcessorCountIndex)
+    .accounts({signer: approvedWallet.publicKey})
+    .signers([approvedWallet])
+    .rpc()
+
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, approvedProcessorCountIndex))
+    assert(processedClaim.status == settledStatus)
+    assert(processedClaim.settledTime.gt(new anchor.BN(0)))
+
+    await submitClaim(deniedWallet)
+    await program.methods.assignClaimToProcessor(deniedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(deniedWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(deniedWallet.publicKey, "Testing", null).rpc()
+
+    processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const deniedProcessorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    try
+    {
+      await program.methods.markClaimSettled(program.provider.publicKey, deniedProcessorCountIndex)
+      .accounts({signer: deniedWallet.publicKey})
+      .signers([deniedWallet])
+      .rpc()
+      assert(false, "Settling a denied claim should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimNotApproved")
+    }
+  })
+
+  //Rolling into the next 24 hour window needs a warped clock, which the local validator these tests run against doesn't support
+  it("Rejects Resolving More Claims Than The Max Daily Processing", async () => 
+  {
+    let cappedProcessorWallet = await createFundedSubmitter()
+    let firstSubmitterWallet = await createFundedSubmitter()
+    let secondSubmitterWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(cappedProcessorWallet.publicKey).rpc()
+    await program.methods.setMaxDailyProcessing(1).rpc()
+
+    await submitClaim(firstSubmitterWallet)
+    await submitClaim(secondSubmitterWallet)
+
+    for (const submitterWallet of [firstSubmitterWallet, secondSubmitterWallet])
+    {
+      await program.methods.assignClaimToProcessor(submitterWallet.publicKey)
+      .accounts({signer: cappedProcessorWallet.publicKey})
+      .signers([cappedProcessorWallet])
+      .rpc()
+
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey)
+      .accounts({signer: cappedProcessorWallet.publicKey})
+      .signers([cappedProcessorWallet])
+      .rpc()
+
+      try
+      {
+        await program.methods.createPatientRecordAndDenyClaim(submitterWallet.publicKey, "Testing", null)
+        .accounts({signer: cappedProcessorWallet.publicKey})
+        .signers([cappedProcessorWallet])
+        .rpc()
+        assert(submitterWallet == firstSubmitterWallet, "Resolving a second claim in the same day should have failed")
+      }
+      catch(error)
+      {
+        assert(error.error.errorCode.code == "DailyLimitReached")
+      }
+    }
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(cappedProcessorWallet.publicKey))
+    assert(processor.processedTodayCount == 1)
+    assert(processor.dayWindowStart.gt(new anchor.BN(0)))
+
+    await program.methods.setMaxDailyProcessing(0).rpc()
+    await program.methods.unassignClaimFromProcessor(secondSubmitterWallet.publicKey).rpc()
+  })
+
+  //There's no client side way to corrupt a hospital's stats, so this checks reconciling a fresh hospital lands on what its records say
+  it("Reconciles A Hospital's Stats From Its Records", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createHospital
+    (
+      submitterWallet.publicKey,
+      countryIndex, 
+      stateIndex, 
+      hospitalType,
+      hospitalLongitude,
+      hospitalLatitude,
+      hospitalName, 
+      hospitalAddress,
+      hospitalCity,
+      hospitalZipCode,
+      hospitalPhoneNumber,
+      note144Characters).rpc()
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    const newHospitalIndex = claim.hospitalIndex
+
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const hospitalRecordPDA = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("hospitalRecord"),
+      new anchor.BN(countryIndex).toArrayLike(Buffer, 'le', 2),
+      new anchor.BN(stateIndex).toArrayLike(Buffer, 'le', 4),
+      new anchor.BN(newHospitalIndex).toArrayLike(Buffer, 'le', 4),
+      new anchor.BN(0).toArrayLike(Buffer, 'le', 8)
+    ], program.programId)[0]
+
+    await program.methods.reconcileHospitalStats(countryIndex, stateIndex, newHospitalIndex)
+    .remainingAccounts([{pubkey: hospitalRecordPDA, isSigner: false, isWritable: false}])
+    .rpc()
+
+    const hospitalPDA = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("hospital"),
+      new anchor.BN(countryIndex).toArrayLike(Buffer, 'le', 2),
+      new anchor.BN(stateIndex).toArrayLike(Buffer, 'le', 4),
+      new anchor.BN(newHospitalIndex).toArrayLike(Buffer, 'le', 4)
+    ], program.programId)[0]
+    const hospital = await program.account.hospital.fetch(hospitalPDA)
+    assert(hospital.approvedClaimCount.eq(new anchor.BN(1)))
+    assert(hospital.approvedClaimAmount.eq(claim.claimAmount))
+    assert(hospital.deniedClaimCount.eq(new anchor.BN(0)))
+
+    try
+    {
+      await program.methods.reconcileHospitalStats(countryIndex, stateIndex, newHospitalIndex).rpc()
+      assert(false, "Reconciling without every hospital record should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "InvalidBatchSize")
+    }
+  })
+
+  it("Rejects Approving A Flagged Claim From A Processor That Isn't A Super Admin", async () => 
+  {
+    const flagReason = "Invoice number doesn't match the hospital's format"
+    let flaggingProcessorWallet = await createFundedSubmitter()
+    let submitterWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(flaggingProcessorWallet.publicKey).rpc()
+
+    await submitClaim(submitterWallet)
+
+    const processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey)
+    .accounts({signer: flaggingProcessorWallet.publicKey})
+    .signers([flaggingProcessorWallet])
+    .rpc()
+
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey)
+    .accounts({signer: flaggingProcessorWallet.publicKey})
+    .signers([flaggingProcessorWallet])
+    .rpc()
+
+    await program.methods.flagClaim(submitterWallet.publicKey, flagReason)
+    .accounts({signer: flaggingProcessorWallet.publicKey})
+    .signers([flaggingProcessorWallet])
+    .rpc()
+
+    await program.methods.createPatientRecord(submitterWallet.publicKey)
+    .accounts({signer: flaggingProcessorWallet.publicKey})
+    .signers([flaggingProcessorWallet])
+    .rpc()
+
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey)
+    .accounts({signer: flaggingProcessorWallet.publicKey})
+    .signers([flaggingProcessorWallet])
+    .rpc()
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.isFlaggedForReview == true)
+    assert(claim.flagReason == flagReason)
+
+    const processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    assert(processorStatsAfter.flaggedClaimCount.eq(processorStatsBefore.flaggedClaimCount.add(new anchor.BN(1))))
+
+    try
+    {
+      await program.methods.approveClaim(submitterWallet.publicKey, null)
+      .accounts({signer: flaggingProcessorWallet.publicKey, submitterWallet: submitterWallet.publicKey})
+      .signers([flaggingProcessorWallet])
+      .rpc()
+      assert(false, "A processor that isn't a super admin shouldn't be able to approve a flagged claim")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotSuperAdmin")
+    }
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Stores The EOB Hash On The Processed Claim And Insurance Company Record", async () => 
+  {
+    const eobHash = [...createHash("sha256").update("EOB 12345").digest()]
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    await program.methods.approveClaim(submitterWallet.publicKey, eobHash)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(Buffer.from(processedClaim.eobHash).equals(Buffer.from(eobHash)))
+
+    const insuranceCompanyRecordPDA = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("insuranceCompanyRecord"),
+      new anchor.BN(claim.insuranceCompanyIndex).toTwos(16).toArrayLike(Buffer, 'le', 2),
+      new anchor.BN(claim.insuranceCompanyRecordIndex).toArrayLike(Buffer, 'le', 8)
+    ], program.programId)[0]
+    const insuranceCompanyRecord = await program.account.insuranceCompanyRecord.fetch(insuranceCompanyRecordPDA)
+    assert(Buffer.from(insuranceCompanyRecord.eobHash).equals(Buffer.from(eobHash)))
+  })
+
+  it("Refuses To Clear A Processor's State While They're Still On A Live Processing Claim", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.setProcessorToNotProcessingClaimState(program.provider.publicKey).rpc()
+      assert(false, "Clearing a processor still on a live claim should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimStillBeingProcessed")
+    }
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processor.isProcessingClaim == true)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.status == 1) //Processing
+    assert(claim.processorAddress.equals(program.provider.publicKey))
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Keeps The Claim Queue Size Within The Min And Max Queue Size", async () => 
+  {
+    const minQueueSize = 5
+    const maxQueueSize = 500
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const originalQueueSizeLimit = claimQueue.queueSizeLimit
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    const originalMinQueueSize = m4aProtocol.minQueueSize
+    const originalMaxQueueSize = m4aProtocol.maxQueueSize
+
+    await program.methods.setQueueSizeBounds(minQueueSize, maxQueueSize).rpc()
+
+    m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocol.minQueueSize == minQueueSize)
+    assert(m4aProtocol.maxQueueSize == maxQueueSize)
+
+    await program.methods.editClaimQueueSize(minQueueSize).rpc()
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.queueSizeLimit == minQueueSize)
+
+    await program.methods.editClaimQueueSize(maxQueueSize).rpc()
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.queueSizeLimit == maxQueueSize)
+
+    try
+    {
+      await program.methods.editClaimQueueSize(minQueueSize - 1).rpc()
+      assert(false, "A queue size below the min should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "QueueSizeOutOfRange")
+    }
+
+    try
+    {
+      await program.methods.editClaimQueueSize(maxQueueSize + 1).rpc()
+      assert(false, "A queue size above the max should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "QueueSizeOutOfRange")
+    }
+
+    try
+    {
+      await program.methods.setQueueSizeBounds(0, maxQueueSize).rpc()
+      assert(false, "A min queue size of 0 should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "QueueSizeOutOfRange")
+    }
+
+    await program.methods.setQueueSizeBounds(originalMinQueueSize, originalMaxQueueSize).rpc()
+    await program.methods.editClaimQueueSize(originalQueueSizeLimit).rpc()
+  })
+
+  it("Rejects Approving A Claim That Crosses The Patient's Lifetime Benefit Cap", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+    const patientPDA = getPatientPDA(submitterWallet.publicKey, patientIndex)
+
+    //Cap the patient one cent below the claim amount
+    await program.methods.setLifetimeBenefitCap(submitterWallet.publicKey, patientIndex, claimAmount.sub(new anchor.BN(1)))
+    .accountsPartial({processor: getProcessorPDA(program.provider.publicKey)})
+    .rpc()
+
+    var patient = await program.account.patientAccount.fetch(patientPDA)
+    assert(patient.lifetimeBenefitCapCents.eq(claimAmount.sub(new anchor.BN(1)))
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.approveClaim(submitterWallet.publicKey, null)
+      .accounts({submitterWallet: submitterWallet.publicKey})
+      .rpc()
+      assert(false, "Approving past the lifetime benefit cap should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "LifetimeCapExceeded")
+    }
+
+    //Landing exactly on the cap is allowed
+    await program.methods.setLifetimeBenefitCap(submitterWallet.publicKey, patientIndex, claimAmount)
+    .accountsPartial({processor: getProcessorPDA(program.provider.publicKey)})
+    .rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    patient = await program.account.patientAccount.fetch(patientPDA)
+    assert(patient.approvedClaimAmount.eq(claimAmount))
+  })
+
+  it("Deactivates Every Hospital In A State And Takes Them Out Of The Type Counts", async () => 
+  {
+    const reimportStateIndex = 11
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createStateAccount(submitterWallet.publicKey, countryIndex, reimportStateIndex).rpc()
+
+    //Two hospitals of the same type in a state no other test uses
+    for (let i = 0; i < 2; i++)
+    {
+      await program.methods.createHospital
+      (
+        submitterWallet.publicKey,
+        countryIndex, 
+        reimportStateIndex, 
+        hospitalType,
+        hospitalLongitude,
+        hospitalLatitude,
+        hospitalName, 
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        note144Characters).rpc()
+    }
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+
+    const hospitalStatsPDA = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("hospitalStats")], program.programId)[0]
+    const hospitalStatsBefore = await program.account.hospitalStats.fetch(hospitalStatsPDA)
+    var state = await program.account.stateAccount.fetch(getStatePDA(countryIndex, reimportStateIndex))
+    assert(state.hospitalCount == 2)
+    assert(state.generalHospitalCount == 2)
+
+    await program.methods.deactivateStateHospitals(countryIndex, reimportStateIndex)
+    .remainingAccounts(
+    [
+      {pubkey: getHospitalPDA(countryIndex, reimportStateIndex, 0), isSigner: false, isWritable: true},
+      {pubkey: getHospitalPDA(countryIndex, reimportStateIndex, 1), isSigner: false, isWritable: true}
+    ])
+    .rpc()
+
+    const firstHospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, reimportStateIndex, 0))
+    const secondHospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, reimportStateIndex, 1))
+    assert(firstHospital.isActive == false)
+    assert(secondHospital.isActive == false)
+
+    //The hospital count is the index for the next hospital so it stays put, only the type counts drop
+    state = await program.account.stateAccount.fetch(getStatePDA(countryIndex, reimportStateIndex))
+    assert(state.hospitalCount == 2)
+    assert(state.generalHospitalCount == 0)
+
+    const hospitalStatsAfter = await program.account.hospitalStats.fetch(hospitalStatsPDA)
+    assert(hospitalStatsAfter.hospitalCount == hospitalStatsBefore.hospitalCount)
+    assert(hospitalStatsAfter.generalHospitalCount == hospitalStatsBefore.generalHospitalCount - 2)
+  })
+
+  it("Records The Insurance Covered Amount On Approval", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.insuranceCoveredAmount.eq(claimAmount.sub(copayAmount).sub(deductibleAmount)))
+  })
+
+  it("Refunds The Claim Rent To The Submitter On Approval", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    const claimRent = await program.provider.connection.getBalance(getClaimPDA(submitterWallet.publicKey))
+    const submitterBalanceBefore = await program.provider.connection.getBalance(submitterWallet.publicKey)
+
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    //The processor pays the transaction fee, so the submitter gets the whole claim rent back
+    const submitterBalanceAfter = await program.provider.connection.getBalance(submitterWallet.publicKey)
+    assert(submitterBalanceAfter == submitterBalanceBefore + claimRent)
+
+    //Claims approved in a batch refund their submitters the same way
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, true).rpc()
+
+    let batchSubmitterWallet = await createFundedSubmitter()
+    await submitClaim(batchSubmitterWallet)
+    await program.methods.assignClaimToProcessor(batchSubmitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(batchSubmitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(batchSubmitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(batchSubmitterWallet.publicKey).rpc()
+
+    const batchClaimPDA = getClaimPDA(batchSubmitterWallet.publicKey)
+    const batchClaim = await program.account.claim.fetch(batchClaimPDA)
+    const batchClaimRent = await program.provider.connection.getBalance(batchClaimPDA)
+    const batchSubmitterBalanceBefore = await program.provider.connection.getBalance(batchSubmitterWallet.publicKey)
+    const superProcessor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+
+    await program.methods.approveClaimsBatch()
+    .remainingAccounts(getBatchApprovalAccounts(batchClaimPDA, batchClaim, superProcessor.processedClaimCount))
+    .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({units: 1400000})])
+    .rpc()
+
+    const batchSubmitterBalanceAfter = await program.provider.connection.getBalance(batchSubmitterWallet.publicKey)
+    assert(batchSubmitterBalanceAfter == batchSubmitterBalanceBefore + batchClaimRent)
+
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, false).rpc()
+  })
+
+  it("Returns The Claim A Processor Is Currently Working", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    var currentClaim = await program.methods.getProcessorCurrentClaim(program.provider.publicKey).view()
+    assert(currentClaim.submitterAddress.equals(submitterWallet.publicKey))
+    assert(currentClaim.isProcessingClaim == true)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+
+    currentClaim = await program.methods.getProcessorCurrentClaim(program.provider.publicKey).view()
+    assert(currentClaim.isProcessingClaim == false)
+  })
+
+  it("Only Refunds The Fee When A Claim Is Max Denied For A Protocol Error", async () => 
+  {
+    const submitterErrorCode = 0
+    const protocolErrorCode = 1
+    let submitterErrorWallet = await createFundedSubmitter()
+    let protocolErrorWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterErrorWallet)
+    await submitClaim(protocolErrorWallet)
+
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, submitterErrorWallet.publicKey)
+    const userBalanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+
+    await program.methods.maxDenyPendingClaim(submitterErrorWallet.publicKey, submitterErrorCode).rpc()
+
+    const userBalanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    assert(userBalanceBefore == userBalanceAfter)
+
+    //A protocol error refund can't go out without the Treasurer and the fee token accounts
+    try
+    {
+      await program.methods.maxDenyPendingClaim(protocolErrorWallet.publicKey, protocolErrorCode).rpc()
+      assert(false, "Max denying for a protocol error without the refund accounts should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "RefundAccountsMissing")
+    }
+
+    await program.methods.maxDenyPendingClaim(protocolErrorWallet.publicKey, submitterErrorCode).rpc()
+  })
+
+  it("Refunds Nothing When A Fee Exempt Claim Is Max Denied For A Protocol Error", async () => 
+  {
+    const protocolErrorCode = 1
+    let exemptWallet = await createFundedSubmitter()
+    const feeExemptionPDA = getFeeExemptionPDA(exemptWallet.publicKey)
+
+    await program.methods.grantFeeExemption(exemptWallet.publicKey).rpc()
+    await submitClaim(exemptWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, feeExemptionPDA)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(exemptWallet.publicKey))
+    assert(claim.feeAmountPaid.eq(new anchor.BN(0)))
+
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, exemptWallet.publicKey)
+    const userBalanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+
+    //The claim paid no fee, so the refund accounts aren't needed and nothing is sent back
+    await program.methods.maxDenyPendingClaim(exemptWallet.publicKey, protocolErrorCode).rpc()
+
+    const userBalanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    assert(userBalanceBefore == userBalanceAfter)
+    assert(await program.account.claim.fetchNullable(getClaimPDA(exemptWallet.publicKey)) == null)
+  })
+
+  //Only runs when the provider wallet is the treasurer, e.g. against a build with INITIAL_TREASURER_ADDRESS pointed at it
+  it("Refunds The Fee From The Treasurer When A Claim Is Max Denied For A Protocol Error", async function() 
+  {
+    if(program.provider.publicKey.equals(treasurerAddress) == false)
+    {
+      this.skip()
+    }
+
+    const protocolErrorCode = 1
+    let submitterWallet = await createFundedSubmitter()
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, submitterWallet.publicKey)
+    const treasurerFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, treasurerAddress)
+
+    await submitClaim(submitterWallet)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    const userBalanceBefore = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, protocolErrorCode)
+    .accounts(
+    {
+      treasurerSigner: program.provider.publicKey,
+      treasurerFeeAta: treasurerFeeAta.address,
+      submitterFeeAta: userFeeAta.address
+    })
+    .rpc()
+
+    //The refund is exactly what the claim paid, in the token it paid with
+    const userBalanceAfter = (await getAccount(program.provider.connection, userFeeAta.address)).amount
+    assert(claim.feeMint.equals(feeTokenMint))
+    assert(userBalanceAfter == userBalanceBefore + BigInt(claim.feeAmountPaid.toString()))
+  })
+
+  it("Traces A Hospital Record Back To Its Processed Claim", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const hospitalRecordPDA = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("hospitalRecord"),
+      new anchor.BN(claim.countryIndex).toArrayLike(Buffer, 'le', 2),
+      new anchor.BN(claim.stateIndex).toArrayLike(Buffer, 'le', 4),
+      new anchor.BN(claim.hospitalIndex).toTwos(32).toArrayLike(Buffer, 'le', 4),
+      new anchor.BN(claim.hospitalRecordIndex).toArrayLike(Buffer, 'le', 8)
+    ], program.programId)[0]
+    const hospitalRecord = await program.account.hospitalRecord.fetch(hospitalRecordPDA)
+
+    //The hospital record's processor and processor count index lead to the processed claim
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(hospitalRecord.processorAddress, hospitalRecord.processorCountIndex))
+    assert(hospitalRecord.processedClaimId.eq(processedClaim.processedClaimId))
+    assert(hospitalRecord.submitterAddress.equals(processedClaim.submitterAddress))
+    assert(hospitalRecord.patientIndex == processedClaim.patientIndex)
+    assert(hospitalRecord.claimId.eq(processedClaim.claimId))
+  })
+
+  it("Rejects Appealing A Claim That Was Already Approved By An Undeny", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.denyClaimWithAllRecords(submitterWallet.publicKey, "Testing Stale Appeal", null).rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+
+    await program.methods.undenyClaimWithAllRecords(program.provider.publicKey, processorCountIndex).rpc()
+
+    //A stale client that still thinks the claim is denied tries to appeal it
+    try
+    {
+      await program.methods.appealDeniedClaimWithAllRecords(program.provider.publicKey, processorCountIndex, feeTokenMint, "Stale Appeal")
+      .accountsPartial({signer: submitterWallet.publicKey, feeExemption: null})
+      .signers([submitterWallet])
+      .rpc()
+      assert(false, "Appealing an approved claim should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimAlreadyResolved")
+    }
+  })
+
+  it("Adds Fee Token Entries In A Batch And Fails The Whole Batch On A Duplicate", async () => 
+  {
+    var mints = []
+    for (let i = 0; i < 3; i++)
+    {
+      mints.push(await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals))
+    }
+
+    await program.methods.addFeeTokenEntriesBatch(mints.map(mint => ({tokenMintAddress: mint, decimalAmount: feeTokenDecimals})))
+    .remainingAccounts(mints.map(mint => ({pubkey: getFeeTokenEntryPDA(mint), isSigner: false, isWritable: true})))
+    .rpc()
+
+    for (const mint of mints)
+    {
+      const feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(mint))
+      assert(feeTokenEntry.tokenMintAddress.equals(mint))
+      assert(feeTokenEntry.decimalAmount == feeTokenDecimals)
+    }
+
+    //The new mint would be fine on its own, but the already added mint fails the batch so neither goes in
+    const newMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+    const duplicateBatch = [newMint, mints[0]]
+
+    try
+    {
+      await program.methods.addFeeTokenEntriesBatch(duplicateBatch.map(mint => ({tokenMintAddress: mint, decimalAmount: feeTokenDecimals})))
+      .remainingAccounts(duplicateBatch.map(mint => ({pubkey: getFeeTokenEntryPDA(mint), isSigner: false, isWritable: true})))
+      .rpc()
+      assert(false, "A batch with an already added mint should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FeeTokenEntryAlreadyExists")
+    }
+
+    const newFeeTokenEntry = await program.provider.connection.getAccountInfo(getFeeTokenEntryPDA(newMint))
+    assert(newFeeTokenEntry == null)
+  })
+
+  it("Requires The Assigned Processor To Acknowledge A Claim Before Working It", async () => 
+  {
+    const assignedStatus = 8
+    const processingStatus = 1
+    let submitterWallet = await createFundedSubmitter()
+    let otherProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(otherProcessorWallet.publicKey).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.status == assignedStatus)
+
+    try
+    {
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      assert(false, "Creating a record before acknowledging the claim should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimNotBeingProcessed")
+    }
+
+    try
+    {
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey)
+      .accounts({signer: otherProcessorWallet.publicKey})
+      .signers([otherProcessorWallet])
+      .rpc()
+      assert(false, "Only the assigned processor should be able to acknowledge the claim")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotTheProcessor")
+    }
+
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.status == processingStatus)
+
+    try
+    {
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+      assert(false, "Acknowledging a claim that's already being processed should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimNotAwaitingAcknowledgement")
+    }
+
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+  })
+
+  it("Blocks Processors From Creating Reserved Insurance Company Indexes", async () => 
+  {
+    const reservedInsuranceCompanyIndex = 5
+    let submitterWallet = await createFundedSubmitter()
+    let processorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(processorWallet.publicKey).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey)
+    .accounts({signer: processorWallet.publicKey})
+    .signers([processorWallet])
+    .rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey)
+    .accounts({signer: processorWallet.publicKey})
+    .signers([processorWallet])
+    .rpc()
+
+    try
+    {
+      await program.methods.createInsuranceCompany(submitterWallet.publicKey, reservedInsuranceCompanyIndex, insuranceCompanyName, note144Characters)
+      .accounts({signer: processorWallet.publicKey})
+      .signers([processorWallet])
+      .rpc()
+      assert(false, "A processor shouldn't be able to create a reserved insurance company index")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ReservedInsuranceIndex")
+    }
+
+    assert(await program.account.insuranceCompany.fetchNullable(getInsuranceCompanyPDA(reservedInsuranceCompanyIndex)) == null)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Takes Two Stats Snapshots And Reads Both", async () => 
+  {
+    var processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    const firstEpoch = processorStats.statsSnapshotCount
+    const secondEpoch = firstEpoch.add(new anchor.BN(1))
+
+    await program.methods.takeStatsSnapshot()
+    .accountsPartial({statsSnapshot: getStatsSnapshotPDA(firstEpoch)})
+    .rpc()
+
+    const submitterWallet = await createFundedSubmitter()
+    await submitClaim(submitterWallet)
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+
+    await program.methods.takeStatsSnapshot()
+    .accountsPartial({statsSnapshot: getStatsSnapshotPDA(secondEpoch)})
+    .rpc()
+
+    const firstSnapshot = await program.account.statsSnapshot.fetch(getStatsSnapshotPDA(firstEpoch))
+    const secondSnapshot = await program.account.statsSnapshot.fetch(getStatsSnapshotPDA(secondEpoch))
+    processorStats = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    assert(firstSnapshot.epoch.eq(firstEpoch))
+    assert(secondSnapshot.epoch.eq(secondEpoch))
+    assert(firstSnapshot.takenBy.equals(program.provider.publicKey))
+    assert(secondSnapshot.timestamp.gte(firstSnapshot.timestamp))
+    assert(secondSnapshot.maxDeniedClaimCount.eq(firstSnapshot.maxDeniedClaimCount.add(new anchor.BN(1))))
+    assert(secondSnapshot.maxDeniedClaimCount.eq(processorStats.maxDeniedClaimCount))
+    assert(secondSnapshot.processedClaimCount.eq(processorStats.processedClaimCount))
+    assert(processorStats.statsSnapshotCount.eq(secondEpoch.add(new anchor.BN(1))))
+
+    //Anyone else has to wait for the interval to pass
+    try
+    {
+      await program.methods.takeStatsSnapshot()
+      .accounts({signer: submitterWallet.publicKey})
+      .accountsPartial({statsSnapshot: getStatsSnapshotPDA(processorStats.statsSnapshotCount)})
+      .signers([submitterWallet])
+      .rpc()
+      assert(false, "A non CEO snapshot right after another one should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "StatsSnapshotTooSoon")
+    }
+  })
+
+  it("Records The Fee Mint And Amount Paid On The Claim And Processed Claim", async () => 
+  {
+    const submitterWallet = await createFundedSubmitter()
+    const feeConfig = await program.account.feeConfig.fetch(getFeeConfigPDA())
+    const expectedFeeAmountPaid = feeConfig.feesEnabled ? new anchor.BN(0.04 * 10 ** feeTokenDecimals) : new anchor.BN(0)
+
+    await submitClaim(submitterWallet)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.feeMint.equals(feeTokenMint))
+    assert(claim.feeAmountPaid.eq(expectedFeeAmountPaid))
+
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.feeMint.equals(feeTokenMint))
+    assert(processedClaim.feeAmountPaid.eq(expectedFeeAmountPaid))
+  })
+
+  it("Keeps The Absolute Queue Cap Separate From A Higher Queue Size Limit", async () => 
+  {
+    const maxAbsoluteQueue = 1000000
+    const softQueueSizeLimit = maxAbsoluteQueue + 1
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const originalQueueSizeLimit = claimQueue.queueSizeLimit
+    var m4aProtocol = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    const originalMinQueueSize = m4aProtocol.minQueueSize
+    const originalMaxQueueSize = m4aProtocol.maxQueueSize
+
+    //The soft limit can be configured past the hard cap
+    await program.methods.setQueueSizeBounds(originalMinQueueSize, softQueueSizeLimit).rpc()
+    await program.methods.editClaimQueueSize(softQueueSizeLimit).rpc()
+
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.queueSizeLimit > maxAbsoluteQueue)
+
+    //Filling the queue up to the hard cap isn't feasible on a local validator, so only check claims under it still go through
+    const submitterWallet = await createFundedSubmitter()
+    await submitClaim(submitterWallet)
+
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.currentClaimQueueCount <= maxAbsoluteQueue)
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+    await program.methods.editClaimQueueSize(originalQueueSizeLimit).rpc()
+    await program.methods.setQueueSizeBounds(originalMinQueueSize, originalMaxQueueSize).rpc()
+  })
+
+  it("Lets The Processor Fix The Claim Location Only Before Any Record Is Created", async () => 
+  {
+    const correctedStateIndex = 12
+    const submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    await program.methods.updateClaimLocation(submitterWallet.publicKey, countryIndex, correctedStateIndex).rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.countryIndex == countryIndex)
+    assert(claim.stateIndex == correctedStateIndex)
+
+    await program.methods.updateClaimLocation(submitterWallet.publicKey, countryIndex, stateIndex).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.updateClaimLocation(submitterWallet.publicKey, countryIndex, correctedStateIndex).rpc()
+      assert(false, "Updating the claim location after a record was created should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "RecordAlreadyCreated")
+    }
+
+    claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.stateIndex == stateIndex)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Emits Claim Approved And Claim Denied Events That Locate The Processed Claim", async () => 
+  {
+    const denialReason = "Testing"
+    const approvedWallet = await createFundedSubmitter()
+    const deniedWallet = await createFundedSubmitter()
+    const eventParser = new anchor.EventParser(program.programId, program.coder)
+
+    await submitClaim(approvedWallet)
+    await program.methods.assignClaimToProcessor(approvedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(approvedWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(approvedWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(approvedWallet.publicKey).rpc()
+    const approveSignature = await program.methods.approveClaim(approvedWallet.publicKey, null)
+    .accounts({submitterWallet: approvedWallet.publicKey})
+    .rpc({commitment: "confirmed"})
+
+    const approveTransaction = await program.provider.connection.getTransaction(approveSignature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+    const claimApprovedEvent = [...eventParser.parseLogs(approveTransaction.meta.logMessages)].find(event => event.name == "claimApproved")
+
+    assert(claimApprovedEvent != undefined)
+    assert(claimApprovedEvent.data.submitterAddress.equals(approvedWallet.publicKey))
+
+    const approvedProcessedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(claimApprovedEvent.data.processorAddress, claimApprovedEvent.data.processorCountIndex))
+    assert(approvedProcessedClaim.processedClaimId.eq(claimApprovedEvent.data.processedClaimId))
+    assert(approvedProcessedClaim.claimId.eq(claimApprovedEvent.data.claimId))
+    assert(approvedProcessedClaim.submitterAddress.equals(approvedWallet.publicKey))
+
+    await submitClaim(deniedWallet)
+    await program.methods.assignClaimToProcessor(deniedWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(deniedWallet.publicKey).rpc()
+    const denySignature = await program.methods.createPatientRecordAndDenyClaim(deniedWallet.publicKey, denialReason, null).rpc({commitment: "confirmed"})
+
+    const denyTransaction = await program.provider.connection.getTransaction(denySignature, {commitment: "confirmed", maxSupportedTransactionVersion: 0})
+    const claimDeniedEvent = [...eventParser.parseLogs(denyTransaction.meta.logMessages)].find(event => event.name == "claimDenied")
+
+    assert(claimDeniedEvent != undefined)
+    assert(claimDeniedEvent.data.submitterAddress.equals(deniedWallet.publicKey))
+    assert(claimDeniedEvent.data.processorCountIndex.eq(claimApprovedEvent.data.processorCountIndex.add(new anchor.BN(1))))
+
+    const deniedProcessedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(claimDeniedEvent.data.processorAddress, claimDeniedEvent.data.processorCountIndex))
+    assert(deniedProcessedClaim.processedClaimId.eq(claimDeniedEvent.data.processedClaimId))
+    assert(deniedProcessedClaim.submitterAddress.equals(deniedWallet.publicKey))
+  })
+
+  it("Lets Only The Submitter And Assigned Processor Comment On A Claim, Up To 5 Comments", async () => 
+  {
+    const maxClaimCommentCount = 5
+    const submitterWallet = await createFundedSubmitter()
+    const outsiderWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.addClaimComment(submitterWallet.publicKey, "Not my claim")
+      .accounts({signer: outsiderWallet.publicKey})
+      .signers([outsiderWallet])
+      .rpc()
+      assert(false, "Someone who isn't the submitter or assigned processor shouldn't be able to comment")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotSubmitterOrProcessor")
+    }
+
+    for(var i=0; i<maxClaimCommentCount; i++)
+    {
+      if(i % 2 == 0)
+      {
+        await program.methods.addClaimComment(submitterWallet.publicKey, "Submitter comment " + i)
+        .accounts({signer: submitterWallet.publicKey})
+        .signers([submitterWallet])
+        .rpc()
+      }
+      else
+      {
+        await program.methods.addClaimComment(submitterWallet.publicKey, "Processor comment " + i).rpc()
+      }
+    }
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.comments.length == maxClaimCommentCount)
+    assert(claim.comments[0].author.equals(submitterWallet.publicKey))
+    assert(claim.comments[1].author.equals(program.provider.publicKey))
+    assert(claim.comments[4].text == "Submitter comment 4")
+
+    try
+    {
+      await program.methods.addClaimComment(submitterWallet.publicKey, "One too many").rpc()
+      assert(false, "A 6th comment should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "TooManyClaimComments")
+    }
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Rejects A Claim Whose Treasurer Fee Token Account Is For A Different Mint", async () => 
+  {
+    const submitterWallet = await createFundedSubmitter()
+    const mismatchedMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+    const mismatchedTreasurerFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, mismatchedMint, treasurerAddress)
+
+    try
+    {
+      await program.methods.submitClaimToQueue
+      (
+        patientIndex,
+        feeTokenMint,
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        hospitalType,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        hospitalBillInvoiceNumber,
+        note144Characters,
+        claimAmount,
+        copayAmount,
+        deductibleAmount,
+        currencyCode,
+        ailment,
+        insuranceCompanyIndex,
+        insuranceCompanyName,
+        diagnosisCodes
+      )
+      .accountsPartial({signer: submitterWallet.publicKey, treasurerFeeAta: mismatchedTreasurerFeeAta.address, feeExemption: null})
+      .signers([submitterWallet])
+      .rpc()
+      assert(false, "Submitting with a treasurer fee token account for a different mint should have failed")
+    }
+    catch(error)
+    {
+      //The associated token constraint catches this first, the runtime mint check backs it up
+      assert(error.error.errorCode.code == "ConstraintAssociated" || error.error.errorCode.code == "FeeMintMismatch")
+    }
+
+    assert(await program.account.claim.fetchNullable(getClaimPDA(submitterWallet.publicKey)) == null)
+  })
+
+  it("Keeps The Last 3 Replaced Hospital Notes In The Note History", async () => 
+  {
+    const maxHospitalNoteHistoryCount = 3
+    const editHospitalNote = async (hospitalNote: string) => 
+    {
+      await program.methods.editHospital
+      (
+        countryIndex,
+        stateIndex,
+        hospitalIndex,
+        true,
+        hospitalType,
+        hospitalLongitude,
+        hospitalLatitude,
+        hospitalName,
+        hospitalAddress,
+        hospitalCity,
+        hospitalZipCode,
+        hospitalPhoneNumber,
+        hospitalNote).rpc()
+    }
+
+    var hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))
+    const originalNote = hospital.note
+
+    await editHospitalNote("First note")
+    await editHospitalNote("Second note")
+    await editHospitalNote("Third note")
+
+    hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))
+    assert(hospital.note == "Third note")
+    assert(hospital.noteHistory.length == maxHospitalNoteHistoryCount)
+    assert(hospital.noteHistory[0].note == originalNote)
+    assert(hospital.noteHistory[1].note == "First note")
+    assert(hospital.noteHistory[2].note == "Second note")
+    assert(hospital.noteHistory[2].time.gte(hospital.noteHistory[0].time))
+
+    //One more edit drops the oldest note
+    await editHospitalNote(originalNote)
+
+    hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))
+    assert(hospital.note == originalNote)
+    assert(hospital.noteHistory.length == maxHospitalNoteHistoryCount)
+    assert(hospital.noteHistory[0].note == "First note")
+    assert(hospital.noteHistory[2].note == "Third note")
+  })
+
+  it("Lets A Super Admin Drop The Denial Hammer But Not A Regular Processor", async () => 
+  {
+    const superAdminWallet = await createFundedSubmitter()
+    const regularProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(superAdminWallet.publicKey).rpc()
+    await program.methods.setProcessorAccountPrivilege(superAdminWallet.publicKey, true).rpc()
+    await program.methods.createProcessorAccount(regularProcessorWallet.publicKey).rpc()
+
+    const submitterWallet = await createFundedSubmitter()
+    await submitClaim(submitterWallet)
+    const claimPDA = getClaimPDA(submitterWallet.publicKey)
+
+    try
+    {
+      await program.methods.dropDenialHammer()
+      .accounts({signer: regularProcessorWallet.publicKey})
+      .remainingAccounts([{pubkey: claimPDA, isSigner: false, isWritable: true}])
+      .signers([regularProcessorWallet])
+      .rpc()
+      assert(false, "A regular processor shouldn't be able to drop the denial hammer")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotSuperAdminOrCEO")
+    }
+
+    assert(await program.account.claim.fetchNullable(claimPDA) != null)
+
+    await program.methods.dropDenialHammer()
+    .accounts({signer: superAdminWallet.publicKey})
+    .remainingAccounts([{pubkey: claimPDA, isSigner: false, isWritable: true}])
+    .signers([superAdminWallet])
+    .rpc()
+
+    assert(await program.account.claim.fetchNullable(claimPDA) == null)
+
+    const superAdminProcessor = await program.account.processorAccount.fetch(getProcessorPDA(superAdminWallet.publicKey))
+    assert(superAdminProcessor.denialHammerDroppedCount.eq(new anchor.BN(1)))
+
+    await program.methods.setProcessorAccountPrivilege(superAdminWallet.publicKey, false).rpc()
+  })
+
+  it("Rejects Dropping The Denial Hammer On A Claim A Processor Is Holding", async () => 
+  {
+    const submitterWallet = await createFundedSubmitter()
+    await submitClaim(submitterWallet)
+    const claimPDA = getClaimPDA(submitterWallet.publicKey)
+
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.dropDenialHammer()
+      .remainingAccounts([{pubkey: claimPDA, isSigner: false, isWritable: true}])
+      .rpc()
+      assert(false, "A claim that's assigned to a processor shouldn't be hammered")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimHeldByProcessor")
+    }
+
+    assert(await program.account.claim.fetchNullable(claimPDA) != null)
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+
+    await program.methods.dropDenialHammer()
+    .remainingAccounts([{pubkey: claimPDA, isSigner: false, isWritable: true}])
+    .rpc()
+
+    assert(await program.account.claim.fetchNullable(claimPDA) == null)
+  })
+
+  it("Rejects A Previously Valid Note After The Note Limit Is Lowered", async () => 
+  {
+    const loweredNoteLength = 100
+    const submitterWallet = await createFundedSubmitter()
+    const originalFieldLimits = await program.account.fieldLimits.fetch(getFieldLimitsPDA())
+
+    await program.methods.setFieldLimits({...originalFieldLimits, noteLength: loweredNoteLength}).rpc()
+
+    var fieldLimits = await program.account.fieldLimits.fetch(getFieldLimitsPDA())
+    assert(fieldLimits.noteLength == loweredNoteLength)
+
+    try
+    {
+      await submitClaim(submitterWallet)
+      assert(false, "A note over the lowered limit should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NoteTooLong")
+    }
+
+    try
+    {
+      await program.methods.setFieldLimits({...originalFieldLimits, noteLength: note144Characters.length + 1}).rpc()
+      assert(false, "Raising a limit past its hard cap should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FieldLimitOutOfRange")
+    }
+
+    await program.methods.setFieldLimits(originalFieldLimits).rpc()
+    await submitClaim(submitterWallet)
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+  })
+
+  it("Requires Patient Consent Before A Claim Can Be Submitted", async () => 
+  {
+    const submitterWallet = await createFundedSubmitter(false)
+
+    var patient = await program.account.patientAccount.fetch(getPatientPDA(submitterWallet.publicKey, patientIndex))
+    assert(patient.consentGiven == false)
+
+    try
+    {
+      await submitClaim(submitterWallet)
+      assert(false, "Submitting a claim without patient consent should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ConsentRequired")
+    }
+
+    await program.methods.givePatientConsent(patientIndex)
+    .accounts({signer: submitterWallet.publicKey})
+    .signers([submitterWallet])
+    .rpc()
+
+    patient = await program.account.patientAccount.fetch(getPatientPDA(submitterWallet.publicKey, patientIndex))
+    assert(patient.consentGiven == true)
+    assert(patient.consentTime.gt(new anchor.BN(0)))
+
+    await submitClaim(submitterWallet)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.submitterAddress.equals(submitterWallet.publicKey))
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+  })
+
+  it("Voids An Approved Processed Claim And Reverses Its Approved Amount Everywhere", async () => 
+  {
+    const voidedStatus = 9
+    const submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const fetchApprovedClaimAmounts = async () => 
+    {
+      return [
+        (await program.account.processorStats.fetch(getprocessorStatsPDA())).approvedClaimAmount,
+        (await program.account.submitterAccount.fetch(getSubmitterPDA(submitterWallet.publicKey))).approvedClaimAmount,
+        (await program.account.patientAccount.fetch(getPatientPDA(submitterWallet.publicKey, patientIndex))).approvedClaimAmount,
+        (await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))).approvedClaimAmount,
+        (await program.account.stateAccount.fetch(getStatePDA(countryIndex, stateIndex))).approvedClaimAmount,
+        (await program.account.hospital.fetch(getHospitalPDA(countryIndex, stateIndex, hospitalIndex))).approvedClaimAmount,
+        (await program.account.insuranceCompany.fetch(getInsuranceCompanyPDA(insuranceCompanyIndex))).approvedClaimAmount
+      ]
+    }
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const approvedClaimAmountsBefore = await fetchApprovedClaimAmounts()
+
+    await program.methods.voidProcessedClaim(program.provider.publicKey, processorCountIndex, "Wrong patient entirely").rpc()
+
+    const approvedClaimAmountsAfter = await fetchApprovedClaimAmounts()
+
+    for(var i=0; i<approvedClaimAmountsBefore.length; i++)
+    {
+      assert(approvedClaimAmountsAfter[i].eq(approvedClaimAmountsBefore[i].sub(claimAmount)))
+    }
+
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex))
+    assert(processedClaim.status == voidedStatus)
+    assert(processedClaim.voidReason == "Wrong patient entirely")
+    assert(processedClaim.insuranceCoveredAmount.eq(new anchor.BN(0)))
+
+    try
+    {
+      await program.methods.voidProcessedClaim(program.provider.publicKey, processorCountIndex, "Voiding twice").rpc()
+      assert(false, "Voiding an already voided claim should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ClaimCannotBeVoided")
+    }
+  })
+
+  it("Changes The Content Hash When The Note Is Edited But Not On Unrelated Updates", async () => 
+  {
+    const submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.contentHash.some((byte: number) => byte != 0))
+
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const processedClaimPDA = getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex)
+
+    var processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+    const approvedContentHash = processedClaim.contentHash
+
+    //Nothing salient changed on approval, so the processed claim starts with the claim's hash
+    assert(Buffer.from(approvedContentHash).equals(Buffer.from(claim.contentHash)))
+
+    await program.methods.editProcessedClaimAndAllRecords(
+      program.provider.publicKey, 
+      processorCountIndex, 
+      hospitalBillInvoiceNumber,
+      "Edited note",
+      processedClaim.claimAmount,
+      processedClaim.copayAmount,
+      processedClaim.deductibleAmount,
+      processedClaim.ailment)
+    .rpc()
+
+    processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+    const editedContentHash = processedClaim.contentHash
+    assert(!Buffer.from(editedContentHash).equals(Buffer.from(approvedContentHash)))
+
+    //Settling only touches status and stats
+    await program.methods.markClaimSettled(program.provider.publicKey, processorCountIndex)
+    .accounts({signer: submitterWallet.publicKey})
+    .signers([submitterWallet])
+    .rpc()
+
+    processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+    assert(processedClaim.settledTime.gt(new anchor.BN(0)))
+    assert(Buffer.from(processedClaim.contentHash).equals(Buffer.from(editedContentHash)))
+  })
+
+  it("Sets Processor Names As The CEO And As The Processor", async () => 
+  {
+    const processorWallet = await createFundedSubmitter()
+    const outsiderWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(processorWallet.publicKey).rpc()
+    const processorPDA = getProcessorPDA(processorWallet.publicKey)
+
+    try
+    {
+      await program.methods.setProcessorName(processorWallet.publicKey, "N".repeat(41)).rpc()
+      assert(false, "Processor names longer than 40 characters shouldn't be allowed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ProcessorNameTooLong")
+    }
+
+    await program.methods.setProcessorName(processorWallet.publicKey, "N".repeat(40)).rpc()
+    var processor = await program.account.processorAccount.fetch(processorPDA)
+    assert(processor.name == "N".repeat(40))
+
+    await program.methods.setProcessorName(processorWallet.publicKey, "Processor Pat")
+    .accounts({signer: processorWallet.publicKey})
+    .signers([processorWallet])
+    .rpc()
+    processor = await program.account.processorAccount.fetch(processorPDA)
+    assert(processor.name == "Processor Pat")
+
+    try
+    {
+      await program.methods.setProcessorName(processorWallet.publicKey, "Outsider")
+      .accounts({signer: outsiderWallet.publicKey})
+      .signers([outsiderWallet])
+      .rpc()
+      assert(false, "Only the CEO or the processor should be able to set the processor name")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotCEOOrProcessorOwner")
+    }
+  })
+
+  it("Takes Approved And Denied Claims Out Of The Queue Count", async () => 
+  {
+    const approvedSubmitterWallet = await createFundedSubmitter()
+    const deniedSubmitterWallet = await createFundedSubmitter()
+    const denialReason = "Testing"
+
+    await submitClaim(approvedSubmitterWallet)
+    await submitClaim(deniedSubmitterWallet)
+
+    var claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    const queueCountBefore = claimQueue.currentClaimQueueCount
+
+    for(const submitterWallet of [approvedSubmitterWallet, deniedSubmitterWallet])
+    {
+      await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+      if(submitterWallet == approvedSubmitterWallet)
+      {
+        await program.methods.approveClaim(submitterWallet.publicKey, null)
+        .accounts({submitterWallet: submitterWallet.publicKey})
+        .rpc()
+      }
+      else
+      {
+        await program.methods.denyClaimWithAllRecords(submitterWallet.publicKey, denialReason, null).rpc()
+      }
+    }
+
+    claimQueue = await program.account.claimQueue.fetch(getClaimQueuePDA())
+    assert(claimQueue.currentClaimQueueCount == queueCountBefore - 2)
+  })
+
+  it("Creates An Other Type Hospital And Counts It", async () => 
+  {
+    const otherType = 4
+    const otherStateIndex = 13
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet, claimAmount, copayAmount, deductibleAmount, feeTokenMint, null, currencyCode, otherType)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createStateAccount(submitterWallet.publicKey, countryIndex, otherStateIndex).rpc()
+
+    const hospitalStatsPDA = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("hospitalStats")], program.programId)[0]
+    const hospitalStatsBefore = await program.account.hospitalStats.fetch(hospitalStatsPDA)
+
+    await program.methods.createHospital
+    (
+      submitterWallet.publicKey,
+      countryIndex, 
+      otherStateIndex, 
+      otherType,
+      hospitalLongitude,
+      hospitalLatitude,
+      hospitalName, 
+      hospitalAddress,
+      hospitalCity,
+      hospitalZipCode,
+      hospitalPhoneNumber,
+      note144Characters).rpc()
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+
+    const hospital = await program.account.hospital.fetch(getHospitalPDA(countryIndex, otherStateIndex, 0))
+    assert(hospital.hospitalType == otherType)
+
+    const state = await program.account.stateAccount.fetch(getStatePDA(countryIndex, otherStateIndex))
+    assert(state.hospitalCount == 1)
+    assert(state.hospitalCountByType[otherType] == 1)
+    assert(state.generalHospitalCount == 0)
+
+    const hospitalStatsAfter = await program.account.hospitalStats.fetch(hospitalStatsPDA)
+    assert(hospitalStatsAfter.hospitalCount == hospitalStatsBefore.hospitalCount + 1)
+    assert(hospitalStatsAfter.hospitalCountByType[otherType] == hospitalStatsBefore.hospitalCountByType[otherType] + 1)
+  })
+
+  it("Reads A Range Of Processed Claim Summaries In One Call", async () => 
+  {
+    const approvedStatus = 2
+    var processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const startIndex = processor.processedClaimCount
+
+    for (let i = 0; i < 3; i++)
+    {
+      let submitterWallet = await createFundedSubmitter()
+
+      await submitClaim(submitterWallet)
+      await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+      await program.methods.approveClaim(submitterWallet.publicKey, null)
+      .accounts({submitterWallet: submitterWallet.publicKey})
+      .rpc()
+    }
+
+    const processedClaimAccounts = [0, 1, 2].map((offset) => (
+    {
+      pubkey: getProcessedClaimPDAForProcessor(program.provider.publicKey, startIndex.add(new anchor.BN(offset))),
+      isSigner: false,
+      isWritable: false
+    }))
+
+    const summaries = await program.methods.getProcessedClaimSummaries(program.provider.publicKey, startIndex, 3)
+    .remainingAccounts(processedClaimAccounts)
+    .view()
+
+    assert(summaries.length == 3)
+    for (let i = 0; i < 3; i++)
+    {
+      const processedClaim = await program.account.processedClaim.fetch(processedClaimAccounts[i].pubkey)
+      assert(summaries[i].processedClaimId.eq(processedClaim.processedClaimId))
+      assert(summaries[i].status == approvedStatus)
+      assert(summaries[i].claimAmount.eq(claimAmount))
+      assert(summaries[i].processedTime.eq(processedClaim.processedTime))
+    }
+  })
+
+  it("Keeps The Submitter And Patient Of A Claim Through Approve With Edits", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+
+    //Edit every field the instruction takes
+    await program.methods.approveClaimWithEdits
+    (
+      submitterWallet.publicKey, 
+      hospitalType,
+      1.111,
+      8.88,
+      "Hos Name Edited",
+      "Hos Address Edited",
+      "Hos City Edited",
+      47474,
+      new anchor.BN(7777774444),
+      "#efg",
+      "Edited Claim Note",
+      claimAmount.sub(new anchor.BN(1000)),
+      copayAmount,
+      deductibleAmount,
+      "Foot Surgery Edited",
+      "Insurance Name Edited",
+      null
+    ).rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+
+    assert(processedClaim.note == "Edited Claim Note")
+    assert(processedClaim.submitterAddress.equals(claim.submitterAddress))
+    assert(processedClaim.submitterAddress.equals(submitterWallet.publicKey))
+    assert(processedClaim.patientIndex == claim.patientIndex)
+    assert(processedClaim.claimId.eq(claim.id))
+  })
+
+  it("Auto Flags Claims Over The Auto Flag Amount", async () => 
+  {
+    await program.methods.setAutoFlagAmountCents(claimAmount).rpc()
+
+    let belowWallet = await createFundedSubmitter()
+    let aboveWallet = await createFundedSubmitter()
+
+    //Landing exactly on the auto flag amount doesn't flag the claim
+    await submitClaim(belowWallet)
+    await submitClaim(aboveWallet, claimAmount.add(new anchor.BN(1)))
+
+    const belowClaim = await program.account.claim.fetch(getClaimPDA(belowWallet.publicKey))
+    assert(belowClaim.isFlaggedForReview == false)
+    assert(belowClaim.autoFlagged == false)
+
+    const aboveClaim = await program.account.claim.fetch(getClaimPDA(aboveWallet.publicKey))
+    assert(aboveClaim.isFlaggedForReview == true)
+    assert(aboveClaim.autoFlagged == true)
+
+    await program.methods.setAutoFlagAmountCents(new anchor.BN(0)).rpc()
+    await program.methods.maxDenyPendingClaim(belowWallet.publicKey, 0).rpc()
+    await program.methods.maxDenyPendingClaim(aboveWallet.publicKey, 0).rpc()
+  })
+
+  it("Closes An Empty Submitter Account And Keeps Ones With Patients", async () => 
+  {
+    let emptyWallet = anchor.web3.Keypair.generate()
+    let token_airdrop = await program.provider.connection.requestAirdrop(emptyWallet.publicKey, 
+      1000 * 10002240)
+
+    const latestBlockHash = await program.provider.connection.getLatestBlockhash()
+    await program.provider.connection.confirmTransaction
+    ({
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature: token_airdrop,
+    })
+
+    await program.methods.createSubmitterAccount()
+    .accounts({signer: emptyWallet.publicKey})
+    .signers([emptyWallet])
+    .rpc()
+
+    const m4aProtocolBefore = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    const balanceBefore = await program.provider.connection.getBalance(emptyWallet.publicKey)
+
+    await program.methods.closeSubmitterAccount()
+    .accounts({signer: emptyWallet.publicKey})
+    .signers([emptyWallet])
+    .rpc()
+
+    const m4aProtocolAfter = await program.account.m4AProtocol.fetch(getM4AProtocolPDA())
+    assert(m4aProtocolAfter.submitterAccountTotal.eq(m4aProtocolBefore.submitterAccountTotal.sub(new anchor.BN(1))))
+    assert(m4aProtocolAfter.submitterIdCounter.eq(m4aProtocolBefore.submitterIdCounter))
+    assert(await program.account.submitterAccount.fetchNullable(getSubmitterPDA(emptyWallet.publicKey)) == null)
+    assert(await program.provider.connection.getBalance(emptyWallet.publicKey) > balanceBefore)
+
+    //Closing doesn't free up an id, the next submitter still gets a new one
+    let submitterWithPatientWallet = await createFundedSubmitter()
+    const nextSubmitter = await program.account.submitterAccount.fetch(getSubmitterPDA(submitterWithPatientWallet.publicKey))
+    assert(nextSubmitter.id.eq(m4aProtocolBefore.submitterIdCounter.add(new anchor.BN(1))))
+
+    try
+    {
+      await program.methods.closeSubmitterAccount()
+      .accounts({signer: submitterWithPatientWallet.publicKey})
+      .signers([submitterWithPatientWallet])
+      .rpc()
+      assert(false, "Submitter accounts with patients shouldn't be closable")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "SubmitterHasPatients")
+    }
+  })
+
+  //Only runs when the provider wallet is the treasurer, e.g. against a build with INITIAL_TREASURER_ADDRESS pointed at it
+  it("Lets The Treasurer Add And Remove A Fee Token Entry", async function() 
+  {
+    if(program.provider.publicKey.equals(treasurerAddress) == false)
+    {
+      this.skip()
+    }
+
+    const treasurerFeeTokenMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+
+    await program.methods.addFeeTokenEntry(treasurerFeeTokenMint, feeTokenDecimals).rpc()
+    var feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(treasurerFeeTokenMint))
+    assert(feeTokenEntry.tokenMintAddress.equals(treasurerFeeTokenMint))
+
+    await program.methods.updateFeeTokenDecimals(treasurerFeeTokenMint, feeTokenDecimals + 2).rpc()
+    feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(treasurerFeeTokenMint))
+    assert(feeTokenEntry.decimalAmount == feeTokenDecimals + 2)
+
+    await program.methods.removeFeeTokenEntry(treasurerFeeTokenMint).rpc()
+    assert(await program.account.feeTokenEntry.fetchNullable(getFeeTokenEntryPDA(treasurerFeeTokenMint)) == null)
+  })
+
+  it("Rejects A Random Signer Adding A Fee Token Entry", async () => 
+  {
+    let randomWallet = await createFundedSubmitter()
+    const randomFeeTokenMint = await createMint(program.provider.connection, payer, program.provider.publicKey, null, feeTokenDecimals)
+
+    try
+    {
+      await program.methods.addFeeTokenEntry(randomFeeTokenMint, feeTokenDecimals)
+      .accounts({signer: randomWallet.publicKey})
+      .signers([randomWallet])
+      .rpc()
+      assert(false, "Only the CEO or the Treasurer should be able to add fee token entries")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "NotCEOOrTreasurer")
+    }
+
+    assert(await program.account.feeTokenEntry.fetchNullable(getFeeTokenEntryPDA(randomFeeTokenMint)) == null)
+  })
+
+  it("Updates The Processor's Last Processed Time On Approval", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+
+    const processorBefore = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+
+    //Make sure the approval lands on a later second than any earlier resolution
+    await sleep(2000)
+
+    await program.methods.approveClaim(submitterWallet.publicKey, null)
+    .accounts({submitterWallet: submitterWallet.publicKey})
+    .rpc()
+
+    const processorAfter = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processorBefore.processedClaimCount))
+
+    assert(processorAfter.lastProcessedTime.gt(processorBefore.lastProcessedTime))
+    assert(processorAfter.lastProcessedTime.eq(processedClaim.processedTime))
+  })
+
+  it("Rejects Closing A Submitter Account With An Open Claim", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+
+    //Claims are always for a patient, so the patient check is what keeps a submitter with an open claim from closing
+
+    try
+    {
+      await program.methods.closeSubmitterAccount()
+      .accounts({signer: submitterWallet.publicKey})
+      .signers([submitterWallet])
+      .rpc()
+      assert(false, "Submitter accounts with an open claim shouldn't be closable")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "SubmitterHasPatients")
+    }
+
+    assert(await program.account.submitterAccount.fetchNullable(getSubmitterPDA(submitterWallet.publicKey)) != null)
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+  })
+
+  it("Fills In The Denial Reason From Its Template When Only A Reason Code Is Given", async () => 
+  {
+    const denialReasonCode = 7
+    const templateText = "The service isn't covered under the patient's plan"
+    const denialReasonTemplatePDA = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("denialReasonTemplate"), new anchor.BN(denialReasonCode).toArrayLike(Buffer, 'le', 2)], program.programId)[0]
+
+    await program.methods.addDenialReasonTemplate(denialReasonCode, "Not covered").rpc()
+    await program.methods.updateDenialReasonTemplate(denialReasonCode, templateText).rpc()
+
+    let submitterWallet = await createFundedSubmitter()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(submitterWallet.publicKey, "", denialReasonCode)
+    .accountsPartial({denialReasonTemplate: denialReasonTemplatePDA})
+    .rpc()
+
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processor.processedClaimCount.sub(new anchor.BN(1))))
+    assert(processedClaim.denialReason == templateText)
+  })
+
+  it("Counts Approvals By Approved Amount Bucket", async () => 
+  {
+    const smallClaimAmount = new anchor.BN(5000) //$50 lands in the <$100 bucket, the default $100 claim lands in the <$1k bucket
+    const processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    for(const claimAmountToSubmit of [smallClaimAmount, claimAmount])
+    {
+      let submitterWallet = await createFundedSubmitter()
+
+      await submitClaim(submitterWallet, claimAmountToSubmit)
+      await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+      await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+      await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+      await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+      await program.methods.approveClaim(submitterWallet.publicKey, null)
+      .accounts({submitterWallet: submitterWallet.publicKey})
+      .rpc()
+    }
+
+    var processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    for(let i = 0; i < 6; i++)
+    {
+      const bucketDelta = processorStatsAfter.approvedAmountBuckets[i].sub(processorStatsBefore.approvedAmountBuckets[i]).toNumber()
+      assert(bucketDelta == (i < 2 ? 1 : 0))
+    }
+
+    //Editing the $100 approval down to $50 moves it into the <$100 bucket
+    const processor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processorCountIndex = processor.processedClaimCount.sub(new anchor.BN(1))
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex))
+
+    await program.methods.editProcessedClaimAndAllRecords(
+      program.provider.publicKey, 
+      processorCountIndex, 
+      processedClaim.hospitalBillInvoiceNumber,
+      processedClaim.note,
+      smallClaimAmount,
+      processedClaim.copayAmount,
+      processedClaim.deductibleAmount,
+      processedClaim.ailment)
+    .rpc()
+
+    processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+
+    for(let i = 0; i < 6; i++)
+    {
+      const bucketDelta = processorStatsAfter.approvedAmountBuckets[i].sub(processorStatsBefore.approvedAmountBuckets[i]).toNumber()
+      assert(bucketDelta == (i == 0 ? 2 : 0))
+    }
+  })
+
+  it("Migrates A Transferred Patient's Record Under The New Submitter", async () => 
+  {
+    const patientIndexBytes = new anchor.BN(patientIndex).toArrayLike(Buffer, 'le', 1)
+    const getPatientRecordPDA = (submitterAddress: anchor.web3.PublicKey, recordIndex: number) => 
+      anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("patientRecord"), submitterAddress.toBuffer(), patientIndexBytes, new anchor.BN(recordIndex).toArrayLike(Buffer, 'le', 4)], program.programId)[0]
+
+    let oldSubmitterWallet = await createFundedSubmitter()
+    let newSubmitterWallet = await createFundedSubmitter()
+
+    //Gives the patient under the old submitter one record
+    await submitClaim(oldSubmitterWallet)
+    await program.methods.assignClaimToProcessor(oldSubmitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(oldSubmitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(oldSubmitterWallet.publicKey, "Testing Record Migration", null).rpc()
+
+    const oldPatientRecord = await program.account.patientRecord.fetch(getPatientRecordPDA(oldSubmitterWallet.publicKey, 0))
+
+    await program.methods.migratePatientRecord(oldSubmitterWallet.publicKey, patientIndex, 0, newSubmitterWallet.publicKey, patientIndex).rpc()
+
+    const migratedPatientRecord = await program.account.patientRecord.fetch(getPatientRecordPDA(newSubmitterWallet.publicKey, 0))
+    assert(migratedPatientRecord.recordId == oldPatientRecord.recordId)
+    assert(migratedPatientRecord.claimId == oldPatientRecord.claimId)
+    assert(migratedPatientRecord.denialReason == "Testing Record Migration")
+    assert(migratedPatientRecord.submitterAddress.equals(newSubmitterWallet.publicKey))
+
+    const newPatient = await program.account.patientAccount.fetch(getPatientPDA(newSubmitterWallet.publicKey, patientIndex))
+    assert(newPatient.recordCount == 1)
+
+    assert(await program.account.patientRecord.fetchNullable(getPatientRecordPDA(oldSubmitterWallet.publicKey, 0)) == null)
+  })
+
+  let dualSignoffSubmitterWallet = anchor.web3.Keypair.generate()
+
+  it("Only Records A First Approval For A Claim Over The Dual Signoff Amount", async () => 
+  {
+    dualSignoffSubmitterWallet = await createFundedSubmitter()
+    await program.methods.setDualSignoffAmountCents(claimAmount.sub(new anchor.BN(1))).rpc()
+
+    await submitClaim(dualSignoffSubmitterWallet)
+    await program.methods.assignClaimToProcessor(dualSignoffSubmitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(dualSignoffSubmitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecord(dualSignoffSubmitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(dualSignoffSubmitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.approveClaim(dualSignoffSubmitterWallet.publicKey, null)
+      .accounts({submitterWallet: dualSignoffSubmitterWallet.publicKey})
+      .rpc()
+      assert(false, "A claim over the dual signoff amount shouldn't be approved in one step")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "DualSignoffRequired")
+    }
+
+    const processorStatsBefore = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    const processorBefore = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+
+    await program.methods.firstApproveClaim(dualSignoffSubmitterWallet.publicKey).rpc()
+
+    //A single processor can't finalize the claim, it stays open waiting on a second approval
+    const claim = await program.account.claim.fetch(getClaimPDA(dualSignoffSubmitterWallet.publicKey))
+    assert(claim.status == 10)
+    assert(claim.firstApproverAddress.equals(program.provider.publicKey))
+
+    const processorStatsAfter = await program.account.processorStats.fetch(getprocessorStatsPDA())
+    assert(processorStatsAfter.approvedClaimCount.eq(processorStatsBefore.approvedClaimCount))
+
+    //The first approval doesn't create a processed claim, so the processor's count index is still free
+    const processorAfter = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    assert(processorAfter.isProcessingClaim == false)
+    assert(processorAfter.processedClaimCount.eq(processorBefore.processedClaimCount))
+    assert(await program.provider.connection.getAccountInfo(getProcessedClaimPDAForProcessor(program.provider.publicKey, processorBefore.processedClaimCount)) == null)
+  })
+
+  it("Rejects The Same Processor Giving The Second Approval", async () => 
+  {
+    try
+    {
+      await program.methods.secondApproveClaim(dualSignoffSubmitterWallet.publicKey, null)
+      .accounts({submitterWallet: dualSignoffSubmitterWallet.publicKey})
+      .rpc()
+      assert(false, "The first approver shouldn't be able to give the second approval")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "SameProcessorSecondApproval")
+    }
+
+    const claim = await program.account.claim.fetch(getClaimPDA(dualSignoffSubmitterWallet.publicKey))
+    assert(claim.status == 10)
+  })
+
+  it("Approves A Claim Over The Dual Signoff Amount Once A Second Processor Signs Off", async () => 
+  {
+    let secondApproverWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(secondApproverWallet.publicKey).rpc()
+
+    await program.methods.secondApproveClaim(dualSignoffSubmitterWallet.publicKey, null)
+    .accounts({signer: secondApproverWallet.publicKey, submitterWallet: dualSignoffSubmitterWallet.publicKey})
+    .signers([secondApproverWallet])
+    .rpc()
+
+    assert(await program.account.claim.fetchNullable(getClaimPDA(dualSignoffSubmitterWallet.publicKey)) == null)
+
+    const processedClaim = await program.account.processedClaim.fetch(getProcessedClaimPDAForProcessor(secondApproverWallet.publicKey, new anchor.BN(0)))
+    assert(processedClaim.status == 2)
+    assert(processedClaim.submitterAddress.equals(dualSignoffSubmitterWallet.publicKey))
+    assert(processedClaim.resolvingProcessorAddress.equals(secondApproverWallet.publicKey))
+    assert(processedClaim.firstApproverAddress.equals(program.provider.publicKey))
+
+    await program.methods.setDualSignoffAmountCents(new anchor.BN(0)).rpc()
+  })
+
+  it("Rejects A First Approval So The Claim Can Be Denied Instead", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+    let rejectingProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(rejectingProcessorWallet.publicKey).rpc()
+    await program.methods.setDualSignoffAmountCents(claimAmount.sub(new anchor.BN(1))).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+
+    //The second approval can't create records, so the first approval needs them all
+    try
+    {
+      await program.methods.firstApproveClaim(submitterWallet.publicKey).rpc()
+      assert(false, "A first approval without the records should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "RecordNotCreated")
+    }
+
+    await program.methods.createPatientRecord(submitterWallet.publicKey).rpc()
+    await program.methods.createHospitalAndInsuranceCompanyRecords(submitterWallet.publicKey).rpc()
+    await program.methods.firstApproveClaim(submitterWallet.publicKey).rpc()
+
+    try
+    {
+      await program.methods.rejectFirstApproval(submitterWallet.publicKey).rpc()
+      assert(false, "The first approver shouldn't be able to reject their own approval")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "SameProcessorSecondApproval")
+    }
+
+    await program.methods.rejectFirstApproval(submitterWallet.publicKey)
+    .accounts({signer: rejectingProcessorWallet.publicKey})
+    .signers([rejectingProcessorWallet])
+    .rpc()
+
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.status == 0) //Pending
+    assert(claim.processorAddress.equals(anchor.web3.SystemProgram.programId))
+    assert(claim.firstApproverAddress.equals(anchor.web3.SystemProgram.programId))
+
+    //Back in the queue with its records, so it can be picked up and denied
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.denyClaimWithAllRecords(submitterWallet.publicKey, "Second processor disagreed", null).rpc()
+
+    assert(await program.account.claim.fetchNullable(getClaimPDA(submitterWallet.publicKey)) == null)
+
+    await program.methods.setDualSignoffAmountCents(new anchor.BN(0)).rpc()
+  })
+
+  it("Renames A Patient Without Touching Their Records", async () => 
+  {
+    const patientIndexBytes = new anchor.BN(patientIndex).toArrayLike(Buffer, 'le', 1)
+    let submitterWallet = await createFundedSubmitter()
+    const patientRecordPDA = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("patientRecord"), submitterWallet.publicKey.toBuffer(), patientIndexBytes, new anchor.BN(0).toArrayLike(Buffer, 'le', 4)], program.programId)[0]
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+    await program.methods.acknowledgeClaim(submitterWallet.publicKey).rpc()
+    await program.methods.createPatientRecordAndDenyClaim(submitterWallet.publicKey, "Testing Patient Rename", null).rpc()
+
+    const patientRecordBefore = await program.account.patientRecord.fetch(patientRecordPDA)
+
+    try
+    {
+      await program.methods.renamePatient(patientIndex, "a".repeat(53), "Doe")
+      .accounts({signer: submitterWallet.publicKey})
+      .signers([submitterWallet])
+      .rpc()
+      assert(false, "A first name over 52 characters should have been rejected")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "PatientFirstNameTooLong")
+    }
+
+    await program.methods.renamePatient(patientIndex, "Jane", "Doe")
+    .accounts({signer: submitterWallet.publicKey})
+    .signers([submitterWallet])
+    .rpc()
+
+    const patient = await program.account.patientAccount.fetch(getPatientPDA(submitterWallet.publicKey, patientIndex))
+    assert(patient.patientFirstName == "Jane")
+    assert(patient.patientLastName == "Doe")
+
+    //Records are historical, so renaming the patient leaves them exactly as they were
+    const patientRecordAfter = await program.account.patientRecord.fetch(patientRecordPDA)
+    assert(JSON.stringify(patientRecordAfter) == JSON.stringify(patientRecordBefore))
+  })
+
+  //The local validator these tests run against can't warp its clock, so a valid until time in the past stands in for an expired promo window
+  it("Rejects Submitting A Claim With An Expired Fee Token", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await program.methods.setFeeTokenValidUntil(feeTokenMint, new anchor.BN(1)).rpc()
+
+    const feeTokenEntry = await program.account.feeTokenEntry.fetch(getFeeTokenEntryPDA(feeTokenMint))
+    assert(feeTokenEntry.validUntil.eq(new anchor.BN(1)))
+
+    try
+    {
+      await submitClaim(submitterWallet)
+      assert(false, "Submitting with an expired fee token should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "FeeTokenExpired")
+    }
+
+    assert(await program.account.claim.fetchNullable(getClaimPDA(submitterWallet.publicKey)) == null)
+
+    //A valid until of 0 means the fee token is accepted forever again
+    await program.methods.setFeeTokenValidUntil(feeTokenMint, new anchor.BN(0)).rpc()
+    await submitClaim(submitterWallet)
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+  })
+
+  it("Counts Reassignments And Flags A Claim Reassigned More Than The Max", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+    let newProcessorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(newProcessorWallet.publicKey).rpc()
+    await program.methods.setProcessorAccountPrivilege(newProcessorWallet.publicKey, true).rpc()
+    await program.methods.setMaxReassignments(1).rpc()
+
+    await submitClaim(submitterWallet)
+    await program.methods.assignClaimToProcessor(submitterWallet.publicKey).rpc()
+
+    await program.methods.reassignClaimToNewProcessor(submitterWallet.publicKey, "Original processor is out this week")
+    .accounts({signer: newProcessorWallet.publicKey})
+    .signers([newProcessorWallet])
+    .rpc()
+
+    //Landing exactly on the max reassignments doesn't flag the claim
+    var claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.reassignmentCount == 1)
+    assert(claim.isFlaggedForReview == false)
+
+    try
+    {
+      await program.methods.reassignClaimToNewProcessor(submitterWallet.publicKey, "a".repeat(101)).rpc()
+      assert(false, "A reassignment reason over 100 characters should have been rejected")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ReassignmentReasonTooLong")
+    }
+
+    await program.methods.reassignClaimToNewProcessor(submitterWallet.publicKey, null).rpc()
+
+    claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.reassignmentCount == 2)
+    assert(claim.isFlaggedForReview == true)
+    assert(claim.autoFlagged == true)
+    assert(claim.processorAddress.equals(program.provider.publicKey))
+
+    await program.methods.setMaxReassignments(0).rpc()
+    await program.methods.setProcessorAccountPrivilege(newProcessorWallet.publicKey, false).rpc()
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  it("Reports Whether A Submitter Can Submit A Claim", async () => 
+  {
+    let submitterWallet = await createFundedSubmitter()
+
+    await program.methods.setClaimQueueFlag(false).rpc()
+
+    //Claim queue disabled is reason code 1
+    const queueDisabledResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex, feeTokenMint).view()
+    assert(queueDisabledResult.canSubmit == false)
+    assert(queueDisabledResult.reasonCode == 1)
+
+    await program.methods.setClaimQueueFlag(true).rpc()
+
+    const canSubmitResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex, feeTokenMint).view()
+    assert(canSubmitResult.canSubmit == true)
+    assert(canSubmitResult.reasonCode == 0)
+
+    //An open claim is reason code 6
+    await submitClaim(submitterWallet)
+    const claimOpenResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex, feeTokenMint).view()
+    assert(claimOpenResult.canSubmit == false)
+    assert(claimOpenResult.reasonCode == 6)
+
+    await program.methods.maxDenyPendingClaim(submitterWallet.publicKey, 0).rpc()
+
+    //A patient that hasn't been created is reason code 7
+    const patientMissingResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex + 1, feeTokenMint).view()
+    assert(patientMissingResult.canSubmit == false)
+    assert(patientMissingResult.reasonCode == 7)
+
+    //A patient that hasn't given consent is reason code 9
+    let noConsentSubmitterWallet = await createFundedSubmitter(false)
+    const consentMissingResult = await program.methods.canSubmit(noConsentSubmitterWallet.publicKey, patientIndex, feeTokenMint).view()
+    assert(consentMissingResult.canSubmit == false)
+    assert(consentMissingResult.reasonCode == 9)
+
+    //A fee mint without a fee token entry is reason code 10
+    const feeTokenNotSupportedResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex, anchor.web3.Keypair.generate().publicKey).view()
+    assert(feeTokenNotSupportedResult.canSubmit == false)
+    assert(feeTokenNotSupportedResult.reasonCode == 10)
+
+    //A fee token past its valid until is reason code 11
+    await program.methods.setFeeTokenValidUntil(feeTokenMint, new anchor.BN(1)).rpc()
+    const feeTokenExpiredResult = await program.methods.canSubmit(submitterWallet.publicKey, patientIndex, feeTokenMint).view()
+    assert(feeTokenExpiredResult.canSubmit == false)
+    assert(feeTokenExpiredResult.reasonCode == 11)
+
+    await program.methods.setFeeTokenValidUntil(feeTokenMint, new anchor.BN(0)).rpc()
+  })
+
+  it("Approves A Batch Whose Processed Claim PDA Was Pre-Funded With Lamports", async () => 
+  {
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, true).rpc()
+
+    let submitterWallet = await createFundedSubmitter()
+    let processorWallet = await createFundedSubmitter()
+    await program.methods.createProcessorAccount(processorWallet.publicKey).rpc()
+
+    await submitClaim(submitterWallet)
+
+    for(const method of ["assignClaimToProcessor", "acknowledgeClaim", "createPatientRecord", "createHospitalAndInsuranceCompanyRecords"])
+    {
+      await program.methods[method](submitterWallet.publicKey)
+      .accounts({signer: processorWallet.publicKey})
+      .signers([processorWallet])
+      .rpc()
+    }
+
+    //The next processed claim PDA is predictable, so anyone can send it lamports ahead of time, that must not block the batch
+    const superProcessor = await program.account.processorAccount.fetch(getProcessorPDA(program.provider.publicKey))
+    const processedClaimPDA = getProcessedClaimPDAForProcessor(program.provider.publicKey, superProcessor.processedClaimCount)
+
+    const preFundTransaction = new anchor.web3.Transaction().add(anchor.web3.SystemProgram.transfer
+    ({
+      fromPubkey: program.provider.publicKey,
+      toPubkey: processedClaimPDA,
+      lamports: 1000000
+    }))
+    await (program.provider as anchor.AnchorProvider).sendAndConfirm(preFundTransaction)
+
+    const claimPDA = getClaimPDA(submitterWallet.publicKey)
+    const claim = await program.account.claim.fetch(claimPDA)
+
+    await program.methods.approveClaimsBatch()
+    .remainingAccounts(getBatchApprovalAccounts(claimPDA, claim, superProcessor.processedClaimCount))
+    .preInstructions([anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({units: 1400000})])
+    .rpc()
+
+    const processedClaim = await program.account.processedClaim.fetch(processedClaimPDA)
+    assert(processedClaim.status == 2) //Approved
+    assert(processedClaim.submitterAddress.equals(submitterWallet.publicKey))
+    assert(await program.account.claim.fetchNullable(claimPDA) == null)
+
+    await program.methods.setProcessorAccountPrivilege(program.provider.publicKey, false).rpc()
+  })
+
+  //Needs the existing processed claim fixture in Anchor.toml, which only gets loaded into a local test validator
+  it("Rejects Approving Into A Processed Claim That Already Exists", async function() 
+  {
+    //Same seed the fixture's PDA was derived from, so its processed claim at index 0 is already taken
+    const collisionProcessorWallet = anchor.web3.Keypair.fromSeed(new Uint8Array(32).fill(21))
+    const existingProcessedClaimPDA = getProcessedClaimPDAForProcessor(collisionProcessorWallet.publicKey, new anchor.BN(0))
+
+    const existingProcessedClaimInfo = await program.provider.connection.getAccountInfo(existingProcessedClaimPDA)
+    if(existingProcessedClaimInfo == null)
+    {
+      this.skip()
+    }
+    assert(existingProcessedClaimInfo.owner.equals(program.programId))
+
+    const fundTransaction = new anchor.web3.Transaction().add(anchor.web3.SystemProgram.transfer
+    ({
+      fromPubkey: program.provider.publicKey,
+      toPubkey: collisionProcessorWallet.publicKey,
+      lamports: 1000000000
+    }))
+    await (program.provider as anchor.AnchorProvider).sendAndConfirm(fundTransaction)
+    await program.methods.createProcessorAccount(collisionProcessorWallet.publicKey).rpc()
+
+    let submitterWallet = await createFundedSubmitter()
+    await submitClaim(submitterWallet)
+
+    for(const method of ["assignClaimToProcessor", "acknowledgeClaim", "createPatientRecord", "createHospitalAndInsuranceCompanyRecords"])
+    {
+      await program.methods[method](submitterWallet.publicKey)
+      .accounts({signer: collisionProcessorWallet.publicKey})
+      .signers([collisionProcessorWallet])
+      .rpc()
+    }
+
+    try
+    {
+      await program.methods.approveClaim(submitterWallet.publicKey, null)
+      .accounts({signer: collisionProcessorWallet.publicKey, submitterWallet: submitterWallet.publicKey})
+      .signers([collisionProcessorWallet])
+      .rpc()
+      assert(false, "Approving into a processed claim that already exists should have failed")
+    }
+    catch(error)
+    {
+      assert(error.error.errorCode.code == "ProcessedClaimAlreadyExists")
+    }
+
+    //The claim is untouched and still open
+    const claim = await program.account.claim.fetch(getClaimPDA(submitterWallet.publicKey))
+    assert(claim.processorAddress.equals(collisionProcessorWallet.publicKey))
+
+    await program.methods.unassignClaimFromProcessor(submitterWallet.publicKey).rpc()
+  })
+
+  const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms))
+  var counter = 0
+  async function sleepFunction() {
+    console.log('Start sleep: ', counter)
+    await sleep(5000) // Sleep for 5 seconds
+    console.log('End sleep: ', counter)
+    counter += 1
+  }
+
+  function getM4AProtocolCEOAccountPDA()
+  {
+    const [m4aProtocolCEOPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("m4aProtocolCEO")
+      ],
+      program.programId
+    )
+    return m4aProtocolCEOPDA
+  }
+
+  function getM4AProtocolPDA()
+  {
+    const [m4aProtocolPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("m4aProtocol")
+      ],
+      program.programId
+    )
+    return m4aProtocolPDA
+  }
+
+  function getFieldLimitsPDA()
+  {
+    const [fieldLimitsPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("fieldLimits")
+      ],
+      program.programId
+    )
+    return fieldLimitsPDA
+  }
+
+  function getprocessorStatsPDA()
+  {
+    const [processorStatsPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("processorStats")
+      ],
+      program.programId
+    )
+    return processorStatsPDA
+  }
+
+  function getStatsSnapshotPDA(epoch: anchor.BN)
+  {
+    const [statsSnapshotPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("statsSnapshot"),
+        epoch.toBuffer('le', 8)
+      ],
+      program.programId
+    )
+    return statsSnapshotPDA
+  }
+
+  function getPatientPDA(submitterAddress: anchor.web3.PublicKey, patientIndex: number)
+  {
+    const [patientPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("patient"),
+        submitterAddress.toBuffer(),
+        new anchor.BN(patientIndex).toBuffer('le', 1)
+      ],
+      program.programId
+    )
+    return patientPDA
+  }
+
+  function getProcessorPDA(processorAddress: anchor.web3.PublicKey)
+  {
+    const [processorPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("processor"),
+        processorAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return processorPDA
+  }
+
+  function getProcessorClaimIndexPDA(processorAddress: anchor.web3.PublicKey)
+  {
+    const [processorClaimIndexPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("processorClaimIndex"),
+        processorAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return processorClaimIndexPDA
+  }
+
+  function getSubmitterPDA(submitterAddress: anchor.web3.PublicKey)
+  {
+    const [submitterPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("submitter"),
+        submitterAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return submitterPDA
+  }
+
+  function getClaimPDA(submitterAddress: anchor.web3.PublicKey )
+  {
+    const [claimPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("claim"),
+        submitterAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return claimPDA
+  }
+
+  function getClaimQueuePDA()
+  {
+    const [claimQueuePDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("claimQueue"),
+      ],
+      program.programId
+    )
+    return claimQueuePDA
+  }
+
+  function getClaimHistoryPDA()
+  {
+    const [claimHistoryPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("claimHistory"),
+      ],
+      program.programId
+    )
+    return claimHistoryPDA
+  }
+
+  function getClaimHistoryChunkPDA(chunkId: number)
+  {
+    const [claimHistoryChunkPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("claimHistoryChunk"),
+        new anchor.BN(chunkId).toBuffer('le', 4)
+      ],
+      program.programId
+    )
+    return claimHistoryChunkPDA
+  }
+
+  function getProcessedClaimPDA(processedClaimIndex: number)
+  {
+    const [claimHistoryChunkPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("processedClaim"),
+        new anchor.web3.PublicKey("93AWVuZKVhNQ4Uky3oFFwNewTgMs6T4aenZ4efom4gUw").toBuffer(),
+        new anchor.BN(processedClaimIndex).toBuffer('le', 4)
+      ],
+      program.programId
+    )
+    return claimHistoryChunkPDA
+  }
+
+  function getProcessedClaimPDAForProcessor(processorAddress: anchor.web3.PublicKey, processorCountIndex: anchor.BN)
+  {
+    const [processedClaimPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("processedClaim"),
+        processorAddress.toBuffer(),
+        processorCountIndex.toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    )
+    return processedClaimPDA
+  }
+
+  function getAdminActionLogPDA()
+  {
+    const [adminActionLogPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("adminActionLog")
+      ],
+      program.programId
+    )
+    return adminActionLogPDA
+  }
+
+  function getFeeTokenEntryPDA(tokenMintAddress: anchor.web3.PublicKey)
+  {
+    const [feeTokenEntryPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("feeTokenEntry"),
+        tokenMintAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return feeTokenEntryPDA
+  }
+
+  function getFeeConfigPDA()
+  {
+    const [feeConfigPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("feeConfig")
+      ],
+      program.programId
+    )
+    return feeConfigPDA
+  }
+
+  function getFeeExemptionPDA(submitterAddress: anchor.web3.PublicKey)
+  {
+    const [feeExemptionPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("feeExemption"),
+        submitterAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return feeExemptionPDA
+  }
+
+  //Funds a new wallet, creates its submitter and patient accounts, and gives it fee tokens to pay with
+  async function createFundedSubmitter(givePatientConsent: boolean = true)
+  {
+    let newWallet = anchor.web3.Keypair.generate()
+    let token_airdrop = await program.provider.connection.requestAirdrop(newWallet.publicKey, 
+      1000 * 10002240)
+
+    const latestBlockHash = await program.provider.connection.getLatestBlockhash()
+    await program.provider.connection.confirmTransaction
+    ({
+      blockhash: latestBlockHash.blockhash,
+      lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+      signature: token_airdrop,
+    })
+
+    await program.methods.createSubmitterAccount()
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    await program.methods.createPatientAccount("John", "Doe")
+    .accounts({signer: newWallet.publicKey})
+    .signers([newWallet])
+    .rpc()
+
+    if(givePatientConsent)
+    {
+      await program.methods.givePatientConsent(patientIndex)
+      .accounts({signer: newWallet.publicKey})
+      .signers([newWallet])
+      .rpc()
+    }
+
+    const userFeeAta = await getOrCreateAssociatedTokenAccount(program.provider.connection, payer, feeTokenMint, newWallet.publicKey)
+    await mintTo(program.provider.connection, payer, feeTokenMint, userFeeAta.address, payer, 1000 * 10 ** feeTokenDecimals)
+
+    return newWallet
+  }
+
+  async function submitClaim(wallet: anchor.web3.Keypair, claimAmountToSubmit: anchor.BN = claimAmount, copayAmountToSubmit: anchor.BN = copayAmount, deductibleAmountToSubmit: anchor.BN = deductibleAmount, tokenMint: anchor.web3.PublicKey = feeTokenMint, feeExemption: anchor.web3.PublicKey | null = null, currencyCodeToSubmit: number[] = currencyCode, hospitalTypeToSubmit: number = hospitalType, diagnosisCodesToSubmit: string[] = diagnosisCodes)
+  {
+    await program.methods.submitClaimToQueue
+    (
+      patientIndex,
+      tokenMint,
+      countryIndex,
+      stateIndex,
+      hospitalIndex,
+      hospitalTypeToSubmit,
+      hospitalName,
+      hospitalAddress,
+      hospitalCity,
+      hospitalZipCode,
+      hospitalPhoneNumber,
+      hospitalBillInvoiceNumber,
+      note144Characters,
+      claimAmountToSubmit,
+      copayAmountToSubmit,
+      deductibleAmountToSubmit,
+      currencyCodeToSubmit,
+      ailment,
+      insuranceCompanyIndex,
+      insuranceCompanyName,
+      diagnosisCodesToSubmit
+    )
+    .accountsPartial({signer: wallet.publicKey, feeExemption: feeExemption})
+    .signers([wallet])
+    .rpc()
+  }
+
+  async function submitClaimAsDelegate(delegateWallet: anchor.web3.Keypair, submitterAddress: anchor.web3.PublicKey)
+  {
+    return await program.methods.submitClaimAsDelegate
+    (
+      submitterAddress,
+      patientIndex,
+      feeTokenMint,
+      countryIndex,
+      stateIndex,
+      hospitalIndex,
+      hospitalType,
+      hospitalName,
+      hospitalAddress,
+      hospitalCity,
+      hospitalZipCode,
+      hospitalPhoneNumber,
+      hospitalBillInvoiceNumber,
+      note144Characters,
+      claimAmount,
+      copayAmount,
+      deductibleAmount,
+      currencyCode,
+      ailment,
+      insuranceCompanyIndex,
+      insuranceCompanyName,
+      diagnosisCodes
+    )
+    .accountsPartial({signer: delegateWallet.publicKey, feeExemption: null})
+    .signers([delegateWallet])
+    .rpc({commitment: "confirmed"})
+  }
+
+  //Remaining accounts approve claims batch expects for one claim, in the order the program reads them
+  function getBatchApprovalAccounts(claimPDA: anchor.web3.PublicKey, claim: any, processorCountIndex: anchor.BN)
+  {
+    const countryIndexBytes = new anchor.BN(claim.countryIndex).toArrayLike(Buffer, 'le', 2)
+    const stateIndexBytes = new anchor.BN(claim.stateIndex).toArrayLike(Buffer, 'le', 4)
+    const hospitalIndexBytes = new anchor.BN(claim.hospitalIndex).toTwos(32).toArrayLike(Buffer, 'le', 4)
+    const insuranceCompanyIndexBytes = new anchor.BN(claim.insuranceCompanyIndex).toTwos(16).toArrayLike(Buffer, 'le', 2)
+    const patientIndexBytes = new anchor.BN(claim.patientIndex).toArrayLike(Buffer, 'le', 1)
+    const findPDA = (seeds: Buffer[]) => anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0]
+
+    const addresses = 
+    [
+      claimPDA,
+      getSubmitterPDA(claim.submitterAddress),
+      getPatientPDA(claim.submitterAddress, claim.patientIndex),
+      findPDA([Buffer.from("state"), countryIndexBytes, stateIndexBytes]),
+      findPDA([Buffer.from("patientRecord"), claim.submitterAddress.toBuffer(), patientIndexBytes, new anchor.BN(claim.patientRecordIndex).toArrayLike(Buffer, 'le', 4)]),
+      findPDA([Buffer.from("hospital"), countryIndexBytes, stateIndexBytes, hospitalIndexBytes]),
+      findPDA([Buffer.from("hospitalRecord"), countryIndexBytes, stateIndexBytes, hospitalIndexBytes, claim.hospitalRecordIndex.toArrayLike(Buffer, 'le', 8)]),
+      findPDA([Buffer.from("insuranceCompany"), insuranceCompanyIndexBytes]),
+      findPDA([Buffer.from("insuranceCompanyRecord"), insuranceCompanyIndexBytes, claim.insuranceCompanyRecordIndex.toArrayLike(Buffer, 'le', 8)]),
+      getProcessorPDA(claim.processorAddress),
+      getProcessedClaimPDAForProcessor(program.provider.publicKey, processorCountIndex),
+      claim.submitterAddress,
+      getProcessorClaimIndexPDA(claim.processorAddress)
+    ]
+
+    return addresses.map(address => ({pubkey: address, isSigner: false, isWritable: true}))
+  }
+
+  function getStatePDA(countryIndex: number, stateIndex: number)
+  {
+    const [statePDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("state"),
+        new anchor.BN(countryIndex).toBuffer('le', 2),
+        new anchor.BN(stateIndex).toBuffer('le', 4)
+      ],
+      program.programId
+    )
+    return statePDA
+  }
+
+  function getInsuranceCompanyPDA(index: number)
+  {
+    const [insuranceCompanyPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
         utf8.encode("insuranceCompany"),
         new anchor.BN(index).toBuffer('le', 4)
       ],
@@ -1620,6 +6231,21 @@ describe("M4A_Protocol", () => {
     return insuranceCompanyPDA
   }
 
+  function getHospitalPDA(countryIndex: number, stateIndex: number, hospitalIndex: number)
+  {
+    const [hospitalPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("hospital"),
+        new anchor.BN(countryIndex).toBuffer('le', 2),
+        new anchor.BN(stateIndex).toBuffer('le', 4),
+        new anchor.BN(hospitalIndex).toBuffer('le', 4)
+      ],
+      program.programId
+    )
+    return hospitalPDA
+  }
+
   const chunk = (arr: any[], size: number) => Array.from
   (
     { length: Math.ceil(arr.length / size) }, (_, i) => 